@@ -0,0 +1,43 @@
+use super::*;
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const PROBLEM_ARG_NAME: &str = "problem-file";
+pub const SOLUTION_ARG_NAME: &str = "solution-file";
+pub const MATRIX_ARG_NAME: &str = "matrix";
+
+pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("check")
+        .about("Provides the way to check solution feasibility")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies the problem type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets the problem file to use")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets the solution file to check")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
+}
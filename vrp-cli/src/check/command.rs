@@ -0,0 +1,58 @@
+use super::app::*;
+use super::*;
+
+use std::io::BufReader;
+use vrp_pragmatic::checker::{check_solution, CheckerContext};
+use vrp_pragmatic::json::problem::{deserialize_matrix, deserialize_problem};
+use vrp_pragmatic::json::solution::deserialize_solution;
+
+/// Runs solution check command.
+pub fn run_check(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let problem_file = open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem");
+    let solution_file = open_file(matches.value_of(SOLUTION_ARG_NAME).unwrap(), "solution");
+    let matrix_files = matches
+        .values_of(MATRIX_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| open_file(path, "routing matrix")).collect::<Vec<_>>());
+
+    let result = match input_format {
+        "pragmatic" => check_pragmatic_solution(problem_file, solution_file, matrix_files),
+        _ => Err(vec![format!("unknown format: '{}'", input_format)]),
+    };
+
+    match result {
+        Ok(_) => println!("solution is feasible"),
+        Err(errors) => {
+            eprintln!("solution is not feasible:\n{}", errors.join("\n"));
+            process::exit(1);
+        }
+    }
+}
+
+fn check_pragmatic_solution(
+    problem_file: File,
+    solution_file: File,
+    matrix_files: Option<Vec<File>>,
+) -> Result<(), Vec<String>> {
+    let format_errors = |errors: Vec<vrp_pragmatic::json::problem::FormatError>| {
+        errors.iter().map(|err| err.to_string()).collect::<Vec<_>>()
+    };
+
+    let problem = deserialize_problem(BufReader::new(problem_file)).map_err(format_errors)?;
+    let solution = deserialize_solution(BufReader::new(solution_file))
+        .map_err(|err| vec![format!("cannot deserialize solution: '{}'", err)])?;
+    let matrices = if let Some(matrix_files) = matrix_files {
+        Some(
+            matrix_files
+                .into_iter()
+                .map(|file| deserialize_matrix(BufReader::new(file)))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(format_errors)?,
+        )
+    } else {
+        None
+    };
+
+    check_solution(&CheckerContext::new(problem, matrices, solution))
+        .map_err(|violations| violations.iter().map(|violation| violation.to_string()).collect())
+}
@@ -0,0 +1,10 @@
+//! A command line interface to check solution feasibility.
+//!
+
+use super::*;
+
+mod app;
+pub use self::app::get_check_app;
+
+mod command;
+pub use self::command::run_check;
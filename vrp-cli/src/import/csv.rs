@@ -65,9 +65,10 @@ fn parse_tw(start: Option<String>, end: Option<String>) -> Option<Vec<String>> {
 fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>> {
     let get_task = |job: &CsvJob| JobTask {
         places: vec![JobPlace {
-            location: Location { lat: job.lat, lng: job.lng },
+            location: Location::new(job.lat, job.lng),
             duration: job.duration as f64 * 60.,
             times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
+            lateness: None,
         }],
         demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
         tag: None,
@@ -97,6 +98,9 @@ fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>>
             services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
             priority: None,
             skills: None,
+            max_ride_time: None,
+            compatibility: None,
+            group: None,
         })
         .collect();
 
@@ -107,16 +111,23 @@ fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<
     let vehicles = read_csv_entries::<CsvVehicle, _>(reader)?
         .into_iter()
         .map(|vehicle| {
-            let depot_location = Location { lat: vehicle.lat, lng: vehicle.lng };
+            let depot_location = Location::new(vehicle.lat, vehicle.lng);
 
             VehicleType {
                 type_id: vehicle.id.clone(),
                 vehicle_ids: (1..vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                 profile: vehicle.profile,
-                costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                costs: VehicleCosts {
+                    fixed: Some(25.),
+                    distance: 0.0002,
+                    time: 0.005,
+                    waiting: None,
+                    service: None,
+                    overtime: None,
+                },
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: vehicle.tw_start, location: depot_location.clone() },
-                    end: Some(VehiclePlace { time: vehicle.tw_end, location: depot_location }),
+                    start: VehiclePlace { time: vehicle.tw_start, location: Some(depot_location.clone()) },
+                    end: Some(VehiclePlace { time: vehicle.tw_end, location: Some(depot_location) }),
                     breaks: None,
                     reloads: None,
                 }],
@@ -152,7 +163,11 @@ pub fn read_csv_problem<R1: Read, R2: Read>(
         plan: Plan { jobs, relations: None },
         fleet: Fleet {
             vehicles,
-            profiles: profiles.into_iter().map(|p| Profile { name: p.clone(), profile_type: p }).collect(),
+            profiles: profiles
+                .into_iter()
+                .map(|p| Profile { name: p.clone(), profile_type: p, speed: None, circuity: None, metric: None })
+                .collect(),
+            drivers: None,
         },
         objectives: None,
         config: None,
@@ -304,7 +304,7 @@ mod hre {
 }
 
 fn to_loc(loc: &hre::Location) -> Location {
-    Location { lat: loc.lat, lng: loc.lng }
+    Location::new(loc.lat, loc.lng)
 }
 
 pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, FormatError> {
@@ -313,6 +313,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
             location: to_loc(&place.location),
             duration: place.duration,
             times: place.times.clone(),
+            lateness: None,
         }],
         demand: Some(job.demand.clone()),
         tag: place.tag.clone(),
@@ -330,6 +331,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                             location: to_loc(&place.location),
                             duration: place.duration,
                             times: place.times.clone(),
+                            lateness: None,
                         }],
                         demand: Some(place.demand.clone()),
                         tag: place.tag.clone(),
@@ -356,7 +358,10 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         replacements: None,
                         services: None,
                         priority: job.priority.as_ref().map(|p| *p),
-                        skills: job.skills.clone(),
+                        skills: job.skills.clone().map(JobSkills::List),
+                        max_ride_time: None,
+                        compatibility: None,
+                        group: None,
                     },
                     hre::JobVariant::Multi(job) => Job {
                         id: job.id.clone(),
@@ -365,7 +370,10 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         replacements: None,
                         services: None,
                         priority: job.priority.as_ref().map(|p| *p),
-                        skills: job.skills.clone(),
+                        skills: job.skills.clone().map(JobSkills::List),
+                        max_ride_time: None,
+                        compatibility: None,
+                        group: None,
                     },
                 })
                 .collect(),
@@ -398,6 +406,9 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         fixed: v.costs.fixed.clone(),
                         distance: v.costs.distance,
                         time: v.costs.time,
+                        waiting: None,
+                        service: None,
+                        overtime: None,
                     },
                     shifts: v
                         .shifts
@@ -405,12 +416,12 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         .map(|shift| VehicleShift {
                             start: VehiclePlace {
                                 time: shift.start.time.clone(),
-                                location: to_loc(&shift.start.location),
+                                location: Some(to_loc(&shift.start.location)),
                             },
-                            end: shift
-                                .end
-                                .as_ref()
-                                .map(|end| VehiclePlace { time: end.time.clone(), location: to_loc(&end.location) }),
+                            end: shift.end.as_ref().map(|end| VehiclePlace {
+                                time: end.time.clone(),
+                                location: Some(to_loc(&end.location)),
+                            }),
                             breaks: shift.breaks.as_ref().map(|breaks| {
                                 breaks
                                     .iter()
@@ -439,6 +450,9 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                     limits: v.limits.as_ref().map(|l| VehicleLimits {
                         max_distance: l.max_distance.clone(),
                         shift_time: l.shift_time.clone(),
+                        max_activities: None,
+                        max_jobs: None,
+                        min_jobs: None,
                     }),
                 })
                 .collect(),
@@ -446,8 +460,15 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                 .fleet
                 .profiles
                 .iter()
-                .map(|p| Profile { name: p.name.clone(), profile_type: p.profile_type.clone() })
+                .map(|p| Profile {
+                    name: p.name.clone(),
+                    profile_type: p.profile_type.clone(),
+                    speed: None,
+                    circuity: None,
+                    metric: None,
+                })
                 .collect(),
+            drivers: None,
         },
         objectives: None,
         config: None,
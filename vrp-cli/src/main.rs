@@ -1,10 +1,18 @@
 //! A command line interface to *Vehicle Routing Problem* solver.
 //!
 
+mod check;
+use self::check::get_check_app;
+use self::check::run_check;
+
 mod import;
 use self::import::get_import_app;
 use self::import::run_import;
 
+mod matrix;
+use self::matrix::get_matrix_app;
+use self::matrix::run_matrix;
+
 mod solve;
 use self::solve::get_solve_app;
 use self::solve::run_solve;
@@ -22,11 +30,15 @@ fn main() {
         .about("A command line interface to Vehicle Routing Problem solver")
         .subcommand(get_solve_app())
         .subcommand(get_import_app())
+        .subcommand(get_check_app())
+        .subcommand(get_matrix_app())
         .get_matches();
 
     match matches.subcommand() {
         ("solve", Some(solve_matches)) => run_solve(solve_matches),
         ("import", Some(import_matches)) => run_import(import_matches),
+        ("check", Some(check_matches)) => run_check(check_matches),
+        ("matrix", Some(matrix_matches)) => run_matrix(matrix_matches),
         ("", None) => eprintln!("No subcommand was used. Use -h to print help information."),
         _ => unreachable!(),
     }
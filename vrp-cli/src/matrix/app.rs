@@ -0,0 +1,33 @@
+use super::*;
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const INPUT_ARG_NAME: &str = "input-file";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_matrix_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("matrix")
+        .about("Provides the way to convert routing matrix between json and binary formats")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies output format")
+                .required(true)
+                .possible_values(&["json", "binary"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(INPUT_ARG_NAME)
+                .help("Sets input file which contains routing matrix in json or binary format")
+                .short("i")
+                .long(INPUT_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
@@ -0,0 +1,33 @@
+use super::app::*;
+use super::*;
+
+use std::io::BufReader;
+use vrp_pragmatic::json::problem::{deserialize_matrix, serialize_binary_matrix, serialize_matrix};
+
+/// Runs routing matrix conversion command.
+pub fn run_matrix(matches: &ArgMatches) {
+    let output_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let input_file = open_file(matches.value_of(INPUT_ARG_NAME).unwrap(), "input");
+
+    let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let out_buffer = create_write_buffer(out_result);
+
+    let matrix = deserialize_matrix(BufReader::new(input_file)).unwrap_or_else(|errors| {
+        eprintln!(
+            "Cannot read routing matrix: '{}'",
+            errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\n")
+        );
+        process::exit(1);
+    });
+
+    let result = match output_format {
+        "json" => serialize_matrix(out_buffer, &matrix).map_err(|err| err.to_string()),
+        "binary" => serialize_binary_matrix(out_buffer, &matrix).map_err(|err| err.to_string()),
+        _ => Err(format!("unknown format: '{}'", output_format)),
+    };
+
+    if let Err(err) = result {
+        eprintln!("Cannot write routing matrix: '{}'", err);
+        process::exit(1);
+    }
+}
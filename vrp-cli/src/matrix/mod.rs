@@ -0,0 +1,10 @@
+//! A command line interface to convert routing matrix between json and binary formats.
+//!
+
+use super::*;
+
+mod app;
+pub use self::app::get_matrix_app;
+
+mod command;
+pub use self::command::run_matrix;
@@ -3,11 +3,23 @@ use super::*;
 pub const FORMAT_ARG_NAME: &str = "FORMAT";
 pub const PROBLEM_ARG_NAME: &str = "PROBLEM";
 pub const MATRIX_ARG_NAME: &str = "matrix";
+pub const ROUTING_URL_ARG_NAME: &str = "routing-url";
+pub const ROUTING_API_ARG_NAME: &str = "routing-api";
+pub const ROUTING_CACHE_ARG_NAME: &str = "routing-cache";
 pub const GENERATIONS_ARG_NAME: &str = "max-generations";
 pub const TIME_ARG_NAME: &str = "max-time";
 pub const GEO_JSON_ARG_NAME: &str = "geo-json";
+pub const SEED_ARG_NAME: &str = "seed";
+pub const ISLANDS_ARG_NAME: &str = "islands";
+pub const ACCEPTANCE_ARG_NAME: &str = "acceptance";
+pub const MIGRATION_ARG_NAME: &str = "migration-interval";
+pub const ADAPTIVE_ARG_NAME: &str = "adaptive";
+pub const LOCAL_SEARCH_ARG_NAME: &str = "local-search";
+pub const PERFORMANCE_ARG_NAME: &str = "with-performance";
+pub const PERFORMANCE_INTERVAL_ARG_NAME: &str = "performance-interval";
 
 pub const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
+pub const CONFIG_ARG_NAME: &str = "config";
 pub const OUT_RESULT_ARG_NAME: &str = "out-result";
 pub const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 
@@ -24,7 +36,7 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
         .arg(Arg::with_name(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
         .arg(
             Arg::with_name(GENERATIONS_ARG_NAME)
-                .help("Specifies maximum number of generations")
+                .help("Specifies maximum number of generations, with islands it is applied to each island")
                 .short("n")
                 .long(GENERATIONS_ARG_NAME)
                 .required(false)
@@ -38,6 +50,72 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(SEED_ARG_NAME)
+                .help("Specifies seed for random generator to make results reproducible")
+                .long(SEED_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ACCEPTANCE_ARG_NAME)
+                .help("Specifies acceptance criteria")
+                .long(ACCEPTANCE_ARG_NAME)
+                .required(false)
+                .possible_values(&["greedy", "annealing", "threshold", "record"])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ADAPTIVE_ARG_NAME)
+                .help("Specifies whether ruin and recreate methods are selected using adaptive weights")
+                .long(ADAPTIVE_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(LOCAL_SEARCH_ARG_NAME)
+                .help("Specifies local search usage: instead of or after ruin and recreate")
+                .long(LOCAL_SEARCH_ARG_NAME)
+                .required(false)
+                .possible_values(&["standalone", "after-recreate"])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(PERFORMANCE_ARG_NAME)
+                .help("Specifies whether search performance telemetry is written into solution extras")
+                .long(PERFORMANCE_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(PERFORMANCE_INTERVAL_ARG_NAME)
+                .help("Specifies amount of generations between performance telemetry samples")
+                .long(PERFORMANCE_INTERVAL_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .requires(PERFORMANCE_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(ISLANDS_ARG_NAME)
+                .help("Specifies amount of islands: independent refinement loops run in parallel")
+                .long(ISLANDS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MIGRATION_ARG_NAME)
+                .help("Specifies amount of generations between migrations of the best solutions across islands")
+                .long(MIGRATION_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .requires(ISLANDS_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(CONFIG_ARG_NAME)
+                .help("Specifies path to file with solver configuration")
+                .short("c")
+                .long(CONFIG_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(INIT_SOLUTION_ARG_NAME)
                 .help("Specifies path to file with initial solution")
@@ -55,6 +133,31 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(ROUTING_URL_ARG_NAME)
+                .help("Specifies url of routing service used to get routing matrices, only plain http is supported")
+                .long(ROUTING_URL_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .conflicts_with(MATRIX_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(ROUTING_API_ARG_NAME)
+                .help("Specifies api of routing service")
+                .long(ROUTING_API_ARG_NAME)
+                .required(false)
+                .possible_values(&["osrm", "valhalla"])
+                .takes_value(true)
+                .requires(ROUTING_URL_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(ROUTING_CACHE_ARG_NAME)
+                .help("Specifies path to directory used to cache routing matrices, default is system temp directory")
+                .long(ROUTING_CACHE_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .requires(ROUTING_URL_ARG_NAME),
+        )
         .arg(
             Arg::with_name(OUT_RESULT_ARG_NAME)
                 .help("Specifies path to file for result output")
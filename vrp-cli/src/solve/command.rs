@@ -1,19 +1,23 @@
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
 use vrp_core::models::{Problem, Solution};
-use vrp_pragmatic::json::problem::PragmaticProblem;
+use vrp_core::refinement::mutation::AdaptiveSettings;
+use vrp_pragmatic::json::problem::{deserialize_problem, FormatError, PragmaticProblem};
 use vrp_pragmatic::json::solution::PragmaticSolution;
+use vrp_pragmatic::routing::*;
 use vrp_scientific::common::read_init_solution;
 use vrp_scientific::lilim::{LilimProblem, LilimSolution};
 use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
-use vrp_solver::SolverBuilder;
+use vrp_solver::config::read_config;
+use vrp_solver::{AcceptanceType, LocalSearchType, SolverBuilder};
 
 use super::app::*;
 use super::*;
 
-struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
+struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>, Option<&dyn RoutingProvider>) -> Result<Problem, String>>);
 
 struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Option<Solution>>);
 
@@ -30,8 +34,8 @@ fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, Sol
         (
             "solomon",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    assert!(matrices.is_none());
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>, routing| {
+                    check_no_routing(matrices, routing)?;
                     problem.read_solomon()
                 })),
                 InitSolutionReader(Box::new(|file, problem| read_init_solution(BufReader::new(file), problem).ok())),
@@ -42,8 +46,8 @@ fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, Sol
         (
             "lilim",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    assert!(matrices.is_none());
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>, routing| {
+                    check_no_routing(matrices, routing)?;
                     problem.read_lilim()
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| None)),
@@ -54,16 +58,28 @@ fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, Sol
         (
             "pragmatic",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    if let Some(matrices) = matrices {
-                        (problem, matrices).read_pragmatic()
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>, routing| {
+                    let to_string = |errors: Vec<FormatError>| {
+                        errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n")
+                    };
+
+                    if let Some(routing) = routing {
+                        println!("configured to use routing matrices from '{}'", routing.source());
+                        let problem = deserialize_problem(BufReader::new(problem)).map_err(to_string)?;
+                        let matrices = get_routing_matrices(&problem, routing)?;
+                        (problem, matrices).read_pragmatic().map_err(to_string)
+                    } else if let Some(matrices) = matrices {
+                        (problem, matrices).read_pragmatic().map_err(to_string)
                     } else {
                         println!("configured to use single approximated routing matrix");
-                        problem.read_pragmatic()
+                        problem.read_pragmatic().map_err(to_string)
                     }
-                    .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
                 })),
-                InitSolutionReader(Box::new(|_file, _problem| None)),
+                InitSolutionReader(Box::new(|file, problem| {
+                    vrp_pragmatic::json::solution::read_init_solution(BufReader::new(file), problem)
+                        .map_err(|err| eprintln!("cannot read init solution: '{}'", err))
+                        .ok()
+                })),
                 SolutionWriter(Box::new(|problem, solution, default_writer, geojson_writer| {
                     geojson_writer
                         .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
@@ -81,6 +97,15 @@ fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, Sol
     .collect()
 }
 
+/// Returns error if routing data is specified as it is supported only by pragmatic format.
+fn check_no_routing(matrices: Option<Vec<File>>, routing: Option<&dyn RoutingProvider>) -> Result<(), String> {
+    match (matrices, routing) {
+        (Some(_), _) => Err("routing matrix is supported only for pragmatic format".to_string()),
+        (_, Some(_)) => Err("routing url is supported only for pragmatic format".to_string()),
+        (None, None) => Ok(()),
+    }
+}
+
 /// Runs solver commands.
 pub fn run_solve(matches: &ArgMatches) {
     let formats = get_formats();
@@ -103,10 +128,63 @@ pub fn run_solve(matches: &ArgMatches) {
             process::exit(1);
         })
     });
+    let seed = matches.value_of(SEED_ARG_NAME).map(|arg| {
+        arg.parse::<u64>().unwrap_or_else(|err| {
+            eprintln!("Cannot get seed: '{}'", err.to_string());
+            process::exit(1);
+        })
+    });
+    let islands = matches.value_of(ISLANDS_ARG_NAME).map(|arg| {
+        arg.parse::<usize>().unwrap_or_else(|err| {
+            eprintln!("Cannot get islands: '{}'", err.to_string());
+            process::exit(1);
+        })
+    });
+    let migration_interval = matches.value_of(MIGRATION_ARG_NAME).map_or(100, |arg| {
+        arg.parse::<usize>().unwrap_or_else(|err| {
+            eprintln!("Cannot get migration interval: '{}'", err.to_string());
+            process::exit(1);
+        })
+    });
+    let acceptance = matches.value_of(ACCEPTANCE_ARG_NAME).map(|arg| match arg {
+        "annealing" => AcceptanceType::SimulatedAnnealing,
+        "threshold" => AcceptanceType::ThresholdAccepting,
+        "record" => AcceptanceType::RecordToRecord,
+        _ => AcceptanceType::Greedy,
+    });
+    let adaptive = if matches.is_present(ADAPTIVE_ARG_NAME) { Some(AdaptiveSettings::default()) } else { None };
+    let local_search = matches.value_of(LOCAL_SEARCH_ARG_NAME).map(|arg| match arg {
+        "standalone" => LocalSearchType::Standalone,
+        _ => LocalSearchType::AfterRecreate,
+    });
+    let performance_interval = matches.value_of(PERFORMANCE_INTERVAL_ARG_NAME).map_or(100, |arg| {
+        arg.parse::<usize>().unwrap_or_else(|err| {
+            eprintln!("Cannot get performance interval: '{}'", err.to_string());
+            process::exit(1);
+        })
+    });
+    let telemetry = if matches.is_present(PERFORMANCE_ARG_NAME) { Some(performance_interval) } else { None };
+    let config = matches.value_of(CONFIG_ARG_NAME).map(|path| {
+        read_config(BufReader::new(open_file(path, "config"))).unwrap_or_else(|err| {
+            eprintln!("Cannot read config: '{}'", err);
+            process::exit(1);
+        })
+    });
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
     let matrix_files = matches
         .values_of(MATRIX_ARG_NAME)
         .map(|paths: Values| paths.map(|path| open_file(path, "routing matrix")).collect());
+    let routing = matches.value_of(ROUTING_URL_ARG_NAME).map(|url| {
+        let api = match matches.value_of(ROUTING_API_ARG_NAME) {
+            Some("valhalla") => TableApi::Valhalla,
+            _ => TableApi::Osrm,
+        };
+        let directory = matches
+            .value_of(ROUTING_CACHE_ARG_NAME)
+            .map_or_else(|| std::env::temp_dir().join("vrp-routing-cache"), PathBuf::from);
+
+        CachedRoutingProvider::new(TableRoutingProvider::new(url, api), directory)
+    });
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
@@ -122,14 +200,26 @@ pub fn run_solve(matches: &ArgMatches) {
                     process::exit(1);
                 });
             } else {
-                match problem_reader.0(problem_file, matrix_files) {
+                match problem_reader.0(
+                    problem_file,
+                    matrix_files,
+                    routing.as_ref().map(|routing| routing as &dyn RoutingProvider),
+                ) {
                     Ok(problem) => {
                         let problem = Arc::new(problem);
                         let solution = init_solution.and_then(|file| init_reader.0(file, problem.clone()));
+                        let config = config.or_else(|| vrp_pragmatic::get_solver_config(problem.as_ref()));
                         let solution = SolverBuilder::default()
+                            .with_config(config)
                             .with_init_solution(solution.map(|s| (problem.clone(), Arc::new(s))))
                             .with_max_generations(max_generations)
                             .with_max_time(max_time)
+                            .with_seed(seed)
+                            .with_islands(islands.map(|islands| (islands, migration_interval)))
+                            .with_acceptance(acceptance)
+                            .with_adaptive_weights(adaptive)
+                            .with_local_search(local_search)
+                            .with_telemetry(telemetry)
                             .build()
                             .solve(problem.clone());
                         match solution {
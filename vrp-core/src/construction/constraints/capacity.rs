@@ -358,6 +358,10 @@ impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + De
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn is_locked_job(&self, job: &Job) -> bool {
+        self.multi_trip.is_reload_job(job)
+    }
 }
 
 struct CapacitySoftRouteConstraint<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
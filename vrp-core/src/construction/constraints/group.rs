@@ -0,0 +1,156 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/group_test.rs"]
+mod group_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::{Dimensions, ValueDimension};
+use crate::models::problem::Job;
+use crate::models::solution::Tour;
+use hashbrown::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A trait to get or set job group.
+pub trait GroupDimension {
+    /// Sets job group.
+    fn set_group(&mut self, group: &str) -> &mut Self;
+    /// Gets job group.
+    fn get_group(&self) -> Option<&String>;
+}
+
+impl GroupDimension for Dimensions {
+    fn set_group(&mut self, group: &str) -> &mut Self {
+        self.set_value("group", group.to_string());
+        self
+    }
+
+    fn get_group(&self) -> Option<&String> {
+        self.get_value("group")
+    }
+}
+
+/// A module which keeps jobs of the same group together: either all of them are assigned to the
+/// same route or none of them. When some job of the group is removed from the solution, e.g. by
+/// ruin method, the rest of the group is removed too.
+pub struct GroupModule {
+    code: i32,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl GroupModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            state_keys: vec![GROUPS_KEY],
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(GroupHardRouteConstraint { code }))],
+        }
+    }
+
+    /// Removes assigned jobs of the groups which are not assigned completely.
+    fn remove_partial_groups(&self, ctx: &mut SolutionContext) {
+        let groups = ctx
+            .required
+            .iter()
+            .chain(ctx.unassigned.keys())
+            .filter_map(|job| job.dimens().get_group().cloned())
+            .collect::<HashSet<_>>();
+
+        if groups.is_empty() {
+            return;
+        }
+
+        // NOTE routes which have no jobs after removal are dropped, states of other changed
+        // routes are updated by constraint pipeline
+        let locked = &ctx.locked;
+        let mut removed = vec![];
+        let (emptied, routes): (Vec<_>, Vec<_>) = ctx
+            .routes
+            .drain(..)
+            .map(|mut route_ctx| {
+                let jobs = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter(|job| !locked.contains(job))
+                    .filter(|job| matches!(job.dimens().get_group(), Some(group) if groups.contains(group)))
+                    .collect::<Vec<_>>();
+
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+
+                let is_emptied = !jobs.is_empty() && !route_ctx.route.tour.has_jobs();
+                removed.extend(jobs);
+
+                (route_ctx, is_emptied)
+            })
+            .partition(|(_, is_emptied)| *is_emptied);
+
+        ctx.routes = routes.into_iter().map(|(route_ctx, _)| route_ctx).collect();
+        emptied.into_iter().for_each(|(route_ctx, _)| ctx.registry.free_actor(&route_ctx.route.actor));
+
+        if ctx.required.is_empty() {
+            ctx.unassigned.extend(removed.into_iter().map(|job| (job, self.code)));
+        } else {
+            ctx.required.extend(removed);
+        }
+    }
+}
+
+impl ConstraintModule for GroupModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let groups = get_groups(&ctx.route.tour);
+        ctx.state_mut().put_route_state(GROUPS_KEY, groups);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.remove_partial_groups(ctx);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct GroupHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for GroupHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let group = job.dimens().get_group()?;
+
+        let is_used_by_other_route =
+            solution_ctx.routes.iter().filter(|rc| rc.route.actor != route_ctx.route.actor).any(|rc| {
+                match rc.state.get_route_state::<HashSet<String>>(GROUPS_KEY) {
+                    Some(groups) => groups.contains(group),
+                    None => get_groups(&rc.route.tour).contains(group),
+                }
+            });
+
+        if is_used_by_other_route {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_groups(tour: &Tour) -> HashSet<String> {
+    tour.jobs().filter_map(|job| job.dimens().get_group().cloned()).collect()
+}
@@ -59,12 +59,16 @@ pub const LATEST_ARRIVAL_KEY: i32 = 1;
 pub const WAITING_KEY: i32 = 2;
 pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
+/// A key of route state which keeps extra cost of the route, e.g. penalties, added by constraint modules.
+pub const EXTRA_COST_KEY: i32 = 5;
 
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
 pub const MAX_FUTURE_CAPACITY_KEY: i32 = 12;
 pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 pub const RELOAD_INTERVALS: i32 = 14;
 
+pub const GROUPS_KEY: i32 = 21;
+
 mod pipeline;
 pub use self::pipeline::*;
 
@@ -82,3 +86,6 @@ pub use self::conditional::*;
 
 mod fleet_usage;
 pub use self::fleet_usage::*;
+
+mod group;
+pub use self::group::*;
@@ -5,6 +5,7 @@ mod pipeline_test;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use crate::models::common::Cost;
 use crate::models::problem::Job;
+use crate::models::solution::Route;
 use hashbrown::HashSet;
 use std::slice::Iter;
 use std::sync::Arc;
@@ -93,6 +94,13 @@ pub trait ConstraintModule {
 
     /// Returns list of constraints to be used.
     fn get_constraints(&self) -> Iter<ConstraintVariant>;
+
+    /// Returns true if job assigned in existing solution has to be locked, e.g. vehicle reload.
+    /// Module locks such jobs when it promotes them to required, but jobs of existing solution
+    /// are already assigned, so they never pass required or ignored collections.
+    fn is_locked_job(&self, _job: &Job) -> bool {
+        false
+    }
 }
 
 /// Provides the way to work with multiple constraints.
@@ -129,9 +137,20 @@ impl ConstraintPipeline {
         self.modules.iter().for_each(|c| c.accept_route_state(ctx))
     }
 
-    /// Accepts solution state.
+    /// Accepts solution state. Routes which tours are changed by modules get their states updated.
     pub fn accept_solution_state(&self, ctx: &mut SolutionContext) {
-        self.modules.iter().for_each(|c| c.accept_solution_state(ctx))
+        let get_tour_size = |route_ctx: &RouteContext| {
+            (route_ctx.route.as_ref() as *const Route as usize, route_ctx.route.tour.total())
+        };
+        let tour_sizes = ctx.routes.iter().map(get_tour_size).collect::<HashSet<_>>();
+
+        self.modules.iter().for_each(|c| c.accept_solution_state(ctx));
+
+        // NOTE modules can remove jobs from routes, so states such as timing or capacity become stale
+        ctx.routes
+            .iter_mut()
+            .filter(|route_ctx| !tour_sizes.contains(&get_tour_size(route_ctx)))
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
     }
 
     /// Adds constraint module.
@@ -155,6 +174,11 @@ impl ConstraintPipeline {
         self
     }
 
+    /// Returns true if job assigned in existing solution has to be locked.
+    pub fn is_locked_job(&self, job: &Job) -> bool {
+        self.modules.iter().any(|module| module.is_locked_job(job))
+    }
+
     /// Checks whether all hard route constraints are fulfilled.
     /// Returns result of first failed constraint or empty value.
     pub fn evaluate_hard_route(
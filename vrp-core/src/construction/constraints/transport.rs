@@ -3,16 +3,18 @@
 mod transport_test;
 
 use crate::construction::constraints::*;
-use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use crate::construction::OP_START_MSG;
-use crate::models::common::{Cost, Distance, Duration, Profile, Timestamp};
+use crate::construction::heuristics::{get_prev_location, ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Distance, Duration, Location, Profile, Timestamp};
 use crate::models::problem::{ActivityCost, Actor, Job, Single, TransportCost};
 use crate::models::solution::{Activity, TourActivity};
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
 use std::ops::Deref;
 use std::slice::Iter;
 use std::sync::Arc;
 
-// TODO revise rescheduling once routing is sensible to departure time
+/// Max amount of iterations used to find the latest departure time with time dependent travel.
+const MAX_DEPARTURE_SEARCH_ITERATIONS: usize = 32;
 
 pub type TravelLimitFunc = Arc<dyn Fn(&Actor) -> (Option<Distance>, Option<Duration>) + Send + Sync>;
 
@@ -89,6 +91,9 @@ impl TransportConstraintModule {
                     transport: transport.clone(),
                     activity: activity.clone(),
                 })),
+                ConstraintVariant::SoftActivity(Arc::new(OvertimeSoftActivityConstraint {
+                    transport: transport.clone(),
+                })),
             ],
             activity,
             transport,
@@ -97,6 +102,8 @@ impl TransportConstraintModule {
     }
 
     fn update_route_schedules(&self, ctx: &mut RouteContext) {
+        update_open_start(ctx);
+
         let (init, actor) = {
             let start = ctx.route.tour.start().unwrap();
             ((start.place.location, start.schedule.departure), ctx.route.actor.clone())
@@ -114,11 +121,7 @@ impl TransportConstraintModule {
     fn update_route_states(&self, ctx: &mut RouteContext) {
         // update latest arrival and waiting states of non-terminate (jobs) activities
         let actor = ctx.route.actor.clone();
-        let init = (
-            actor.detail.time.end,
-            actor.detail.end.unwrap_or_else(|| actor.detail.start.unwrap_or_else(|| panic!(OP_START_MSG))),
-            0_f64,
-        );
+        let init = (actor.detail.time.end, actor.detail.end.or(actor.detail.start), 0_f64);
 
         let (route, state) = ctx.as_mut();
 
@@ -128,6 +131,7 @@ impl TransportConstraintModule {
             }
 
             let (end_time, prev_loc, waiting) = acc;
+            let prev_loc = prev_loc.unwrap_or(act.place.location);
             let potential_latest = end_time
                 - self.transport.duration(actor.vehicle.profile, act.place.location, prev_loc, end_time)
                 - self.activity.duration(actor.as_ref(), act.deref(), end_time);
@@ -138,7 +142,7 @@ impl TransportConstraintModule {
             state.put_activity_state(LATEST_ARRIVAL_KEY, &act, latest_arrival_time);
             state.put_activity_state(WAITING_KEY, &act, future_waiting);
 
-            (latest_arrival_time, act.place.location, future_waiting)
+            (latest_arrival_time, Some(act.place.location), future_waiting)
         });
     }
 
@@ -157,18 +161,54 @@ impl TransportConstraintModule {
         if let Some(first) = ctx.route.tour.get(1) {
             let start = ctx.route.tour.start().unwrap();
             let last_departure_time = start.schedule.departure;
-            let start_to_first = self.transport.duration(
+            let new_departure_time = self.get_latest_departure(
                 ctx.route.actor.vehicle.profile,
-                start.place.location,
-                first.place.location,
+                (start.place.location, first.place.location),
                 last_departure_time,
+                first.place.time.start,
             );
-            let new_departure_time = last_departure_time.max(first.place.time.start - start_to_first);
             return Some((last_departure_time, new_departure_time));
         }
         None
     }
 
+    /// Returns the latest departure time which still allows to arrive not later than given arrival time.
+    /// As travel time can depend on departure time, this allows to shift departure to off-peak hours.
+    fn get_latest_departure(
+        &self,
+        profile: Profile,
+        leg: (Location, Location),
+        earliest_departure: Timestamp,
+        latest_arrival: Timestamp,
+    ) -> Timestamp {
+        let (from, to) = leg;
+        let get_arrival = |departure: Timestamp| departure + self.transport.duration(profile, from, to, departure);
+
+        if get_arrival(earliest_departure) >= latest_arrival {
+            return earliest_departure;
+        }
+
+        // NOTE this is exact when travel time does not depend on departure time
+        let duration = self.transport.duration(profile, from, to, earliest_departure);
+        let candidate = latest_arrival - duration;
+        if compare_floats(self.transport.duration(profile, from, to, candidate), duration) == Ordering::Equal {
+            return candidate;
+        }
+
+        // NOTE travel time is FIFO consistent, so arrival time does not decrease with departure time
+        let (mut left, mut right) = (earliest_departure, latest_arrival);
+        for _ in 0..MAX_DEPARTURE_SEARCH_ITERATIONS {
+            let middle = (left + right) / 2.;
+            if get_arrival(middle) <= latest_arrival {
+                left = middle;
+            } else {
+                right = middle;
+            }
+        }
+
+        left
+    }
+
     fn update_statistics(&self, ctx: &mut RouteContext) {
         let start = ctx.route.tour.start().unwrap();
         let end = ctx.route.tour.end().unwrap();
@@ -259,8 +299,9 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             (target.place.location, target.place.time.end.min(actor.detail.time.end))
         };
 
+        let prev_to_next_location = get_prev_location(route_ctx, activity_ctx, next_act_location);
         let arr_time_at_next =
-            departure + self.transport.duration(profile, prev.place.location, next_act_location, departure);
+            departure + self.transport.duration(profile, prev_to_next_location, next_act_location, departure);
 
         if arr_time_at_next > latest_arr_time_at_next_act {
             return fail(self.code);
@@ -269,8 +310,9 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             return stop(self.code);
         }
 
+        let prev_to_target_location = get_prev_location(route_ctx, activity_ctx, target.place.location);
         let arr_time_at_target_act =
-            departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+            departure + self.transport.duration(profile, prev_to_target_location, target.place.location, departure);
 
         let end_time_at_new_act = arr_time_at_target_act.max(target.place.time.start)
             + self.activity.duration(actor, target.deref(), arr_time_at_target_act);
@@ -321,7 +363,7 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
     ) -> Option<ActivityConstraintViolation> {
         let limit = (self.limit_func)(&route_ctx.route.actor);
         if limit.0.is_some() || limit.1.is_some() {
-            let (change_distance, change_duration) = self.calculate_travel(route_ctx, activity_ctx);
+            let (change_distance, change_duration) = calculate_travel(self.transport.as_ref(), route_ctx, activity_ctx);
 
             let curr_dis = route_ctx.state.get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
             let curr_dur = route_ctx.state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
@@ -340,48 +382,63 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
     }
 }
 
-impl TravelHardActivityConstraint {
-    fn calculate_travel(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> (Distance, Duration) {
-        let actor = &route_ctx.route.actor;
-        let profile = actor.vehicle.profile;
-
-        let prev = activity_ctx.prev;
-        let tar = activity_ctx.target;
-        let next = activity_ctx.next;
-
-        let prev_dep = prev.schedule.departure;
+/// Calculates change of route distance and duration when target activity is inserted.
+fn calculate_travel(
+    transport: &(dyn TransportCost + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+) -> (Distance, Duration) {
+    let actor = &route_ctx.route.actor;
+    let profile = actor.vehicle.profile;
+
+    let prev = activity_ctx.prev;
+    let tar = activity_ctx.target;
+    let next = activity_ctx.next;
+
+    let prev_dep = prev.schedule.departure;
+
+    let prev_to_tar_location = get_prev_location(route_ctx, activity_ctx, tar.place.location);
+    let (prev_to_tar_dis, prev_to_tar_dur) =
+        calculate_leg_travel_info(transport, profile, prev_to_tar_location, tar, prev_dep);
+    if next.is_none() {
+        return (prev_to_tar_dis, prev_to_tar_dur);
+    }
 
-        let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(profile, prev, tar, prev_dep);
-        if next.is_none() {
-            return (prev_to_tar_dis, prev_to_tar_dur);
-        }
+    let next = next.unwrap();
+    let tar_dep = prev_dep + prev_to_tar_dur;
 
-        let next = next.unwrap();
-        let tar_dep = prev_dep + prev_to_tar_dur;
+    let prev_to_next_location = get_prev_location(route_ctx, activity_ctx, next.place.location);
+    let (prev_to_next_dis, prev_to_next_dur) =
+        calculate_leg_travel_info(transport, profile, prev_to_next_location, next, prev_dep);
+    let (tar_to_next_dis, tar_to_next_dur) =
+        calculate_leg_travel_info(transport, profile, tar.place.location, next, tar_dep);
 
-        let (prev_to_next_dis, prev_to_next_dur) = self.calculate_leg_travel_info(profile, prev, next, prev_dep);
-        let (tar_to_next_dis, tar_to_next_dur) = self.calculate_leg_travel_info(profile, tar, next, tar_dep);
+    (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
+}
 
-        (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
-    }
+fn calculate_leg_travel_info(
+    transport: &(dyn TransportCost + Send + Sync),
+    profile: Profile,
+    first: Location,
+    second: &TourActivity,
+    departure: Timestamp,
+) -> (Distance, Duration) {
+    let first_to_second_dis = transport.distance(profile, first, second.place.location, departure);
+    let first_to_second_dur = transport.duration(profile, first, second.place.location, departure);
+
+    let second_arr = departure + first_to_second_dur;
+    let second_wait = (second.place.time.start - second_arr).max(0.);
+    let second_dep = second_arr + second_wait + second.place.duration;
+
+    (first_to_second_dis, second_dep - departure)
+}
 
-    fn calculate_leg_travel_info(
-        &self,
-        profile: Profile,
-        first: &TourActivity,
-        second: &TourActivity,
-        departure: Timestamp,
-    ) -> (Distance, Duration) {
-        let first_to_second_dis =
-            self.transport.distance(profile, first.place.location, second.place.location, departure);
-        let first_to_second_dur =
-            self.transport.duration(profile, first.place.location, second.place.location, departure);
-
-        let second_arr = departure + first_to_second_dur;
-        let second_wait = (second.place.time.start - second_arr).max(0.);
-        let second_dep = second_arr + second_wait + second.place.duration;
-
-        (first_to_second_dis, second_dep - departure)
+/// Moves start of the tour without start location to the location of its first activity.
+fn update_open_start(ctx: &mut RouteContext) {
+    if ctx.route.actor.detail.start.is_none() {
+        if let Some(location) = ctx.route.tour.get(1).map(|activity| activity.place.location) {
+            ctx.route_mut().tour.get_mut(0).unwrap().place.location = location;
+        }
     }
 }
 
@@ -416,15 +473,14 @@ impl CostSoftActivityConstraint {
     fn analyze_route_leg(
         &self,
         actor: &Actor,
-        start: &Activity,
+        start: Location,
         end: &Activity,
         time: Timestamp,
     ) -> (Cost, Cost, Timestamp) {
-        let arrival =
-            time + self.transport.duration(actor.vehicle.profile, start.place.location, end.place.location, time);
+        let arrival = time + self.transport.duration(actor.vehicle.profile, start, end.place.location, time);
         let departure = arrival.max(end.place.time.start) + self.activity.duration(actor, end, arrival);
 
-        let transport_cost = self.transport.cost(actor, start.place.location, end.place.location, time);
+        let transport_cost = self.transport.cost(actor, start, end.place.location, time);
         let activity_cost = self.activity.cost(actor, end, arrival);
 
         (transport_cost, activity_cost, departure)
@@ -439,11 +495,12 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         let target = activity_ctx.target;
         let next = activity_ctx.next;
 
+        let prev_to_target_location = get_prev_location(route_ctx, activity_ctx, target.place.location);
         let (tp_cost_left, act_cost_left, dep_time_left) =
-            self.analyze_route_leg(actor, prev, target, prev.schedule.departure);
+            self.analyze_route_leg(actor, prev_to_target_location, target, prev.schedule.departure);
 
         let (tp_cost_right, act_cost_right, dep_time_right) = if let Some(next) = next {
-            self.analyze_route_leg(actor, target, next, dep_time_left)
+            self.analyze_route_leg(actor, target.place.location, next, dep_time_left)
         } else {
             (0., 0., 0.)
         };
@@ -458,8 +515,9 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         let next = next.unwrap();
         let waiting_time = *route_ctx.state.get_activity_state(WAITING_KEY, next).unwrap_or(&0_f64);
 
+        let prev_to_next_location = get_prev_location(route_ctx, activity_ctx, next.place.location);
         let (tp_cost_old, act_cost_old, dep_time_old) =
-            self.analyze_route_leg(actor, prev, next, prev.schedule.departure);
+            self.analyze_route_leg(actor, prev_to_next_location, next, prev.schedule.departure);
 
         let waiting_cost =
             waiting_time.min(0_f64.max(dep_time_right - dep_time_old)) * actor.vehicle.costs.per_waiting_time;
@@ -470,6 +528,31 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
     }
 }
 
+/// Estimates change of overtime costs caused by route duration change.
+struct OvertimeSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for OvertimeSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let actor = route_ctx.route.actor.as_ref();
+
+        if actor.vehicle.costs.overtime.is_none() && actor.driver.costs.overtime.is_none() {
+            return 0.;
+        }
+
+        let (_, change_duration) = calculate_travel(self.transport.as_ref(), route_ctx, activity_ctx);
+        let old_duration = route_ctx.state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+
+        get_overtime_cost(actor, old_duration + change_duration) - get_overtime_cost(actor, old_duration)
+    }
+}
+
+/// Returns overtime cost of actor for given route duration.
+pub fn get_overtime_cost(actor: &Actor, duration: Duration) -> Cost {
+    actor.vehicle.costs.overtime_cost(duration) + actor.driver.costs.overtime_cost(duration)
+}
+
 fn fail(code: i32) -> Option<ActivityConstraintViolation> {
     Some(ActivityConstraintViolation { code, stopped: true })
 }
@@ -3,8 +3,7 @@
 mod context_test;
 
 use crate::construction::heuristics::factories::*;
-use crate::construction::OP_START_MSG;
-use crate::models::common::{Cost, Schedule};
+use crate::models::common::{Cost, Location, Schedule};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Extras, Problem, Solution};
@@ -297,11 +296,12 @@ pub struct ActivityContext<'a> {
 type ActivityWithKey = (usize, i32);
 type ActivityPlace = crate::models::solution::Place;
 
-/// Creates start activity.
+/// Creates start activity. If actor has no start location, the activity is placed at the location
+/// of the first job activity once it is known.
 pub fn create_start_activity(actor: &Arc<Actor>) -> TourActivity {
     Box::new(Activity {
         place: ActivityPlace {
-            location: actor.detail.start.unwrap_or_else(|| unimplemented!("{}", OP_START_MSG)),
+            location: actor.detail.start.or(actor.detail.end).unwrap_or_default(),
             duration: 0.0,
             time: actor.detail.time.clone(),
         },
@@ -310,6 +310,16 @@ pub fn create_start_activity(actor: &Arc<Actor>) -> TourActivity {
     })
 }
 
+/// Returns location of the previous activity used to reach given location. When actor has no
+/// start location, the tour starts at the location of its first job activity.
+pub fn get_prev_location(route_ctx: &RouteContext, activity_ctx: &ActivityContext, location: Location) -> Location {
+    if activity_ctx.index == 0 && route_ctx.route.actor.detail.start.is_none() {
+        location
+    } else {
+        activity_ctx.prev.place.location
+    }
+}
+
 /// Creates end activity if it is specified for the actor.
 pub fn create_end_activity(actor: &Arc<Actor>) -> Option<TourActivity> {
     actor.detail.end.map(|location| {
@@ -7,9 +7,10 @@ use std::sync::Arc;
 use crate::construction::constraints::ActivityConstraintViolation;
 use crate::construction::heuristics::*;
 use crate::models::common::{Cost, TimeWindow};
-use crate::models::problem::{Job, Multi, Single};
+use crate::models::problem::{Job, Single};
 use crate::models::solution::{Activity, Place, TourActivity};
 use crate::models::Problem;
+use crate::utils::Random;
 
 /// Specifies allowed insertion position in route for the job.
 #[derive(Copy, Clone)]
@@ -21,15 +22,20 @@ pub enum InsertionPosition {
 }
 
 /// Evaluates possibility to preform insertion from given insertion context in all available
-/// routes at given position constraint.
-pub fn evaluate_job_insertion(job: &Job, ctx: &InsertionContext, position: InsertionPosition) -> InsertionResult {
+/// routes at given position constraint. Given `random` is used to sample multi job permutations.
+pub fn evaluate_job_insertion(
+    job: &Job,
+    ctx: &InsertionContext,
+    position: InsertionPosition,
+    random: &dyn Random,
+) -> InsertionResult {
     ctx.solution
         .routes
         .iter()
         .cloned()
         .chain(ctx.solution.registry.next().map(RouteContext::new))
         .fold(InsertionResult::make_failure(), |acc, route_ctx| {
-            evaluate_job_insertion_in_route(job, ctx, &route_ctx, position, Some(acc))
+            evaluate_job_insertion_in_route(job, ctx, &route_ctx, position, random, Some(acc))
         })
 }
 
@@ -40,6 +46,7 @@ pub fn evaluate_job_insertion_in_route(
     ctx: &InsertionContext,
     route_ctx: &RouteContext,
     position: InsertionPosition,
+    random: &dyn Random,
     alternative: Option<InsertionResult>,
 ) -> InsertionResult {
     let alternative = alternative.map_or_else(InsertionResult::make_failure, |r| r);
@@ -69,7 +76,10 @@ pub fn evaluate_job_insertion_in_route(
             Job::Single(single) => {
                 evaluate_single(job, single, ctx, &route_ctx, position, route_costs, best_known_cost)
             }
-            Job::Multi(multi) => evaluate_multi(job, multi, ctx, &route_ctx, position, route_costs, best_known_cost),
+            Job::Multi(multi) => {
+                let permutations = multi.permutations(random);
+                evaluate_multi(job, permutations, ctx, &route_ctx, position, route_costs, best_known_cost)
+            }
         },
     )
 }
@@ -104,7 +114,7 @@ fn evaluate_single(
 
 fn evaluate_multi(
     job: &Job,
-    multi: &Arc<Multi>,
+    permutations: Vec<Vec<Arc<Single>>>,
     ctx: &InsertionContext,
     route_ctx: &RouteContext,
     position: InsertionPosition,
@@ -112,7 +122,7 @@ fn evaluate_multi(
     best_known_cost: Option<Cost>,
 ) -> InsertionResult {
     // 1. analyze permutations
-    let result = unwrap_from_result(multi.permutations().into_iter().try_fold(
+    let result = unwrap_from_result(permutations.into_iter().try_fold(
         MultiContext::new(best_known_cost),
         |acc_res, services| {
             let mut shadow = ShadowContext::new(&ctx.problem, &route_ctx);
@@ -1,5 +1,4 @@
 use super::*;
-use crate::construction::OP_START_MSG;
 use crate::models::common::{Cost, Schedule};
 use crate::models::problem::*;
 use crate::models::solution::*;
@@ -27,7 +26,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, random: Arc<dyn Random +
         if let Some(actor) = actor {
             registry.use_actor(&actor);
             let mut route_ctx = RouteContext::new(actor);
-            let start = route_ctx.route.tour.start().unwrap_or_else(|| panic!(OP_START_MSG)).place.location;
+            let start = route_ctx.route.tour.start().unwrap().place.location;
 
             let create_activity = |single: Arc<Single>, previous_location: usize| {
                 assert_eq!(single.places.len(), 1);
@@ -134,9 +133,25 @@ pub fn create_insertion_context_from_solution(
         }
     });
 
-    InsertionContext {
-        problem,
+    // NOTE jobs of existing solution never pass required or ignored collections, so constraint
+    // modules cannot lock them on their own, e.g. reloads
+    let locked = locked
+        .into_iter()
+        .chain(
+            routes
+                .iter()
+                .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+                .filter(|job| problem.constraint.is_locked_job(job)),
+        )
+        .collect();
+
+    let mut ctx = InsertionContext {
+        problem: problem.clone(),
         solution: SolutionContext { required: jobs, ignored: vec![], unassigned, locked, routes, registry },
         random,
-    }
+    };
+
+    problem.constraint.accept_solution_state(&mut ctx.solution);
+
+    ctx
 }
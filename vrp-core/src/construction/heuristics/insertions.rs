@@ -4,7 +4,9 @@ use crate::construction::Quota;
 use crate::models::common::Cost;
 use crate::models::problem::Job;
 use crate::models::solution::TourActivity;
-use crate::utils::map_reduce;
+use crate::utils::{parallel_collect, SeededRandom};
+use hashbrown::HashMap;
+use rand::Rng;
 use std::borrow::Borrow;
 use std::ops::Deref;
 
@@ -89,12 +91,10 @@ impl JobMapReducer for PairJobMapReducer {
         jobs: Vec<Job>,
         map: Box<dyn Fn(&Job) -> InsertionResult + Send + Sync + 'a>,
     ) -> InsertionResult {
-        map_reduce(
-            &jobs,
-            |job| map.deref()(&job),
-            InsertionResult::make_failure,
-            |a, b| self.result_selector.select(&ctx, a, b),
-        )
+        // NOTE reduce sequentially to keep results reproducible when result selector uses random
+        parallel_collect(&jobs, |job| map.deref()(&job))
+            .into_iter()
+            .fold(InsertionResult::make_failure(), |a, b| self.result_selector.select(&ctx, a, b))
     }
 }
 
@@ -151,10 +151,24 @@ impl InsertionHeuristic {
 
         while !ctx.solution.required.is_empty() && !quota.map_or(false, |q| q.is_reached()) {
             let jobs = job_selector.select(&mut ctx).collect::<Vec<Job>>();
+
+            // NOTE jobs are evaluated in parallel, so each multi job gets its own random seeded from the
+            // context's one: shared random would make sampled permutations dependent on thread scheduling.
+            let seeds = jobs
+                .iter()
+                .filter(|job| job.as_multi().is_some())
+                .map(|job| (job.clone(), ctx.random.get_rng().gen::<u64>()))
+                .collect::<HashMap<_, _>>();
+
             let result = job_reducer.reduce(
                 &ctx,
                 jobs,
-                Box::new(|job| evaluate_job_insertion(&job, &ctx, self.insertion_position)),
+                Box::new(|job| match seeds.get(job) {
+                    Some(&seed) => {
+                        evaluate_job_insertion(&job, &ctx, self.insertion_position, &SeededRandom::new(seed))
+                    }
+                    None => evaluate_job_insertion(&job, &ctx, self.insertion_position, ctx.random.as_ref()),
+                }),
             );
             insert(result, &mut ctx);
         }
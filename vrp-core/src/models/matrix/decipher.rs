@@ -11,7 +11,7 @@ use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionCo
 use crate::models::problem::{Actor, ActorDetail, Job, Place, Single};
 use crate::models::solution::TourActivity;
 use crate::models::Problem;
-use crate::utils::DefaultRandom;
+use crate::utils::Random;
 use hashbrown::{HashMap, HashSet};
 use std::hash::Hash;
 use std::sync::Arc;
@@ -106,11 +106,11 @@ impl AdjacencyMatrixDecipher {
     }
 
     /// Decodes a feasible solution from adjacency matrix specified by `matrix` which, potentially
-    /// might define an unfeasible solution.
-    pub fn decode<T: AdjacencyMatrix>(&self, matrix: &T) -> SolutionContext {
+    /// might define an unfeasible solution. Given `random` is used by insertion context.
+    pub fn decode<T: AdjacencyMatrix>(&self, matrix: &T, random: Arc<dyn Random + Send + Sync>) -> SolutionContext {
         // NOTE A new context already contains routes with locked jobs which is important as
         // passed AM solution might ignore these rules.
-        let mut ctx = InsertionContext::new(self.problem.clone(), Arc::new(DefaultRandom::default()));
+        let mut ctx = InsertionContext::new(self.problem.clone(), random);
         ctx.problem.constraint.accept_solution_state(&mut ctx.solution);
 
         let mut unprocessed =
@@ -57,8 +57,14 @@ impl<'a> ActivityInfoInserter<'a> {
         activity_info_idx: usize,
     ) -> bool {
         let single = Job::Single(single);
-        let result =
-            evaluate_job_insertion_in_route(&single, self.insertion_ctx, self.route_ctx, InsertionPosition::Last, None);
+        let result = evaluate_job_insertion_in_route(
+            &single,
+            self.insertion_ctx,
+            self.route_ctx,
+            InsertionPosition::Last,
+            self.insertion_ctx.random.as_ref(),
+            None,
+        );
 
         match result {
             InsertionResult::Success(success) => {
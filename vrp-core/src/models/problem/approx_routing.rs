@@ -0,0 +1,102 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/approx_routing_test.rs"]
+mod approx_routing_test;
+
+use crate::models::common::{Distance, Duration};
+
+/// Specifies a metric used to approximate distance between two points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistanceMetric {
+    /// A great-circle distance in meters between two points given by latitude and longitude in degrees.
+    Haversine,
+    /// A sum of absolute differences of point coordinates.
+    Manhattan,
+    /// A straight line distance between two points.
+    Euclidean,
+}
+
+/// Specifies the way to approximate routing data when no real routing matrix is available.
+#[derive(Clone, Debug)]
+pub struct ApproxRouting {
+    /// An average speed: distance units per time unit.
+    pub speed: f64,
+    /// A circuity factor: ratio between actual travel distance and the one given by metric.
+    pub circuity: f64,
+    /// A distance metric.
+    pub metric: DistanceMetric,
+}
+
+impl ApproxRouting {
+    /// Creates a new instance of `ApproxRouting`.
+    pub fn new(speed: f64, circuity: f64, metric: DistanceMetric) -> Self {
+        Self { speed, circuity, metric }
+    }
+
+    /// Returns approximated distance between two points.
+    pub fn distance(&self, from: (f64, f64), to: (f64, f64)) -> Distance {
+        let distance = match self.metric {
+            DistanceMetric::Haversine => get_haversine_distance(from, to),
+            DistanceMetric::Manhattan => (from.0 - to.0).abs() + (from.1 - to.1).abs(),
+            DistanceMetric::Euclidean => ((from.0 - to.0).powi(2) + (from.1 - to.1).powi(2)).sqrt(),
+        };
+
+        distance * self.circuity
+    }
+
+    /// Returns approximated durations and distances between all points as flatten square matrices.
+    pub fn get_matrices(&self, points: &[(f64, f64)]) -> (Vec<Duration>, Vec<Distance>) {
+        points
+            .iter()
+            .flat_map(|&from| {
+                points.iter().map(move |&to| {
+                    let distance = self.distance(from, to);
+                    (distance / self.speed, distance)
+                })
+            })
+            .unzip()
+    }
+}
+
+impl Default for ApproxRouting {
+    fn default() -> Self {
+        Self { speed: 1., circuity: 1., metric: DistanceMetric::Euclidean }
+    }
+}
+
+/// Gets distance between two points using haversine formula.
+fn get_haversine_distance(p1: (f64, f64), p2: (f64, f64)) -> f64 {
+    let d_lat = degree_rad(p1.0 - p2.0);
+    let d_lng = degree_rad(p1.1 - p2.1);
+
+    let lat1 = degree_rad(p1.0);
+    let lat2 = degree_rad(p2.0);
+
+    let a =
+        (d_lat / 2.).sin() * (d_lat / 2.).sin() + (d_lng / 2.).sin() * (d_lng / 2.).sin() * (lat1).cos() * (lat2).cos();
+    let c = 2. * a.sqrt().atan2((1. - a).sqrt());
+
+    let radius = wgs84_earth_radius(d_lat);
+
+    radius * c
+}
+
+/// Converts degrees to radians.
+#[inline(always)]
+fn degree_rad(degrees: f64) -> f64 {
+    std::f64::consts::PI * degrees / 180.
+}
+
+#[inline(always)]
+fn wgs84_earth_radius(lat: f64) -> f64 {
+    // Semi-axes of WGS-84 geoidal reference
+    const WGS84_A: f64 = 6378137.0; // Major semiaxis [m]
+    const WGS84_B: f64 = 6356752.3; // Minor semiaxis [m]
+
+    // http://en.wikipedia.org/wiki/Earth_radius
+    let an = WGS84_A * WGS84_A * lat.cos();
+    let bn = WGS84_B * WGS84_B * lat.sin();
+    let ad = WGS84_A * lat.cos();
+    let bd = WGS84_B * lat.sin();
+
+    ((an * an + bn * bn) / (ad * ad + bd * bd)).sqrt()
+}
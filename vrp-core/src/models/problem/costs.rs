@@ -5,7 +5,7 @@ mod costs_test;
 use crate::models::common::{Cost, Distance, Duration, Location, Profile, Timestamp};
 use crate::models::problem::Actor;
 use crate::models::solution::Activity;
-use crate::utils::CollectGroupBy;
+use crate::utils::{compare_floats, CollectGroupBy};
 use hashbrown::HashMap;
 use std::sync::Arc;
 
@@ -143,8 +143,12 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
 }
 
 /// A time aware matrix costs.
+///
+/// Durations and distances are linearly interpolated between matrices of the same profile. Travel is
+/// kept FIFO consistent: departing later never results in an earlier arrival, as a vehicle can always
+/// wait for the moment when travelling becomes faster.
 struct TimeAwareMatrixTransportCost {
-    costs: HashMap<Profile, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<Profile, Vec<MatrixData>>,
     size: usize,
 }
 
@@ -164,55 +168,82 @@ impl TimeAwareMatrixTransportCost {
         let costs = costs
             .into_iter()
             .map(|(profile, mut matrices)| {
-                matrices.sort_by(|a, b| (a.timestamp.unwrap() as u64).cmp(&(b.timestamp.unwrap() as u64)));
-                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+                matrices.sort_by(|a, b| compare_floats(a.timestamp.unwrap(), b.timestamp.unwrap()));
 
-                (profile, (timestamps, matrices))
+                (profile, matrices)
             })
             .collect();
 
         Ok(Self { costs, size })
     }
-}
 
-impl TransportCost for TimeAwareMatrixTransportCost {
-    fn duration(&self, profile: Profile, from: Location, to: Location, timestamp: Timestamp) -> Duration {
-        let (timestamps, matrices) = self.costs.get(&profile).unwrap();
+    /// Returns travel duration and distance for given departure time.
+    fn travel(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> (Duration, Distance) {
+        let matrices = self.costs.get(&profile).unwrap();
         let data_idx = from * self.size + to;
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().durations.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().durations.get(data_idx).unwrap()
-            }
-            Err(matrix_idx) => {
-                let left_matrix = matrices.get(matrix_idx - 1).unwrap();
-                let right_matrix = matrices.get(matrix_idx).unwrap();
-
-                let left_value = *matrices.get(matrix_idx - 1).unwrap().durations.get(data_idx).unwrap();
-                let right_value = *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap();
-
-                // perform linear interpolation
-                let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                    / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
-
-                left_value + ratio * (right_value - left_value)
-            }
+        let (duration, distance) = interpolate(matrices.as_slice(), data_idx, departure);
+
+        // NOTE negative duration is used to mark unreachable locations
+        if duration < 0. {
+            return (duration, distance);
         }
+
+        // NOTE within each interval arrival time changes linearly, so the earliest arrival time for the
+        // given or any later departure is either at departure itself or at one of the following timestamps
+        let (arrival, distance) = matrices
+            .iter()
+            .filter(|matrix| matrix.timestamp.unwrap() > departure)
+            .map(|matrix| (matrix.timestamp.unwrap(), matrix.durations[data_idx], matrix.distances[data_idx]))
+            .filter(|(_, duration, _)| *duration >= 0.)
+            .fold(
+                (departure + duration, distance),
+                |(best_arrival, best_distance), (timestamp, duration, distance)| {
+                    let arrival = timestamp + duration;
+                    if arrival < best_arrival {
+                        (arrival, distance)
+                    } else {
+                        (best_arrival, best_distance)
+                    }
+                },
+            );
+
+        (arrival - departure, distance)
     }
+}
 
-    fn distance(&self, profile: Profile, from: Location, to: Location, timestamp: Timestamp) -> Distance {
-        let (timestamps, matrices) = self.costs.get(&profile).unwrap();
-        let data_idx = from * self.size + to;
+impl TransportCost for TimeAwareMatrixTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.travel(profile, from, to, departure).0
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.travel(profile, from, to, departure).1
+    }
+}
+
+/// Interpolates duration and distance linearly between two matrices surrounding given timestamp.
+fn interpolate(matrices: &[MatrixData], data_idx: usize, timestamp: Timestamp) -> (Duration, Distance) {
+    let get_values = |matrix: &MatrixData| (matrix.durations[data_idx], matrix.distances[data_idx]);
+
+    match matrices.iter().position(|matrix| matrix.timestamp.unwrap() > timestamp) {
+        Some(0) => get_values(matrices.first().unwrap()),
+        None => get_values(matrices.last().unwrap()),
+        Some(matrix_idx) => {
+            let left_matrix = matrices.get(matrix_idx - 1).unwrap();
+            let right_matrix = matrices.get(matrix_idx).unwrap();
+
+            let (left_duration, left_distance) = get_values(left_matrix);
+            let (right_duration, right_distance) = get_values(right_matrix);
+
+            // perform linear interpolation
+            let ratio = (timestamp - left_matrix.timestamp.unwrap())
+                / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().distances.get(data_idx).unwrap()
-            }
-            Err(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
+            (
+                left_duration + ratio * (right_duration - left_duration),
+                left_distance + ratio * (right_distance - left_distance),
+            )
         }
     }
 }
@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/models/problem/fleet_test.rs"]
 mod fleet_test;
 
-use crate::models::common::{Dimensions, Location, Profile, TimeWindow};
+use crate::models::common::{Cost, Dimensions, Duration, Location, Profile, TimeWindow};
 use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering::Less;
 use std::hash::{Hash, Hasher};
@@ -21,9 +21,29 @@ pub struct Costs {
     pub per_waiting_time: f64,
     /// Cost per service time unit.
     pub per_service_time: f64,
+    /// Overtime costs applied when route duration exceeds threshold.
+    pub overtime: Option<OvertimeCosts>,
+}
+
+/// Specifies piecewise time costs: each time unit of route duration beyond threshold
+/// is charged at extra rate in addition to regular time costs.
+#[derive(Clone, Debug)]
+pub struct OvertimeCosts {
+    /// A route duration after which overtime starts.
+    pub threshold: Duration,
+    /// An extra cost per overtime unit.
+    pub per_time: f64,
+}
+
+impl Costs {
+    /// Returns overtime cost for given route duration.
+    pub fn overtime_cost(&self, duration: Duration) -> Cost {
+        self.overtime.as_ref().map_or(0., |overtime| (duration - overtime.threshold).max(0.) * overtime.per_time)
+    }
 }
 
 /// Represents driver detail.
+#[derive(Clone, Debug)]
 pub struct DriverDetail {
     /// Time windows when driver can work.
     pub time: Option<TimeWindow>,
@@ -33,7 +53,10 @@ pub struct DriverDetail {
 /// Introduced to allow the following scenarios:
 /// * reuse vehicle multiple times with different drivers
 /// * solve best driver-vehicle match problem.
-/// NOTE: At the moment, it is not used.
+///
+/// A driver with details can drive only one vehicle at a time within its working time. A driver
+/// without details has no working time restrictions and can be paired with any amount of vehicles
+/// at the same time: it is used when drivers are not modeled explicitly.
 pub struct Driver {
     /// Specifies operating costs for driver.
     pub costs: Costs,
@@ -102,10 +125,10 @@ pub struct Fleet {
 }
 
 impl Fleet {
-    /// Creates a new fleet.
+    /// Creates a new fleet. Actors are created for each valid driver-vehicle pairing: for each
+    /// vehicle detail and driver detail which working times intersect.
     pub fn new(drivers: Vec<Arc<Driver>>, vehicles: Vec<Arc<Vehicle>>, group_key: ActorGroupKeyFn) -> Fleet {
-        // TODO we should also consider multiple drivers to support smart vehicle-driver assignment.
-        assert_eq!(drivers.len(), 1);
+        assert!(!drivers.is_empty());
         assert!(!vehicles.is_empty());
 
         let profiles: HashSet<Profile> = vehicles.iter().map(|v| v.profile).collect();
@@ -115,15 +138,30 @@ impl Fleet {
         let mut actors: Vec<Arc<Actor>> = Default::default();
         vehicles.iter().for_each(|vehicle| {
             vehicle.details.iter().for_each(|detail| {
-                actors.push(Arc::new(Actor {
-                    vehicle: vehicle.clone(),
-                    driver: drivers.first().unwrap().clone(),
-                    detail: ActorDetail {
-                        start: detail.start,
-                        end: detail.end,
-                        time: detail.time.clone().unwrap_or(TimeWindow { start: 0.0, end: std::f64::MAX }),
-                    },
-                }));
+                let vehicle_time = detail.time.clone().unwrap_or(TimeWindow { start: 0.0, end: std::f64::MAX });
+
+                drivers.iter().for_each(|driver| {
+                    let times = if driver.details.is_empty() {
+                        vec![vehicle_time.clone()]
+                    } else {
+                        driver
+                            .details
+                            .iter()
+                            .filter_map(|driver_detail| match &driver_detail.time {
+                                Some(driver_time) => get_intersection(&vehicle_time, driver_time),
+                                None => Some(vehicle_time.clone()),
+                            })
+                            .collect()
+                    };
+
+                    actors.extend(times.into_iter().map(|time| {
+                        Arc::new(Actor {
+                            vehicle: vehicle.clone(),
+                            driver: driver.clone(),
+                            detail: ActorDetail { start: detail.start, end: detail.end, time },
+                        })
+                    }));
+                });
             });
         });
 
@@ -137,6 +175,18 @@ impl Fleet {
     }
 }
 
+/// Returns intersection of two time windows if it is not empty.
+fn get_intersection(first: &TimeWindow, second: &TimeWindow) -> Option<TimeWindow> {
+    let start = first.start.max(second.start);
+    let end = first.end.min(second.end);
+
+    if start < end {
+        Some(TimeWindow::new(start, end))
+    } else {
+        None
+    }
+}
+
 impl Hash for Costs {
     fn hash<H: Hasher>(&self, state: &mut H) {
         let fixed: i64 = unsafe { std::mem::transmute(self.fixed) };
@@ -150,6 +200,11 @@ impl Hash for Costs {
         per_driving_time.hash(state);
         per_service_time.hash(state);
         per_waiting_time.hash(state);
+
+        if let Some(overtime) = &self.overtime {
+            overtime.threshold.to_bits().hash(state);
+            overtime.per_time.to_bits().hash(state);
+        }
     }
 }
 
@@ -162,6 +217,11 @@ impl PartialEq for Costs {
             && self.per_driving_time == other.per_driving_time
             && self.per_service_time == other.per_service_time
             && self.per_waiting_time == other.per_waiting_time
+            && match (&self.overtime, &other.overtime) {
+                (Some(left), Some(right)) => left.threshold == right.threshold && left.per_time == right.per_time,
+                (None, None) => true,
+                _ => false,
+            }
     }
 }
 
@@ -4,11 +4,12 @@ mod jobs_test;
 
 use crate::models::common::*;
 use crate::models::problem::{Fleet, TransportCost};
+use crate::utils::{parallel_collect, Random};
 use hashbrown::HashMap;
 use std::cell::UnsafeCell;
 use std::cmp::Ordering::Less;
 use std::hash::{Hash, Hasher};
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, OnceLock, Weak};
 
 /// Represents a job variant.
 #[derive(Clone)]
@@ -89,8 +90,8 @@ pub struct Multi {
 /// Defines a trait to work with multi job's permutations.
 pub trait JobPermutation {
     // TODO fix all implementations to support returning reference
-    /// Returns a valid permutation.
-    fn get(&self) -> Vec<Vec<usize>>;
+    /// Returns a valid permutation. Given `random` should be used when permutations are sampled.
+    fn get(&self, random: &dyn Random) -> Vec<Vec<usize>>;
 
     /// Validates given permutation.
     fn validate(&self, permutation: &[usize]) -> bool;
@@ -109,7 +110,7 @@ impl FixedJobPermutation {
 }
 
 impl JobPermutation for FixedJobPermutation {
-    fn get(&self) -> Vec<Vec<usize>> {
+    fn get(&self, _: &dyn Random) -> Vec<Vec<usize>> {
         self.permutations.clone()
     }
 
@@ -138,9 +139,9 @@ impl Multi {
     }
 
     /// Returns all sub-jobs permutations.
-    pub fn permutations(&self) -> Vec<Vec<Arc<Single>>> {
+    pub fn permutations(&self, random: &dyn Random) -> Vec<Vec<Arc<Single>>> {
         self.permutator
-            .get()
+            .get(random)
             .iter()
             .map(|perm| perm.iter().map(|&i| self.jobs.get(i).unwrap().clone()).collect())
             .collect()
@@ -177,18 +178,56 @@ impl Multi {
     }
 }
 
-type JobIndex = HashMap<Job, (Vec<(Job, Cost)>, Cost)>;
+/// Specifies how job neighbourhood index is built.
+#[derive(Clone)]
+pub struct JobIndexOptions {
+    /// Max amount of nearest neighbours kept per job. If not set, all jobs are kept.
+    pub max_neighbours: Option<usize>,
+    /// If true, neighbours of a job are calculated on first access instead of during construction.
+    pub is_lazy: bool,
+}
+
+impl Default for JobIndexOptions {
+    fn default() -> Self {
+        Self { max_neighbours: Some(DEFAULT_MAX_NEIGHBOURS), is_lazy: false }
+    }
+}
+
+/// Keeps neighbours and rank of each job for specific profile.
+struct JobIndex {
+    neighbours: Vec<OnceLock<Vec<(usize, Cost)>>>,
+    ranks: Vec<Cost>,
+}
 
 /// Stores all jobs taking into account their neighborhood.
 pub struct Jobs {
     jobs: Vec<Job>,
+    positions: HashMap<Job, usize>,
     index: HashMap<Profile, JobIndex>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    max_neighbours: usize,
 }
 
 impl Jobs {
-    /// Creates a new [`Jobs`].
+    /// Creates a new [`Jobs`] using default index options.
     pub fn new(fleet: &Fleet, jobs: Vec<Job>, transport: &Arc<dyn TransportCost + Send + Sync>) -> Jobs {
-        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport) }
+        Self::new_with_options(fleet, jobs, transport, JobIndexOptions::default())
+    }
+
+    /// Creates a new [`Jobs`] with given index options.
+    pub fn new_with_options(
+        fleet: &Fleet,
+        jobs: Vec<Job>,
+        transport: &Arc<dyn TransportCost + Send + Sync>,
+        options: JobIndexOptions,
+    ) -> Jobs {
+        let positions = jobs.iter().cloned().enumerate().map(|(idx, job)| (job, idx)).collect();
+        let max_neighbours = options.max_neighbours.unwrap_or(jobs.len());
+
+        let jobs = Jobs { jobs, positions, index: HashMap::new(), transport: transport.clone(), max_neighbours };
+        let index = create_index(fleet, &jobs, options.is_lazy);
+
+        Jobs { index, ..jobs }
     }
 
     /// Returns all jobs in original order.
@@ -205,26 +244,51 @@ impl Jobs {
         _: Timestamp,
         max_cost: Cost,
     ) -> impl Iterator<Item = Job> + 'a {
-        self.index
-            .get(&profile)
-            .unwrap()
-            .get(job)
-            .unwrap()
-            .0
+        let job_idx = *self.positions.get(job).unwrap();
+
+        self.index.get(&profile).unwrap().neighbours[job_idx]
+            .get_or_init(|| self.get_nearest_jobs(profile, job_idx))
             .iter()
             .filter(move |(_, cost)| *cost > 0. && *cost < max_cost)
-            .map(|(j, _)| j.clone())
+            .map(move |(idx, _)| self.jobs[*idx].clone())
     }
 
     /// Returns job rank as relative cost from any vehicle's start position.
     pub fn rank(&self, profile: Profile, job: &Job) -> Cost {
-        self.index.get(&profile).unwrap().get(job).unwrap().1
+        self.index.get(&profile).unwrap().ranks[*self.positions.get(job).unwrap()]
     }
 
     /// Returns amount of jobs.
     pub fn size(&self) -> usize {
         self.jobs.len()
     }
+
+    /// Returns nearest jobs to the job with given index sorted by cost.
+    fn get_nearest_jobs(&self, profile: Profile, job_idx: usize) -> Vec<(usize, Cost)> {
+        let job = &self.jobs[job_idx];
+        let mut job_costs: Vec<(usize, Cost)> = self
+            .jobs
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| *idx != job_idx)
+            .map(|(idx, other)| (idx, get_cost_between_jobs(profile, &self.transport, job, other)))
+            .collect();
+
+        // NOTE ties are resolved by original order, so result is the same as with full stable sort
+        let compare = |(a_idx, a_cost): &(usize, Cost), (b_idx, b_cost): &(usize, Cost)| {
+            a_cost.partial_cmp(b_cost).unwrap_or(Less).then_with(|| a_idx.cmp(b_idx))
+        };
+
+        if self.max_neighbours < job_costs.len() {
+            job_costs.select_nth_unstable_by(self.max_neighbours, compare);
+            job_costs.truncate(self.max_neighbours);
+        }
+
+        job_costs.sort_by(compare);
+        job_costs.shrink_to_fit();
+
+        job_costs
+    }
 }
 
 impl PartialEq<Job> for Job {
@@ -258,45 +322,39 @@ impl Hash for Job {
 // TODO: we don't know actual departure and zero-cost when we create job index.
 const DEFAULT_DEPARTURE: Timestamp = 0.0;
 const DEFAULT_COST: Cost = 0.0;
+const DEFAULT_MAX_NEIGHBOURS: usize = 128;
 
 /// Creates job index.
-fn create_index(
-    fleet: &Fleet,
-    jobs: Vec<Job>,
-    transport: &Arc<dyn TransportCost + Send + Sync>,
-) -> HashMap<Profile, JobIndex> {
+fn create_index(fleet: &Fleet, jobs: &Jobs, is_lazy: bool) -> HashMap<Profile, JobIndex> {
     fleet.profiles.iter().cloned().fold(HashMap::new(), |mut acc, profile| {
-        // get all possible start positions for given profile
-        let starts: Vec<Location> = fleet
+        // get all possible start positions for given profile: vehicle without start location
+        // can start at any job
+        let starts: Vec<Option<Location>> = fleet
             .vehicles
             .iter()
             .filter(|v| v.profile == profile)
             .flat_map(|v| v.details.iter().map(|d| d.start))
-            .filter(|s| s.is_some())
-            .map(|s| s.unwrap())
             .collect();
 
-        // create job index
-        let item = jobs.iter().cloned().fold(HashMap::new(), |mut acc, job| {
-            let mut job_costs: Vec<(Job, Cost)> = jobs
-                .iter()
-                .filter(|j| **j != job)
-                .map(|j| (j.clone(), get_cost_between_jobs(profile, transport, &job, j)))
-                .collect();
-            job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
-
-            let fleet_costs = starts
+        let ranks = parallel_collect(&jobs.jobs, |job| {
+            starts
                 .iter()
                 .cloned()
-                .map(|s| get_cost_between_job_and_location(profile, transport, &job, s))
+                .map(|s| {
+                    s.map_or(DEFAULT_COST, |s| get_cost_between_job_and_location(profile, &jobs.transport, job, s))
+                })
                 .min_by(|a, b| a.partial_cmp(b).unwrap_or(Less))
-                .unwrap_or(DEFAULT_COST);
-
-            acc.insert(job, (job_costs, fleet_costs));
-            acc
+                .unwrap_or(DEFAULT_COST)
         });
 
-        acc.insert(profile, item);
+        let neighbours = if is_lazy {
+            (0..jobs.jobs.len()).map(|_| OnceLock::new()).collect()
+        } else {
+            let indices = (0..jobs.jobs.len()).collect::<Vec<_>>();
+            parallel_collect(&indices, |&job_idx| OnceLock::from(jobs.get_nearest_jobs(profile, job_idx)))
+        };
+
+        acc.insert(profile, JobIndex { neighbours, ranks });
         acc
     })
 }
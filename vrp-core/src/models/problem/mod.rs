@@ -1,5 +1,8 @@
 //! Problem domain models.
 
+mod approx_routing;
+pub use self::approx_routing::*;
+
 mod costs;
 pub use self::costs::*;
 
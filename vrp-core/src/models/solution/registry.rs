@@ -7,10 +7,15 @@ use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
+/// Actors which share the same driver or the same vehicle at the same time are in conflict:
+/// when one of them is used, others are not available.
 pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
+    conflicts: Arc<HashMap<Arc<Actor>, Vec<Arc<Actor>>>>,
+    blocked: HashMap<Arc<Actor>, usize>,
+    used: HashSet<Arc<Actor>>,
 }
 
 impl Registry {
@@ -22,17 +27,64 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec() }
+        Self {
+            available: fleet.groups.clone(),
+            index,
+            all: fleet.actors.to_vec(),
+            conflicts: Arc::new(get_conflicts(fleet.actors.as_slice())),
+            blocked: Default::default(),
+            used: Default::default(),
+        }
     }
 
-    /// Removes actor from the list of available actors.
+    /// Removes actor and actors in conflict with it from the list of available actors.
     pub fn use_actor(&mut self, actor: &Arc<Actor>) {
-        self.available.get_mut(self.index.get(actor).unwrap()).unwrap().remove(actor);
+        self.set_available(actor, false);
+
+        if !self.used.insert(actor.clone()) {
+            return;
+        }
+
+        if let Some(conflicts) = self.conflicts.clone().get(actor) {
+            conflicts.iter().for_each(|other| {
+                let blocked = self.blocked.entry(other.clone()).or_insert(0);
+                *blocked += 1;
+
+                self.set_available(other, false);
+            });
+        }
     }
 
-    /// Adds actor to the list of available actors.
+    /// Adds actor and actors in conflict with it to the list of available actors if they are not
+    /// used or blocked by other actors.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) {
-        self.available.get_mut(self.index.get(actor).unwrap()).unwrap().insert(actor.clone());
+        if !self.blocked.contains_key(actor) {
+            self.set_available(actor, true);
+        }
+
+        if !self.used.remove(actor) {
+            return;
+        }
+
+        if let Some(conflicts) = self.conflicts.clone().get(actor) {
+            conflicts.iter().for_each(|other| {
+                let is_unblocked = match self.blocked.get_mut(other) {
+                    Some(blocked) if *blocked > 1 => {
+                        *blocked -= 1;
+                        false
+                    }
+                    Some(_) => {
+                        self.blocked.remove(other);
+                        true
+                    }
+                    None => false,
+                };
+
+                if is_unblocked && !self.used.contains(other) {
+                    self.set_available(other, true);
+                }
+            });
+        }
     }
 
     /// Returns all actors.
@@ -52,6 +104,67 @@ impl Registry {
 
     /// Creates a deep copy of registry.
     pub fn deep_copy(&self) -> Self {
-        Self { available: self.available.clone(), index: self.index.clone(), all: self.all.clone() }
+        Self {
+            available: self.available.clone(),
+            index: self.index.clone(),
+            all: self.all.clone(),
+            conflicts: self.conflicts.clone(),
+            blocked: self.blocked.clone(),
+            used: self.used.clone(),
+        }
+    }
+
+    fn set_available(&mut self, actor: &Arc<Actor>, is_available: bool) {
+        let actors = self.available.get_mut(self.index.get(actor).unwrap()).unwrap();
+
+        if is_available {
+            actors.insert(actor.clone());
+        } else {
+            actors.remove(actor);
+        }
     }
 }
+
+/// Returns actors which cannot be used together with each actor: the same driver with working
+/// time restrictions or the same vehicle driven by different drivers at overlapping time.
+fn get_conflicts(actors: &[Arc<Actor>]) -> HashMap<Arc<Actor>, Vec<Arc<Actor>>> {
+    let by_driver = actors.iter().filter(|actor| !actor.driver.details.is_empty()).fold(
+        HashMap::<_, Vec<_>>::new(),
+        |mut acc, actor| {
+            acc.entry(Arc::as_ptr(&actor.driver)).or_default().push(actor.clone());
+            acc
+        },
+    );
+
+    let by_vehicle = actors.iter().fold(HashMap::<_, Vec<_>>::new(), |mut acc, actor| {
+        acc.entry(Arc::as_ptr(&actor.vehicle)).or_default().push(actor.clone());
+        acc
+    });
+
+    let is_overlapping = |a: &Arc<Actor>, b: &Arc<Actor>| {
+        a.detail.time.start < b.detail.time.end && b.detail.time.start < a.detail.time.end
+    };
+
+    let driver_conflicts = by_driver.values().flat_map(|group| {
+        group.iter().flat_map(move |a| {
+            group.iter().filter(move |b| a != *b && is_overlapping(a, b)).map(move |b| (a.clone(), b.clone()))
+        })
+    });
+
+    let vehicle_conflicts = by_vehicle.values().flat_map(|group| {
+        group.iter().flat_map(move |a| {
+            group
+                .iter()
+                .filter(move |b| !Arc::ptr_eq(&a.driver, &b.driver) && is_overlapping(a, b))
+                .map(move |b| (a.clone(), b.clone()))
+        })
+    });
+
+    driver_conflicts.chain(vehicle_conflicts).fold(HashMap::new(), |mut acc, (actor, other)| {
+        let conflicts: &mut Vec<Arc<Actor>> = acc.entry(actor).or_default();
+        if !conflicts.contains(&other) {
+            conflicts.push(other);
+        }
+        acc
+    })
+}
@@ -1,6 +1,9 @@
 //! Contains logic which responsible for decision whether some solution is improvement.
 
 use crate::refinement::{Individuum, RefinementContext};
+use crate::utils::Timer;
+use std::cmp::Ordering;
+use std::sync::Mutex;
 
 /// Specifies solution acceptance logic.
 pub trait Acceptance {
@@ -8,5 +11,65 @@ pub trait Acceptance {
     fn is_accepted(&self, refinement_ctx: &mut RefinementContext, solution: &Individuum) -> bool;
 }
 
+/// Specifies the way to measure search progress which is used by acceptance criteria to decrease
+/// their tolerance to worse solutions over time.
+pub enum CoolingSchedule {
+    /// Progress is measured as a ratio of current generation to given max generations.
+    Generations(usize),
+    /// Progress is measured as a ratio of elapsed time to given time limit in seconds.
+    /// Timer is started on the first progress request, i.e. when refinement is started.
+    Time(f64, Mutex<Option<Timer>>),
+}
+
+impl CoolingSchedule {
+    /// Creates a new schedule which is tied to generations.
+    pub fn new_with_generations(max_generations: usize) -> Self {
+        CoolingSchedule::Generations(max_generations.max(1))
+    }
+
+    /// Creates a new schedule which is tied to time limit. Time is counted since refinement start.
+    pub fn new_with_time(max_time: f64) -> Self {
+        CoolingSchedule::Time(max_time, Mutex::new(None))
+    }
+
+    /// Returns search progress in range [0, 1].
+    pub fn get_progress(&self, refinement_ctx: &RefinementContext) -> f64 {
+        let progress = match self {
+            CoolingSchedule::Generations(limit) => refinement_ctx.generation as f64 / *limit as f64,
+            CoolingSchedule::Time(limit, timer) => {
+                timer.lock().unwrap().get_or_insert_with(Timer::start).elapsed_secs_as_f64() / limit
+            }
+        };
+
+        progress.clamp(0., 1.)
+    }
+}
+
+/// Returns relative deviation of solution value from the best known one or `None` if there is
+/// no known best or solution is an improvement. Solution with equal cost has zero deviation. When
+/// solution is worse, but its value is not (e.g. it has more unassigned jobs), infinity is returned.
+fn get_relative_deviation(refinement_ctx: &RefinementContext, solution: &Individuum) -> Option<f64> {
+    let (_, cost, _) = solution;
+    let (_, best, _) = refinement_ctx.population.best()?;
+
+    let deviation = (cost.value() - best.value()) / best.value().abs().max(f64::EPSILON);
+
+    match cost.cmp_relaxed(best).0 {
+        Ordering::Less => None,
+        Ordering::Equal => Some(deviation.max(0.)),
+        Ordering::Greater if deviation > 0. => Some(deviation),
+        Ordering::Greater => Some(f64::INFINITY),
+    }
+}
+
 mod greedy;
 pub use self::greedy::Greedy;
+
+mod simulated_annealing;
+pub use self::simulated_annealing::SimulatedAnnealing;
+
+mod threshold_accepting;
+pub use self::threshold_accepting::ThresholdAccepting;
+
+mod record_to_record;
+pub use self::record_to_record::RecordToRecord;
@@ -0,0 +1,33 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/refinement/acceptance/record_to_record_test.rs"]
+mod record_to_record_test;
+
+use super::*;
+
+/// Record-to-record travel acceptance which accepts worse solutions if their relative deviation
+/// from the best known solution (record) does not exceed fixed deviation.
+pub struct RecordToRecord {
+    deviation: f64,
+}
+
+impl RecordToRecord {
+    /// Creates a new instance of [`RecordToRecord`].
+    pub fn new(deviation: f64) -> Self {
+        Self { deviation }
+    }
+}
+
+impl Default for RecordToRecord {
+    fn default() -> Self {
+        Self::new(0.01)
+    }
+}
+
+impl Acceptance for RecordToRecord {
+    fn is_accepted(&self, refinement_ctx: &mut RefinementContext, solution: &Individuum) -> bool {
+        match get_relative_deviation(refinement_ctx, solution) {
+            Some(deviation) => deviation <= self.deviation,
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/refinement/acceptance/simulated_annealing_test.rs"]
+mod simulated_annealing_test;
+
+use super::*;
+
+/// Simulated annealing acceptance which accepts worse solutions with probability `exp(-d/t)`,
+/// where `d` is relative deviation from the best known solution and `t` is temperature. The
+/// temperature decreases geometrically from initial to final value according to cooling schedule.
+pub struct SimulatedAnnealing {
+    initial_temperature: f64,
+    final_temperature: f64,
+    schedule: CoolingSchedule,
+}
+
+impl SimulatedAnnealing {
+    /// Creates a new instance of [`SimulatedAnnealing`]. Returns error if some of temperatures is
+    /// not positive.
+    pub fn new(initial_temperature: f64, final_temperature: f64, schedule: CoolingSchedule) -> Result<Self, String> {
+        if initial_temperature > 0. && final_temperature > 0. {
+            Ok(Self { initial_temperature, final_temperature, schedule })
+        } else {
+            Err("annealing temperatures should be greater than zero".to_string())
+        }
+    }
+
+    /// Creates a new instance of [`SimulatedAnnealing`] with default temperatures.
+    pub fn new_with_schedule(schedule: CoolingSchedule) -> Self {
+        Self { initial_temperature: 0.01, final_temperature: 0.0001, schedule }
+    }
+
+    fn get_temperature(&self, refinement_ctx: &RefinementContext) -> f64 {
+        let progress = self.schedule.get_progress(refinement_ctx);
+        self.initial_temperature * (self.final_temperature / self.initial_temperature).powf(progress)
+    }
+}
+
+impl Acceptance for SimulatedAnnealing {
+    fn is_accepted(&self, refinement_ctx: &mut RefinementContext, solution: &Individuum) -> bool {
+        match get_relative_deviation(refinement_ctx, solution) {
+            Some(deviation) if deviation.is_finite() => {
+                let probability = (-deviation / self.get_temperature(refinement_ctx)).exp();
+                solution.0.random.uniform_real(0., 1.) < probability
+            }
+            Some(_) => false,
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/refinement/acceptance/threshold_accepting_test.rs"]
+mod threshold_accepting_test;
+
+use super::*;
+
+/// Threshold accepting which accepts worse solutions if their relative deviation from the best
+/// known solution is below the threshold. The threshold decreases linearly to zero according to
+/// cooling schedule.
+pub struct ThresholdAccepting {
+    initial_threshold: f64,
+    schedule: CoolingSchedule,
+}
+
+impl ThresholdAccepting {
+    /// Creates a new instance of [`ThresholdAccepting`].
+    pub fn new(initial_threshold: f64, schedule: CoolingSchedule) -> Self {
+        Self { initial_threshold, schedule }
+    }
+
+    /// Creates a new instance of [`ThresholdAccepting`] with default initial threshold.
+    pub fn new_with_schedule(schedule: CoolingSchedule) -> Self {
+        Self::new(0.02, schedule)
+    }
+}
+
+impl Acceptance for ThresholdAccepting {
+    fn is_accepted(&self, refinement_ctx: &mut RefinementContext, solution: &Individuum) -> bool {
+        match get_relative_deviation(refinement_ctx, solution) {
+            Some(deviation) => deviation <= self.initial_threshold * (1. - self.schedule.get_progress(refinement_ctx)),
+            None => true,
+        }
+    }
+}
@@ -27,12 +27,26 @@ pub struct RefinementContext {
     pub population: Box<dyn Population + Sync + Send>,
 
     /// A collection of data associated with refinement process.
-    pub state: HashMap<String, Box<dyn Any>>,
+    pub state: HashMap<String, Box<dyn Any + Send + Sync>>,
 
     /// Specifies refinement generation (or iteration).
     pub generation: usize,
 }
 
+/// Specifies solving progress which is reported to the callback.
+pub struct Progress {
+    /// Current generation.
+    pub generation: usize,
+    /// Cost of the best known solution.
+    pub cost: f64,
+    /// Elapsed time in seconds.
+    pub elapsed: f64,
+    /// Amount of tours in the best known solution.
+    pub tours: usize,
+    /// Amount of unassigned jobs in the best known solution.
+    pub unassigned: usize,
+}
+
 /// Represents solution in population defined as actual solution, its cost, and generation
 pub type Individuum = (InsertionContext, ObjectiveCostType, usize);
 
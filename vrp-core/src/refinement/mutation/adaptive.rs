@@ -0,0 +1,134 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/refinement/mutation/adaptive_test.rs"]
+mod adaptive_test;
+
+use crate::refinement::mutation::MutationOutcome;
+use crate::refinement::RefinementContext;
+use crate::utils::Random;
+
+/// Specifies settings of adaptive operator selection used by adaptive large neighbourhood search.
+#[derive(Clone)]
+pub struct AdaptiveSettings {
+    /// Score given to operator which produced a new best solution.
+    pub best_score: f64,
+    /// Score given to operator which produced accepted, but not the best solution.
+    pub accepted_score: f64,
+    /// Score given to operator which produced rejected solution.
+    pub rejected_score: f64,
+    /// Reaction factor in range [0, 1] which controls how quickly weights react to scores.
+    pub reaction_factor: f64,
+    /// Amount of operator usages after which weights are updated.
+    pub segment_size: usize,
+}
+
+impl Default for AdaptiveSettings {
+    fn default() -> Self {
+        Self { best_score: 33., accepted_score: 9., rejected_score: 0., reaction_factor: 0.1, segment_size: 100 }
+    }
+}
+
+/// Keeps operator weights which are adjusted over segments of generations using scores of
+/// operators.
+pub struct AdaptiveWeights {
+    weights: Vec<f64>,
+    scores: Vec<f64>,
+    usages: Vec<usize>,
+    last: Option<usize>,
+    total_usages: usize,
+}
+
+impl AdaptiveWeights {
+    /// Creates a new instance of [`AdaptiveWeights`] using given initial weights.
+    pub fn new(weights: &[usize]) -> Self {
+        let size = weights.len();
+        Self {
+            weights: weights.iter().map(|&weight| weight as f64).collect(),
+            scores: vec![0.; size],
+            usages: vec![0; size],
+            last: None,
+            total_usages: 0,
+        }
+    }
+
+    /// Selects operator index using current weights and remembers it as last used.
+    pub fn select(&mut self, random: &(dyn Random + Send + Sync)) -> usize {
+        let total = self.weights.iter().sum::<f64>().max(f64::EPSILON);
+        // NOTE weighted selection works with integers, so keep three digits precision
+        let weights =
+            self.weights.iter().map(|weight| ((weight / total * 1000.).round() as usize).max(1)).collect::<Vec<_>>();
+
+        let index = random.weighted(weights.iter());
+        self.last = Some(index);
+
+        index
+    }
+
+    /// Marks operator with given index as last used.
+    pub fn set_last(&mut self, index: usize) {
+        self.last = Some(index);
+    }
+
+    /// Scores the last used operator and updates weights at the end of segment.
+    pub fn update(&mut self, outcome: &MutationOutcome, settings: &AdaptiveSettings) {
+        if let Some(index) = self.last.take() {
+            self.scores[index] += match outcome {
+                MutationOutcome::NewBest => settings.best_score,
+                MutationOutcome::Accepted => settings.accepted_score,
+                MutationOutcome::Rejected => settings.rejected_score,
+            };
+            self.usages[index] += 1;
+            self.total_usages += 1;
+        }
+
+        if self.total_usages >= settings.segment_size {
+            let reaction = settings.reaction_factor;
+            self.weights.iter_mut().zip(self.scores.iter().zip(self.usages.iter())).for_each(
+                |(weight, (&score, &usages))| {
+                    if usages > 0 {
+                        *weight = *weight * (1. - reaction) + reaction * score / usages as f64;
+                    }
+                },
+            );
+
+            self.scores.iter_mut().for_each(|score| *score = 0.);
+            self.usages.iter_mut().for_each(|usages| *usages = 0);
+            self.total_usages = 0;
+        }
+    }
+
+    /// Returns current weights.
+    pub fn weights(&self) -> &[f64] {
+        self.weights.as_slice()
+    }
+}
+
+/// Gets adaptive weights stored in refinement context's state using given key or creates them.
+pub(crate) fn get_adaptive_weights<'a>(
+    refinement_ctx: &'a mut RefinementContext,
+    key: &str,
+    weights: &[usize],
+) -> &'a mut AdaptiveWeights {
+    refinement_ctx
+        .state
+        .entry(key.to_string())
+        .or_insert_with(|| Box::new(AdaptiveWeights::new(weights)))
+        .downcast_mut::<AdaptiveWeights>()
+        .expect("unexpected adaptive weights type")
+}
+
+/// Returns formatted adaptive weights stored in refinement context's state using given key.
+pub(crate) fn format_adaptive_weights(
+    refinement_ctx: &RefinementContext,
+    key: &str,
+    names: &[String],
+) -> Option<String> {
+    refinement_ctx.state.get(key).and_then(|state| state.downcast_ref::<AdaptiveWeights>()).map(|adaptive| {
+        adaptive
+            .weights()
+            .iter()
+            .zip(names.iter())
+            .map(|(weight, name)| format!("{}: {:.2}", name, weight))
+            .collect::<Vec<_>>()
+            .join(", ")
+    })
+}
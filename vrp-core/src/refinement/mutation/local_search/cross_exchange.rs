@@ -0,0 +1,57 @@
+use super::*;
+
+/// A local search operator which swaps segments of few consecutive activities between two routes.
+pub struct CrossExchange {
+    max_segment: usize,
+}
+
+impl Default for CrossExchange {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl CrossExchange {
+    /// Creates a new instance of [`CrossExchange`] with given maximum segment size.
+    pub fn new(max_segment: usize) -> Self {
+        Self { max_segment }
+    }
+}
+
+impl LocalOperator for CrossExchange {
+    fn get_moves(&self, insertion_ctx: &InsertionContext) -> Vec<LocalMove> {
+        select_route_pair(insertion_ctx).map_or_else(Vec::new, |(source_idx, target_idx)| {
+            let source = get_sequence(insertion_ctx, source_idx);
+            let target = get_sequence(insertion_ctx, target_idx);
+            let original = estimate_sequence(insertion_ctx, source_idx, source.as_slice())
+                + estimate_sequence(insertion_ctx, target_idx, target.as_slice());
+
+            let target_segments = get_movable_segments(insertion_ctx, target.as_slice(), self.max_segment);
+
+            get_movable_segments(insertion_ctx, source.as_slice(), self.max_segment)
+                .into_iter()
+                .flat_map(|source_seg| target_segments.iter().map(move |&target_seg| (source_seg, target_seg)))
+                // NOTE swap of single activities is covered by exchange operator
+                .filter(|((source_start, source_end), (target_start, target_end))| {
+                    source_start != source_end || target_start != target_end
+                })
+                .map(|((source_start, source_end), (target_start, target_end))| {
+                    let new_source =
+                        replace_segment(&source, (source_start, source_end), &target[target_start..=target_end]);
+                    let new_target =
+                        replace_segment(&target, (target_start, target_end), &source[source_start..=source_end]);
+
+                    create_move(insertion_ctx, vec![(source_idx, new_source), (target_idx, new_target)], original)
+                })
+                .collect()
+        })
+    }
+}
+
+fn replace_segment(
+    sequence: &[(usize, usize)],
+    segment: (usize, usize),
+    replacement: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    sequence[..segment.0].iter().chain(replacement.iter()).chain(sequence[segment.1 + 1..].iter()).cloned().collect()
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+/// A local search operator which swaps two activities from different routes.
+#[derive(Default)]
+pub struct Exchange {}
+
+impl LocalOperator for Exchange {
+    fn get_moves(&self, insertion_ctx: &InsertionContext) -> Vec<LocalMove> {
+        select_route_pair(insertion_ctx).map_or_else(Vec::new, |(source_idx, target_idx)| {
+            let source = get_sequence(insertion_ctx, source_idx);
+            let target = get_sequence(insertion_ctx, target_idx);
+            let original = estimate_sequence(insertion_ctx, source_idx, source.as_slice())
+                + estimate_sequence(insertion_ctx, target_idx, target.as_slice());
+
+            let target_segments = get_movable_segments(insertion_ctx, target.as_slice(), 1);
+
+            get_movable_segments(insertion_ctx, source.as_slice(), 1)
+                .into_iter()
+                .flat_map(|(source_pos, _)| {
+                    target_segments.iter().map(move |&(target_pos, _)| (source_pos, target_pos))
+                })
+                .map(|(source_pos, target_pos)| {
+                    let mut new_source = source.clone();
+                    let mut new_target = target.clone();
+                    std::mem::swap(&mut new_source[source_pos], &mut new_target[target_pos]);
+
+                    create_move(insertion_ctx, vec![(source_idx, new_source), (target_idx, new_target)], original)
+                })
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,323 @@
+//! A various local search operators which improve solution by small changes of its routes.
+
+#[cfg(test)]
+#[path = "../../../../tests/unit/refinement/mutation/local_search/local_search_test.rs"]
+mod local_search_test;
+
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext};
+use crate::models::common::Cost;
+use crate::refinement::mutation::{Mutation, Recreate, RecreateWithCheapest};
+use crate::refinement::objectives::ObjectiveCostType;
+use crate::refinement::RefinementContext;
+use std::cmp::Ordering;
+
+/// Specifies a local move as new sequences of job activities in affected routes.
+pub struct LocalMove {
+    /// Estimated cost change of the move: negative value means improvement.
+    pub estimate: Cost,
+    /// Affected route indices with their new sequences of job activities. Each activity is
+    /// specified by its route index and activity index in the original solution.
+    pub routes: Vec<(usize, Vec<(usize, usize)>)>,
+}
+
+/// A trait which specifies logic to find local moves in given solution.
+pub trait LocalOperator {
+    /// Returns candidate moves with their estimated cost change.
+    fn get_moves(&self, insertion_ctx: &InsertionContext) -> Vec<LocalMove>;
+}
+
+mod cross_exchange;
+pub use self::cross_exchange::CrossExchange;
+
+mod exchange;
+pub use self::exchange::Exchange;
+
+mod or_opt;
+pub use self::or_opt::OrOpt;
+
+mod relocate;
+pub use self::relocate::Relocate;
+
+mod two_opt;
+pub use self::two_opt::TwoOpt;
+
+/// A mutation which applies local search operators to the solution while they find improving moves.
+/// Each move is checked against hard constraints and accepted only if it improves the problem
+/// objective. Required jobs which are not yet assigned (e.g. in initial solution)
+/// are inserted using cheapest insertion before local search starts.
+pub struct LocalSearchMutation {
+    operators: Vec<Box<dyn LocalOperator + Send + Sync>>,
+    max_iterations: usize,
+    max_candidates: usize,
+}
+
+impl Default for LocalSearchMutation {
+    fn default() -> Self {
+        Self::new(
+            vec![
+                Box::new(TwoOpt::default()),
+                Box::new(OrOpt::default()),
+                Box::new(Relocate::default()),
+                Box::new(Exchange::default()),
+                Box::new(CrossExchange::default()),
+            ],
+            10,
+            8,
+        )
+    }
+}
+
+impl LocalSearchMutation {
+    /// Creates a new instance of [`LocalSearchMutation`] with given operators, maximum amount of
+    /// iterations and maximum amount of candidate moves checked by each operator per iteration.
+    pub fn new(
+        operators: Vec<Box<dyn LocalOperator + Send + Sync>>,
+        max_iterations: usize,
+        max_candidates: usize,
+    ) -> Self {
+        Self { operators, max_iterations, max_candidates }
+    }
+
+    /// Tries to apply the most promising moves found by operator. Returns true if any is applied.
+    fn apply_operator(
+        &self,
+        refinement_ctx: &mut RefinementContext,
+        insertion_ctx: &mut InsertionContext,
+        operator: &(dyn LocalOperator + Send + Sync),
+    ) -> bool {
+        let mut moves = operator
+            .get_moves(insertion_ctx)
+            .into_iter()
+            .filter(|local_move| local_move.estimate < -f64::EPSILON)
+            .collect::<Vec<_>>();
+
+        moves.sort_by(|a, b| a.estimate.partial_cmp(&b.estimate).unwrap_or(Ordering::Equal));
+
+        moves.iter().take(self.max_candidates).any(|local_move| apply_move(refinement_ctx, insertion_ctx, local_move))
+    }
+}
+
+impl Mutation for LocalSearchMutation {
+    fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = if insertion_ctx.solution.required.is_empty() {
+            insertion_ctx
+        } else {
+            RecreateWithCheapest::default().run(refinement_ctx, insertion_ctx)
+        };
+        let mut is_improved = false;
+
+        for _ in 0..self.max_iterations {
+            // NOTE all operators are applied within one iteration
+            let applied = self
+                .operators
+                .iter()
+                .filter(|operator| self.apply_operator(refinement_ctx, &mut insertion_ctx, operator.as_ref()))
+                .count();
+
+            if applied == 0 {
+                break;
+            }
+
+            is_improved = true;
+        }
+
+        if is_improved {
+            insertion_ctx.restore();
+        }
+
+        insertion_ctx
+    }
+}
+
+/// Applies move if it is feasible and improves the problem objective.
+fn apply_move(
+    refinement_ctx: &mut RefinementContext,
+    insertion_ctx: &mut InsertionContext,
+    local_move: &LocalMove,
+) -> bool {
+    let new_routes = local_move.routes.iter().try_fold(vec![], |mut acc, (route_idx, sequence)| {
+        create_route(insertion_ctx, *route_idx, sequence.as_slice()).map(|route_ctx| {
+            acc.push((*route_idx, route_ctx));
+            acc
+        })
+    });
+
+    if let Some(new_routes) = new_routes {
+        let old_routes = insertion_ctx.solution.routes.clone();
+        let mut routes = old_routes.clone();
+        new_routes.into_iter().for_each(|(idx, route_ctx)| routes[idx] = route_ctx);
+
+        let old_cost = estimate_cost(refinement_ctx, insertion_ctx, &old_routes);
+        let new_cost = estimate_cost(refinement_ctx, insertion_ctx, &routes);
+
+        if new_cost.cmp_relaxed(&old_cost).0 == Ordering::Less {
+            insertion_ctx.solution.routes = routes;
+            return true;
+        }
+
+        insertion_ctx.solution.routes = old_routes;
+    }
+
+    false
+}
+
+/// Estimates problem objective for the solution with given routes. Empty routes are excluded
+/// as they are removed from solution later.
+fn estimate_cost(
+    refinement_ctx: &mut RefinementContext,
+    insertion_ctx: &mut InsertionContext,
+    routes: &[RouteContext],
+) -> ObjectiveCostType {
+    insertion_ctx.solution.routes =
+        routes.iter().filter(|route_ctx| route_ctx.route.tour.has_jobs()).cloned().collect();
+
+    insertion_ctx.problem.objective.estimate_cost(refinement_ctx, insertion_ctx)
+}
+
+/// Creates a new route for the actor of the route with given index and inserts activities one by
+/// one at the end checking hard constraints. Returns none if any constraint is violated.
+fn create_route(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    sequence: &[(usize, usize)],
+) -> Option<RouteContext> {
+    let problem = &insertion_ctx.problem;
+    let solution = &insertion_ctx.solution;
+
+    let mut route_ctx = RouteContext::new(solution.routes[route_idx].route.actor.clone());
+    problem.constraint.accept_route_state(&mut route_ctx);
+
+    sequence.iter().try_fold(route_ctx, |mut route_ctx, &(source_idx, activity_idx)| {
+        let activity = solution.routes[source_idx].route.tour.get(activity_idx)?;
+        let job = activity.retrieve_job()?;
+
+        if source_idx != route_idx && problem.constraint.evaluate_hard_route(solution, &route_ctx, &job).is_some() {
+            return None;
+        }
+
+        let target = Box::new(activity.deep_copy());
+        let index = route_ctx.route.tour.activity_count();
+
+        {
+            let tour = &route_ctx.route.tour;
+            let activity_ctx =
+                ActivityContext { index, prev: tour.get(index)?, target: &target, next: tour.get(index + 1) };
+
+            if problem.constraint.evaluate_hard_activity(&route_ctx, &activity_ctx).is_some() {
+                return None;
+            }
+        }
+
+        route_ctx.route_mut().tour.insert_at(target, index + 1);
+        problem.constraint.accept_route_state(&mut route_ctx);
+
+        Some(route_ctx)
+    })
+}
+
+/// Creates a move from new sequences estimating its cost change against original estimate.
+fn create_move(
+    insertion_ctx: &InsertionContext,
+    routes: Vec<(usize, Vec<(usize, usize)>)>,
+    original: Cost,
+) -> LocalMove {
+    let estimate = routes
+        .iter()
+        .map(|(route_idx, sequence)| estimate_sequence(insertion_ctx, *route_idx, sequence.as_slice()))
+        .sum::<Cost>();
+
+    LocalMove { estimate: estimate - original, routes }
+}
+
+/// Estimates transport cost of the route with given sequence of job activities.
+fn estimate_sequence(insertion_ctx: &InsertionContext, route_idx: usize, sequence: &[(usize, usize)]) -> Cost {
+    let routes = &insertion_ctx.solution.routes;
+    let route = &routes[route_idx].route;
+    let transport = &insertion_ctx.problem.transport;
+
+    // NOTE tour without start location begins at its first job activity
+    let start = route.tour.start().filter(|_| route.actor.detail.start.is_some());
+    let end = route.tour.end().filter(|end| end.job.is_none() && route.tour.total() > 1);
+
+    start
+        .into_iter()
+        .chain(
+            sequence.iter().map(|&(route_idx, activity_idx)| routes[route_idx].route.tour.get(activity_idx).unwrap()),
+        )
+        .chain(end)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|leg| {
+            transport.cost(&route.actor, leg[0].place.location, leg[1].place.location, leg[0].schedule.departure)
+        })
+        .sum()
+}
+
+/// Returns sequence of job activities of the route.
+fn get_sequence(insertion_ctx: &InsertionContext, route_idx: usize) -> Vec<(usize, usize)> {
+    let count = insertion_ctx.solution.routes[route_idx].route.tour.activity_count();
+
+    (1..=count).map(|activity_idx| (route_idx, activity_idx)).collect()
+}
+
+/// Checks whether activity can be moved: only activities of non locked single jobs are moved.
+fn is_movable(insertion_ctx: &InsertionContext, activity: &(usize, usize)) -> bool {
+    let &(route_idx, activity_idx) = activity;
+
+    insertion_ctx.solution.routes[route_idx]
+        .route
+        .tour
+        .get(activity_idx)
+        .and_then(|activity| activity.retrieve_job())
+        .filter(|job| job.as_single().is_some() && !insertion_ctx.solution.locked.contains(job))
+        .is_some()
+}
+
+/// Returns all segments of movable activities with length up to max size as (start, end) inclusive pairs.
+fn get_movable_segments(
+    insertion_ctx: &InsertionContext,
+    sequence: &[(usize, usize)],
+    max_size: usize,
+) -> Vec<(usize, usize)> {
+    let movable = sequence.iter().map(|activity| is_movable(insertion_ctx, activity)).collect::<Vec<_>>();
+
+    (0..sequence.len())
+        .flat_map(|start| {
+            let movable = &movable;
+            (start..sequence.len().min(start + max_size))
+                .take_while(move |&end| movable[end])
+                .map(move |end| (start, end))
+        })
+        .collect()
+}
+
+/// Selects random route which has at least given amount of jobs.
+fn select_route(insertion_ctx: &InsertionContext, min_jobs: usize) -> Option<usize> {
+    let routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.activity_count() >= min_jobs)
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if routes.is_empty() {
+        None
+    } else {
+        routes.get(insertion_ctx.random.uniform_int(0, routes.len() as i32 - 1) as usize).cloned()
+    }
+}
+
+/// Selects random pair of different routes where the first one has jobs.
+fn select_route_pair(insertion_ctx: &InsertionContext) -> Option<(usize, usize)> {
+    let total = insertion_ctx.solution.routes.len();
+    if total < 2 {
+        return None;
+    }
+
+    select_route(insertion_ctx, 1).map(|source_idx| {
+        let target_idx = insertion_ctx.random.uniform_int(0, total as i32 - 2) as usize;
+        (source_idx, if target_idx >= source_idx { target_idx + 1 } else { target_idx })
+    })
+}
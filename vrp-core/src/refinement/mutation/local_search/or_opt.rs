@@ -0,0 +1,42 @@
+use super::*;
+
+/// A local search operator which moves a segment of few consecutive activities to another position
+/// within the same route.
+pub struct OrOpt {
+    max_segment: usize,
+}
+
+impl Default for OrOpt {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl OrOpt {
+    /// Creates a new instance of [`OrOpt`] with given maximum segment size.
+    pub fn new(max_segment: usize) -> Self {
+        Self { max_segment }
+    }
+}
+
+impl LocalOperator for OrOpt {
+    fn get_moves(&self, insertion_ctx: &InsertionContext) -> Vec<LocalMove> {
+        select_route(insertion_ctx, 2).map_or_else(Vec::new, |route_idx| {
+            let sequence = get_sequence(insertion_ctx, route_idx);
+            let original = estimate_sequence(insertion_ctx, route_idx, sequence.as_slice());
+
+            get_movable_segments(insertion_ctx, sequence.as_slice(), self.max_segment)
+                .into_iter()
+                .flat_map(|(start, end)| {
+                    let segment = sequence[start..=end].to_vec();
+                    let rest = sequence[..start].iter().chain(sequence[end + 1..].iter()).cloned().collect::<Vec<_>>();
+
+                    (0..=rest.len()).filter(move |&position| position != start).map(move |position| {
+                        rest[..position].iter().chain(segment.iter()).chain(rest[position..].iter()).cloned().collect()
+                    })
+                })
+                .map(|new_sequence| create_move(insertion_ctx, vec![(route_idx, new_sequence)], original))
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use super::*;
+
+/// A local search operator which moves one activity from one route to another.
+#[derive(Default)]
+pub struct Relocate {}
+
+impl LocalOperator for Relocate {
+    fn get_moves(&self, insertion_ctx: &InsertionContext) -> Vec<LocalMove> {
+        select_route_pair(insertion_ctx).map_or_else(Vec::new, |(source_idx, target_idx)| {
+            let source = get_sequence(insertion_ctx, source_idx);
+            let target = get_sequence(insertion_ctx, target_idx);
+            let original = estimate_sequence(insertion_ctx, source_idx, source.as_slice())
+                + estimate_sequence(insertion_ctx, target_idx, target.as_slice());
+
+            get_movable_segments(insertion_ctx, source.as_slice(), 1)
+                .into_iter()
+                .flat_map(|(idx, _)| (0..=target.len()).map(move |position| (idx, position)))
+                .map(|(idx, position)| {
+                    let mut new_source = source.clone();
+                    let activity = new_source.remove(idx);
+
+                    let mut new_target = target.clone();
+                    new_target.insert(position, activity);
+
+                    create_move(insertion_ctx, vec![(source_idx, new_source), (target_idx, new_target)], original)
+                })
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+/// A local search operator which reverses a part of the route: it removes two edges and
+/// reconnects the route in opposite direction which eliminates route crossings.
+#[derive(Default)]
+pub struct TwoOpt {}
+
+impl LocalOperator for TwoOpt {
+    fn get_moves(&self, insertion_ctx: &InsertionContext) -> Vec<LocalMove> {
+        select_route(insertion_ctx, 2).map_or_else(Vec::new, |route_idx| {
+            let sequence = get_sequence(insertion_ctx, route_idx);
+            let original = estimate_sequence(insertion_ctx, route_idx, sequence.as_slice());
+
+            get_movable_segments(insertion_ctx, sequence.as_slice(), sequence.len())
+                .into_iter()
+                .filter(|(start, end)| start < end)
+                .map(|(start, end)| {
+                    let mut new_sequence = sequence.clone();
+                    new_sequence[start..=end].reverse();
+
+                    create_move(insertion_ctx, vec![(route_idx, new_sequence)], original)
+                })
+                .collect()
+        })
+    }
+}
@@ -1,21 +1,45 @@
 use crate::construction::heuristics::InsertionContext;
 use crate::refinement::RefinementContext;
 
+mod adaptive;
+pub use self::adaptive::*;
+
+mod local_search;
+pub use self::local_search::*;
+
 mod recreate;
 pub use self::recreate::*;
 
 mod ruin;
 pub use self::ruin::*;
 
+/// Specifies outcome of the solution produced by mutation.
+pub enum MutationOutcome {
+    /// A new best known solution is found.
+    NewBest,
+    /// Solution is accepted, but it is not the best known.
+    Accepted,
+    /// Solution is rejected.
+    Rejected,
+}
+
 /// Mutates given insertion context.
 pub trait Mutation {
     fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
+
+    /// Accepts outcome of the last mutated solution which can be used to adjust internal state.
+    fn accept_outcome(&self, _refinement_ctx: &mut RefinementContext, _outcome: &MutationOutcome) {}
+
+    /// Returns description of internal state, if there is any.
+    fn describe_state(&self, _refinement_ctx: &RefinementContext) -> Option<String> {
+        None
+    }
 }
 
 /// A mutation which implements ruin and recreate metaheuristic.
 pub struct RuinAndRecreateMutation {
-    pub recreate: Box<dyn Recreate>,
-    pub ruin: Box<dyn Ruin>,
+    pub recreate: Box<dyn Recreate + Send + Sync>,
+    pub ruin: Box<dyn Ruin + Send + Sync>,
 }
 
 impl Default for RuinAndRecreateMutation {
@@ -26,9 +50,18 @@ impl Default for RuinAndRecreateMutation {
 
 impl RuinAndRecreateMutation {
     /// Creates a new instance of [`RuinAndRecreateMutation`].
-    pub fn new(recreate: Box<dyn Recreate>, ruin: Box<dyn Ruin>) -> Self {
+    pub fn new(recreate: Box<dyn Recreate + Send + Sync>, ruin: Box<dyn Ruin + Send + Sync>) -> Self {
         Self { recreate, ruin }
     }
+
+    /// Creates a new instance of [`RuinAndRecreateMutation`] with default ruin and recreate methods
+    /// which are selected using adaptive weights.
+    pub fn new_adaptive(settings: AdaptiveSettings) -> Self {
+        Self {
+            recreate: Box::new(CompositeRecreate::new_adaptive(settings.clone())),
+            ruin: Box::new(CompositeRuin::new_adaptive(settings)),
+        }
+    }
 }
 
 impl Mutation for RuinAndRecreateMutation {
@@ -37,4 +70,55 @@ impl Mutation for RuinAndRecreateMutation {
 
         self.recreate.run(refinement_ctx, insertion_ctx)
     }
+
+    fn accept_outcome(&self, refinement_ctx: &mut RefinementContext, outcome: &MutationOutcome) {
+        self.ruin.accept_outcome(refinement_ctx, outcome);
+        self.recreate.accept_outcome(refinement_ctx, outcome);
+    }
+
+    fn describe_state(&self, refinement_ctx: &RefinementContext) -> Option<String> {
+        let states = vec![self.ruin.describe_state(refinement_ctx), self.recreate.describe_state(refinement_ctx)]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        if states.is_empty() {
+            None
+        } else {
+            Some(states.join("\n"))
+        }
+    }
+}
+
+/// A mutation which applies multiple mutations one by one, e.g. local search after ruin and recreate.
+pub struct CompositeMutation {
+    mutations: Vec<Box<dyn Mutation + Send + Sync>>,
+}
+
+impl CompositeMutation {
+    /// Creates a new instance of [`CompositeMutation`].
+    pub fn new(mutations: Vec<Box<dyn Mutation + Send + Sync>>) -> Self {
+        Self { mutations }
+    }
+}
+
+impl Mutation for CompositeMutation {
+    fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.mutations.iter().fold(insertion_ctx, |ctx, mutation| mutation.mutate(refinement_ctx, ctx))
+    }
+
+    fn accept_outcome(&self, refinement_ctx: &mut RefinementContext, outcome: &MutationOutcome) {
+        self.mutations.iter().for_each(|mutation| mutation.accept_outcome(refinement_ctx, outcome));
+    }
+
+    fn describe_state(&self, refinement_ctx: &RefinementContext) -> Option<String> {
+        let states =
+            self.mutations.iter().filter_map(|mutation| mutation.describe_state(refinement_ctx)).collect::<Vec<_>>();
+
+        if states.is_empty() {
+            None
+        } else {
+            Some(states.join("\n"))
+        }
+    }
 }
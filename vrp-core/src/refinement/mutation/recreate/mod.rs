@@ -1,12 +1,21 @@
 //! Contains logic to build a feasible solution from partially ruined one.
 
 use crate::construction::heuristics::InsertionContext;
+use crate::refinement::mutation::*;
 use crate::refinement::RefinementContext;
 
 /// A trait which specifies logic to produce a new feasible solution from partial one.
 pub trait Recreate {
     /// Recreates a new solution from the given.
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
+
+    /// Accepts outcome of the last produced solution which can be used to adjust internal state.
+    fn accept_outcome(&self, _refinement_ctx: &mut RefinementContext, _outcome: &MutationOutcome) {}
+
+    /// Returns description of internal state, if there is any.
+    fn describe_state(&self, _refinement_ctx: &RefinementContext) -> Option<String> {
+        None
+    }
 }
 
 mod recreate_with_cheapest;
@@ -24,37 +33,95 @@ pub use self::recreate_with_regret::RecreateWithRegret;
 mod recreate_with_nearest_neighbor;
 pub use self::recreate_with_nearest_neighbor::*;
 
-/// Provides the way to run one of multiple recreate methods.
+/// Provides the way to run one of multiple recreate methods. Methods are selected using either
+/// fixed or adaptive weights.
 pub struct CompositeRecreate {
-    recreates: Vec<Box<dyn Recreate>>,
+    recreates: Vec<Box<dyn Recreate + Send + Sync>>,
     weights: Vec<usize>,
+    names: Vec<String>,
+    adaptive: Option<AdaptiveSettings>,
 }
 
+const ADAPTIVE_RECREATE_KEY: &str = "adaptive_recreate";
+
 impl Default for CompositeRecreate {
     fn default() -> Self {
-        Self::new(vec![
-            (Box::new(RecreateWithCheapest::default()), 100),
-            (Box::new(RecreateWithRegret::default()), 90),
-            (Box::new(RecreateWithBlinks::<i32>::default()), 30),
-            (Box::new(RecreateWithRegret::new((5, 8))), 20),
-            (Box::new(RecreateWithGaps::default()), 10),
-            (Box::new(RecreateWithNearestNeighbor::default()), 5),
-        ])
+        Self::new_with_names(create_default_recreates(), None)
     }
 }
 
 impl CompositeRecreate {
-    pub fn new(recreates: Vec<(Box<dyn Recreate>, usize)>) -> Self {
-        let weights = recreates.iter().map(|(_, weight)| *weight).collect();
-        let recreates = recreates.into_iter().map(|(recreate, _)| recreate).collect();
-        Self { recreates, weights }
+    /// Creates a new instance of [`CompositeRecreate`] which uses fixed weights.
+    pub fn new(recreates: Vec<(Box<dyn Recreate + Send + Sync>, usize)>) -> Self {
+        let recreates = recreates
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (recreate, weight))| (recreate, weight, format!("recreate_{}", idx)))
+            .collect();
+
+        Self::new_with_names(recreates, None)
+    }
+
+    /// Creates a new instance of [`CompositeRecreate`] with default recreate methods which weights
+    /// are adjusted using their performance.
+    pub fn new_adaptive(settings: AdaptiveSettings) -> Self {
+        Self::new_with_names(create_default_recreates(), Some(settings))
+    }
+
+    /// Creates a new instance of [`CompositeRecreate`] using named recreate methods with their
+    /// initial weights. When adaptive settings are specified, weights are adjusted using methods'
+    /// performance.
+    pub fn new_with_names(
+        recreates: Vec<(Box<dyn Recreate + Send + Sync>, usize, String)>,
+        adaptive: Option<AdaptiveSettings>,
+    ) -> Self {
+        let weights = recreates.iter().map(|(_, weight, _)| *weight).collect();
+        let names = recreates.iter().map(|(_, _, name)| name.clone()).collect();
+        let recreates = recreates.into_iter().map(|(recreate, _, _)| recreate).collect();
+
+        Self { recreates, weights, names, adaptive }
     }
 }
 
 impl Recreate for CompositeRecreate {
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         // NOTE always use the first recreate method for initial generation
-        let index = if refinement_ctx.generation == 1 { 0 } else { insertion_ctx.random.weighted(self.weights.iter()) };
+        let index = match (refinement_ctx.generation, &self.adaptive) {
+            (1, None) => 0,
+            (_, None) => insertion_ctx.random.weighted(self.weights.iter()),
+            (generation, Some(_)) => {
+                let adaptive = get_adaptive_weights(refinement_ctx, ADAPTIVE_RECREATE_KEY, &self.weights);
+                if generation == 1 {
+                    adaptive.set_last(0);
+                    0
+                } else {
+                    adaptive.select(insertion_ctx.random.as_ref())
+                }
+            }
+        };
+
         self.recreates.get(index).unwrap().run(refinement_ctx, insertion_ctx)
     }
+
+    fn accept_outcome(&self, refinement_ctx: &mut RefinementContext, outcome: &MutationOutcome) {
+        if let Some(settings) = &self.adaptive {
+            get_adaptive_weights(refinement_ctx, ADAPTIVE_RECREATE_KEY, &self.weights).update(outcome, settings);
+        }
+    }
+
+    fn describe_state(&self, refinement_ctx: &RefinementContext) -> Option<String> {
+        format_adaptive_weights(refinement_ctx, ADAPTIVE_RECREATE_KEY, &self.names)
+            .map(|weights| format!("recreate weights: {}", weights))
+    }
+}
+
+fn create_default_recreates() -> Vec<(Box<dyn Recreate + Send + Sync>, usize, String)> {
+    vec![
+        (Box::new(RecreateWithCheapest::default()), 100, "cheapest".to_string()),
+        (Box::new(RecreateWithRegret::default()), 90, "regret".to_string()),
+        (Box::new(RecreateWithBlinks::<i32>::default()), 30, "blinks".to_string()),
+        (Box::new(RecreateWithRegret::new((5, 8))), 20, "regret_5_8".to_string()),
+        (Box::new(RecreateWithGaps::default()), 10, "gaps".to_string()),
+        (Box::new(RecreateWithNearestNeighbor::default()), 5, "nearest_neighbor".to_string()),
+    ]
 }
@@ -73,7 +73,7 @@ impl RandomJobSelector {
 
 impl JobSelector for RandomJobSelector {
     fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
-        ctx.solution.required.shuffle(&mut rand::thread_rng());
+        ctx.solution.required.shuffle(&mut ctx.random.get_rng());
 
         Box::new(ctx.solution.required.iter().cloned())
     }
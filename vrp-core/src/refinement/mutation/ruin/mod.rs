@@ -3,6 +3,7 @@
 use crate::construction::heuristics::{InsertionContext, RouteContext};
 use crate::models::problem::Job;
 use crate::models::Problem;
+use crate::refinement::mutation::*;
 use crate::refinement::RefinementContext;
 use crate::utils::Random;
 use std::iter::{empty, once};
@@ -12,6 +13,14 @@ use std::sync::Arc;
 pub trait Ruin {
     /// Ruins given solution and returns a new one with less jobs assigned.
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
+
+    /// Accepts outcome of the last produced solution which can be used to adjust internal state.
+    fn accept_outcome(&self, _refinement_ctx: &mut RefinementContext, _outcome: &MutationOutcome) {}
+
+    /// Returns description of internal state, if there is any.
+    fn describe_state(&self, _refinement_ctx: &RefinementContext) -> Option<String> {
+        None
+    }
 }
 
 mod adjusted_string_removal;
@@ -29,55 +38,90 @@ pub use self::random_job_removal::RandomJobRemoval;
 mod worst_jobs_removal;
 pub use self::worst_jobs_removal::WorstJobRemoval;
 
-/// Provides the way to run multiple ruin methods one by one on the same solution.
+/// Provides the way to run multiple ruin methods one by one on the same solution. Groups of ruin
+/// methods are selected using either fixed or adaptive weights.
 pub struct CompositeRuin {
-    ruins: Vec<Vec<(Arc<dyn Ruin>, f64)>>,
+    ruins: Vec<Vec<(Arc<dyn Ruin + Send + Sync>, f64)>>,
     weights: Vec<usize>,
+    names: Vec<String>,
+    adaptive: Option<AdaptiveSettings>,
 }
 
+/// Specifies a group of ruin methods with their probabilities.
+pub type RuinGroup = Vec<(Arc<dyn Ruin + Send + Sync>, f64)>;
+
+const ADAPTIVE_RUIN_KEY: &str = "adaptive_ruin";
+
 impl Default for CompositeRuin {
     fn default() -> Self {
-        let adjusted_string_default = Arc::new(AdjustedStringRemoval::default());
-        let adjusted_string_aggressive = Arc::new(AdjustedStringRemoval::new(30, 120, 0.02));
-
-        let neighbour_removal = Arc::new(NeighbourRemoval::default());
-        let neighbour_aggressive = Arc::new(NeighbourRemoval::new(30, 120, 0.5));
-
-        let worst_job_default = Arc::new(WorstJobRemoval::default());
-        let random_job_default = Arc::new(RandomJobRemoval::default());
-        let random_route_default = Arc::new(RandomRouteRemoval::default());
-
-        Self::new(vec![
-            (
-                vec![
-                    (adjusted_string_default.clone(), 1.),
-                    (random_route_default.clone(), 0.05),
-                    (random_job_default.clone(), 0.05),
-                ],
-                100,
-            ),
-            (vec![(adjusted_string_aggressive, 1.)], 10),
-            (
-                vec![(neighbour_removal, 1.), (random_route_default.clone(), 0.05), (random_job_default.clone(), 0.05)],
-                50,
-            ),
-            (vec![(neighbour_aggressive, 1.)], 10),
-            (vec![(worst_job_default, 1.), (adjusted_string_default, 0.1)], 10),
-            (vec![(random_job_default.clone(), 1.), (random_route_default.clone(), 0.1)], 10),
-            (vec![(random_route_default, 1.), (random_job_default, 0.1)], 10),
-        ])
+        Self::new_with_names(create_default_ruins(), None)
     }
 }
 
 impl CompositeRuin {
-    pub fn new(ruins: Vec<(Vec<(Arc<dyn Ruin>, f64)>, usize)>) -> Self {
-        let weights = ruins.iter().map(|(_, weight)| *weight).collect();
-        let ruins = ruins.into_iter().map(|(ruin, _)| ruin).collect();
+    /// Creates a new instance of [`CompositeRuin`] which uses fixed weights.
+    pub fn new(ruins: Vec<(RuinGroup, usize)>) -> Self {
+        let ruins = ruins
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (ruin, weight))| (ruin, weight, format!("ruin_{}", idx)))
+            .collect();
+
+        Self::new_with_names(ruins, None)
+    }
+
+    /// Creates a new instance of [`CompositeRuin`] with default ruin methods which weights
+    /// are adjusted using their performance.
+    pub fn new_adaptive(settings: AdaptiveSettings) -> Self {
+        Self::new_with_names(create_default_ruins(), Some(settings))
+    }
 
-        Self { ruins, weights }
+    /// Creates a new instance of [`CompositeRuin`] using named groups of ruin methods with their
+    /// initial weights. When adaptive settings are specified, weights are adjusted using groups'
+    /// performance.
+    pub fn new_with_names(ruins: Vec<(RuinGroup, usize, String)>, adaptive: Option<AdaptiveSettings>) -> Self {
+        let weights = ruins.iter().map(|(_, weight, _)| *weight).collect();
+        let names = ruins.iter().map(|(_, _, name)| name.clone()).collect();
+        let ruins = ruins.into_iter().map(|(ruin, _, _)| ruin).collect();
+
+        Self { ruins, weights, names, adaptive }
     }
 }
 
+fn create_default_ruins() -> Vec<(RuinGroup, usize, String)> {
+    let adjusted_string_default = Arc::new(AdjustedStringRemoval::default());
+    let adjusted_string_aggressive = Arc::new(AdjustedStringRemoval::new(30, 120, 0.02));
+
+    let neighbour_removal = Arc::new(NeighbourRemoval::default());
+    let neighbour_aggressive = Arc::new(NeighbourRemoval::new(30, 120, 0.5));
+
+    let worst_job_default = Arc::new(WorstJobRemoval::default());
+    let random_job_default = Arc::new(RandomJobRemoval::default());
+    let random_route_default = Arc::new(RandomRouteRemoval::default());
+
+    vec![
+        (
+            vec![
+                (adjusted_string_default.clone(), 1.),
+                (random_route_default.clone(), 0.05),
+                (random_job_default.clone(), 0.05),
+            ],
+            100,
+            "adjusted_string".to_string(),
+        ),
+        (vec![(adjusted_string_aggressive, 1.)], 10, "adjusted_string_aggressive".to_string()),
+        (
+            vec![(neighbour_removal, 1.), (random_route_default.clone(), 0.05), (random_job_default.clone(), 0.05)],
+            50,
+            "neighbour".to_string(),
+        ),
+        (vec![(neighbour_aggressive, 1.)], 10, "neighbour_aggressive".to_string()),
+        (vec![(worst_job_default, 1.), (adjusted_string_default, 0.1)], 10, "worst_job".to_string()),
+        (vec![(random_job_default.clone(), 1.), (random_route_default.clone(), 0.1)], 10, "random_job".to_string()),
+        (vec![(random_route_default, 1.), (random_job_default, 0.1)], 10, "random_route".to_string()),
+    ]
+}
+
 impl Ruin for CompositeRuin {
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         if insertion_ctx.solution.routes.is_empty() {
@@ -86,7 +130,10 @@ impl Ruin for CompositeRuin {
 
         let random = insertion_ctx.random.clone();
 
-        let index = insertion_ctx.random.weighted(self.weights.iter());
+        let index = match &self.adaptive {
+            Some(_) => get_adaptive_weights(refinement_ctx, ADAPTIVE_RUIN_KEY, &self.weights).select(random.as_ref()),
+            None => insertion_ctx.random.weighted(self.weights.iter()),
+        };
 
         let mut insertion_ctx = self
             .ruins
@@ -100,6 +147,17 @@ impl Ruin for CompositeRuin {
 
         insertion_ctx
     }
+
+    fn accept_outcome(&self, refinement_ctx: &mut RefinementContext, outcome: &MutationOutcome) {
+        if let Some(settings) = &self.adaptive {
+            get_adaptive_weights(refinement_ctx, ADAPTIVE_RUIN_KEY, &self.weights).update(outcome, settings);
+        }
+    }
+
+    fn describe_state(&self, refinement_ctx: &RefinementContext) -> Option<String> {
+        format_adaptive_weights(refinement_ctx, ADAPTIVE_RUIN_KEY, &self.names)
+            .map(|weights| format!("ruin weights: {}", weights))
+    }
 }
 
 fn get_chunk_size(ctx: &InsertionContext, range: &(usize, usize), threshold: f64) -> usize {
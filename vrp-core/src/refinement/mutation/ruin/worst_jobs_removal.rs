@@ -47,7 +47,7 @@ impl Ruin for WorstJobRemoval {
         let mut routes_savings = get_routes_cost_savings(&insertion_ctx);
         let removed_jobs: RwLock<HashSet<Job>> = RwLock::new(HashSet::default());
 
-        routes_savings.shuffle(&mut rand::thread_rng());
+        routes_savings.shuffle(&mut random.get_rng());
 
         routes_savings.iter().take_while(|_| removed_jobs.read().unwrap().len() <= self.threshold).for_each(
             |(rc, savings)| {
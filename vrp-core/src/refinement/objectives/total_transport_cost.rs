@@ -3,6 +3,9 @@
 mod total_transport_cost_test;
 
 use super::*;
+use crate::construction::constraints::{get_overtime_cost, EXTRA_COST_KEY};
+use crate::construction::heuristics::RouteContext;
+use crate::models::Problem;
 
 /// An objective function which calculate total cost.
 pub struct TotalTransportCost {
@@ -28,31 +31,37 @@ impl TotalTransportCost {
     }
 
     fn get_actual_cost(&self, insertion_ctx: &InsertionContext) -> Cost {
-        insertion_ctx.solution.routes.iter().fold(Cost::default(), |acc, rc| {
-            let actor = &rc.route.actor;
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .fold(Cost::default(), |acc, route_ctx| acc + get_route_cost(&insertion_ctx.problem, route_ctx))
+    }
+}
 
-            let start = rc.route.tour.start().unwrap();
-            let problem = &insertion_ctx.problem;
-            let initial = problem.activity.cost(actor, start, start.schedule.arrival);
-            let initial = initial + actor.vehicle.costs.fixed + actor.driver.costs.fixed;
+/// Returns total cost of the route: fixed costs, overtime costs, extra costs, activity costs and transport costs.
+fn get_route_cost(problem: &Problem, route_ctx: &RouteContext) -> Cost {
+    let actor = &route_ctx.route.actor;
 
-            acc + rc.route.tour.legs().fold(initial, |acc, (items, _)| {
-                acc + match items {
-                    [from, to] => {
-                        problem.activity.cost(actor, to, to.schedule.arrival)
-                            + problem.transport.cost(
-                                actor,
-                                from.place.location,
-                                to.place.location,
-                                from.schedule.departure,
-                            )
-                    }
-                    [_] => 0.0,
-                    _ => panic!("Unexpected route leg configuration."),
-                }
-            })
-        })
-    }
+    let start = route_ctx.route.tour.start().unwrap();
+    let end = route_ctx.route.tour.end().unwrap();
+    let initial = problem.activity.cost(actor, start, start.schedule.arrival);
+    let initial = initial
+        + actor.vehicle.costs.fixed
+        + actor.driver.costs.fixed
+        + get_overtime_cost(actor, end.schedule.arrival - start.schedule.departure)
+        + route_ctx.state.get_route_state::<Cost>(EXTRA_COST_KEY).cloned().unwrap_or(0.);
+
+    route_ctx.route.tour.legs().fold(initial, |acc, (items, _)| {
+        acc + match items {
+            [from, to] => {
+                problem.activity.cost(actor, to, to.schedule.arrival)
+                    + problem.transport.cost(actor, from.place.location, to.place.location, from.schedule.departure)
+            }
+            [_] => 0.0,
+            _ => panic!("Unexpected route leg configuration."),
+        }
+    })
 }
 
 impl Objective for TotalTransportCost {
@@ -16,6 +16,7 @@ pub use self::parallel::*;
 mod random;
 pub use self::random::DefaultRandom;
 pub use self::random::Random;
+pub use self::random::SeededRandom;
 
 mod statistics;
 pub use self::statistics::*;
@@ -1,5 +1,6 @@
 pub use self::actual::map_reduce;
 pub use self::actual::parallel_collect;
+pub use self::actual::parallel_into_collect;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod actual {
@@ -16,6 +17,16 @@ mod actual {
         source.par_iter().map(map_op).collect()
     }
 
+    /// Maps collection taking ownership of its items and collects results into vector in parallel.
+    pub fn parallel_into_collect<T, F, R>(source: Vec<T>, map_op: F) -> Vec<R>
+    where
+        T: Send,
+        F: Fn(T) -> R + Sync + Send,
+        R: Send,
+    {
+        source.into_par_iter().map(map_op).collect()
+    }
+
     /// Performs map reduce operations in parallel.
     pub fn map_reduce<T, FM, FR, FD, R>(source: &[T], map_op: FM, default_op: FD, reduce_op: FR) -> R
     where
@@ -41,6 +52,16 @@ mod actual {
         source.iter().map(map_op).collect()
     }
 
+    /// Maps collection taking ownership of its items and collects results into vector synchronously.
+    pub fn parallel_into_collect<T, F, R>(source: Vec<T>, map_op: F) -> Vec<R>
+    where
+        T: Send,
+        F: Fn(T) -> R + Sync + Send,
+        R: Send,
+    {
+        source.into_iter().map(map_op).collect()
+    }
+
     /// Performs map reduce operations synchronously.
     pub fn map_reduce<T, FM, FR, FD, R>(source: &[T], map_op: FM, default_op: FD, reduce_op: FR) -> R
     where
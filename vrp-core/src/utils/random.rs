@@ -1,7 +1,13 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/random_test.rs"]
+mod random_test;
+
 extern crate rand;
 
-use self::rand::Rng;
+use self::rand::rngs::StdRng;
+use self::rand::{Rng, SeedableRng};
 use std::slice::Iter;
+use std::sync::Mutex;
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -41,8 +47,14 @@ pub trait Random {
             .unwrap()
             .1
     }
+
+    /// Returns random generator which can be used with `rand` crate api, e.g. to shuffle collection.
+    fn get_rng(&self) -> StdRng {
+        StdRng::from_rng(rand::thread_rng()).expect("cannot create random generator")
+    }
 }
 
+/// A default random implementation which uses thread local random generator.
 pub struct DefaultRandom {}
 
 impl Random for DefaultRandom {}
@@ -52,3 +64,39 @@ impl Default for DefaultRandom {
         Self {}
     }
 }
+
+/// A random implementation which produces reproducible sequence of values for the same seed.
+pub struct SeededRandom {
+    rng: Mutex<StdRng>,
+}
+
+impl SeededRandom {
+    /// Creates a new instance of `SeededRandom` using given seed.
+    pub fn new(seed: u64) -> Self {
+        Self { rng: Mutex::new(StdRng::seed_from_u64(seed)) }
+    }
+}
+
+impl Random for SeededRandom {
+    fn uniform_int(&self, min: i32, max: i32) -> i32 {
+        if min == max {
+            return min;
+        }
+
+        assert!(min < max);
+        self.rng.lock().unwrap().gen_range(min, max + 1)
+    }
+
+    fn uniform_real(&self, min: f64, max: f64) -> f64 {
+        if (min - max).abs() < std::f64::EPSILON {
+            return min;
+        }
+
+        assert!(min < max);
+        self.rng.lock().unwrap().gen_range(min, max)
+    }
+
+    fn get_rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.rng.lock().unwrap().gen())
+    }
+}
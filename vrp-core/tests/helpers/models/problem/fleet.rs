@@ -7,19 +7,39 @@ use std::sync::Arc;
 
 pub const DEFAULT_ACTOR_LOCATION: Location = 0;
 pub const DEFAULT_ACTOR_TIME_WINDOW: TimeWindow = TimeWindow { start: 0.0, end: 1000.0 };
-pub const DEFAULT_VEHICLE_COSTS: Costs =
-    Costs { fixed: 0.0, per_distance: 1.0, per_driving_time: 1.0, per_waiting_time: 1.0, per_service_time: 1.0 };
+pub const DEFAULT_VEHICLE_COSTS: Costs = Costs {
+    fixed: 0.0,
+    per_distance: 1.0,
+    per_driving_time: 1.0,
+    per_waiting_time: 1.0,
+    per_service_time: 1.0,
+    overtime: None,
+};
 
 pub fn test_costs() -> Costs {
     DEFAULT_VEHICLE_COSTS
 }
 
 pub fn fixed_costs() -> Costs {
-    Costs { fixed: 100.0, per_distance: 1.0, per_driving_time: 1.0, per_waiting_time: 1.0, per_service_time: 1.0 }
+    Costs {
+        fixed: 100.0,
+        per_distance: 1.0,
+        per_driving_time: 1.0,
+        per_waiting_time: 1.0,
+        per_service_time: 1.0,
+        overtime: None,
+    }
 }
 
 pub fn empty_costs() -> Costs {
-    Costs { fixed: 0.0, per_distance: 0.0, per_driving_time: 0.0, per_waiting_time: 0.0, per_service_time: 0.0 }
+    Costs {
+        fixed: 0.0,
+        per_distance: 0.0,
+        per_driving_time: 0.0,
+        per_waiting_time: 0.0,
+        per_service_time: 0.0,
+        overtime: None,
+    }
 }
 
 pub fn test_driver() -> Driver {
@@ -30,6 +50,12 @@ pub fn test_driver_with_costs(costs: Costs) -> Driver {
     Driver { costs, dimens: Default::default(), details: vec![] }
 }
 
+pub fn test_driver_with_times(times: Vec<TimeWindow>) -> Driver {
+    let details = times.into_iter().map(|time| DriverDetail { time: Some(time) }).collect();
+
+    Driver { costs: test_costs(), dimens: Default::default(), details }
+}
+
 pub fn test_vehicle_detail() -> VehicleDetail {
     VehicleDetail { start: Some(0), end: Some(0), time: Some(DEFAULT_ACTOR_TIME_WINDOW) }
 }
@@ -115,6 +141,11 @@ impl FleetBuilder {
         self
     }
 
+    pub fn add_drivers(&mut self, drivers: Vec<Driver>) -> &mut FleetBuilder {
+        self.drivers.extend(drivers.into_iter());
+        self
+    }
+
     pub fn add_vehicle(&mut self, vehicle: Vehicle) -> &mut FleetBuilder {
         self.vehicles.push(vehicle);
         self
@@ -0,0 +1,133 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+use crate::models::problem::{Fleet, Single};
+use crate::models::solution::Registry;
+
+fn create_single_with_group(id: &str, group: Option<&str>) -> Arc<Single> {
+    let mut single = test_single();
+    single.dimens.set_id(id);
+    if let Some(group) = group {
+        single.dimens.set_group(group);
+    }
+
+    Arc::new(single)
+}
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build()
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, singles: Vec<Arc<Single>>) -> RouteContext {
+    create_route_context_with_activities(fleet, vehicle, singles.into_iter().map(test_tour_activity_with_job).collect())
+}
+
+fn get_sorted_ids(jobs: impl Iterator<Item = Job>) -> Vec<String> {
+    let mut ids = jobs.map(|job| get_job_id(&job).clone()).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+parameterized_test! {can_keep_group_in_one_route, (vehicle, group, expected), {
+    can_keep_group_in_one_route_impl(vehicle, group, expected);
+}}
+
+can_keep_group_in_one_route! {
+    case01: ("v1", Some("g1"), None),
+    case02: ("v2", Some("g1"), Some(RouteConstraintViolation { code: 1 })),
+    case03: ("v2", Some("g2"), None),
+    case04: ("v2", None, None),
+}
+
+fn can_keep_group_in_one_route_impl(vehicle: &str, group: Option<&str>, expected: Option<RouteConstraintViolation>) {
+    let fleet = create_test_fleet();
+    let pipeline = create_constraint_pipeline_with_module(Box::new(GroupModule::new(1)));
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_ctx(&fleet, "v1", vec![create_single_with_group("job1", Some("g1"))]));
+    let route_ctx = solution_ctx
+        .routes
+        .iter()
+        .find(|rc| get_vehicle_id(&rc.route.actor.vehicle) == vehicle)
+        .cloned()
+        .unwrap_or_else(|| create_route_ctx(&fleet, vehicle, vec![]));
+    let job = Job::Single(create_single_with_group("job2", group));
+
+    let result = pipeline.evaluate_hard_route(&solution_ctx, &route_ctx, &job);
+
+    assert_eq_option!(result, expected);
+}
+
+#[test]
+fn can_remove_rest_of_group_when_it_is_ruined() {
+    let fleet = create_test_fleet();
+    let module = GroupModule::new(1);
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_ctx(
+        &fleet,
+        "v1",
+        vec![
+            create_single_with_group("job1", Some("g1")),
+            create_single_with_group("job2", Some("g1")),
+            create_single_with_group("job3", None),
+        ],
+    ));
+    solution_ctx.required.push(Job::Single(create_single_with_group("job4", Some("g1"))));
+
+    module.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_sorted_ids(solution_ctx.required.iter().cloned()), vec!["job1", "job2", "job4"]);
+    assert_eq!(get_sorted_ids(solution_ctx.routes.first().unwrap().route.tour.jobs()), vec!["job3"]);
+}
+
+#[test]
+fn can_unassign_partially_assigned_group() {
+    let fleet = create_test_fleet();
+    let module = GroupModule::new(1);
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_ctx(
+        &fleet,
+        "v1",
+        vec![create_single_with_group("job1", Some("g1")), create_single_with_group("job2", Some("g2"))],
+    ));
+    solution_ctx.unassigned.insert(Job::Single(create_single_with_group("job3", Some("g1"))), 2);
+
+    module.accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.required.is_empty());
+    assert_eq!(get_sorted_ids(solution_ctx.unassigned.keys().cloned()), vec!["job1", "job3"]);
+    assert_eq!(solution_ctx.unassigned.values().filter(|&&code| code == 1).count(), 1);
+    assert_eq!(get_sorted_ids(solution_ctx.routes.first().unwrap().route.tour.jobs()), vec!["job2"]);
+}
+
+#[test]
+fn can_remove_route_without_jobs_after_group_removal() {
+    let fleet = create_test_fleet();
+    let module = GroupModule::new(1);
+    let mut solution_ctx = SolutionContext { registry: Registry::new(&fleet), ..create_empty_solution_context() };
+    solution_ctx.routes.push(create_route_ctx(
+        &fleet,
+        "v1",
+        vec![create_single_with_group("job1", Some("g1")), create_single_with_group("job2", Some("g1"))],
+    ));
+    solution_ctx.routes.push(create_route_ctx(&fleet, "v2", vec![create_single_with_group("job3", None)]));
+    let registry = &mut solution_ctx.registry;
+    solution_ctx.routes.iter().for_each(|route_ctx| registry.use_actor(&route_ctx.route.actor));
+    solution_ctx.unassigned.insert(Job::Single(create_single_with_group("job4", Some("g1"))), 2);
+
+    module.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_sorted_ids(solution_ctx.unassigned.keys().cloned()), vec!["job1", "job2", "job4"]);
+    assert_eq!(solution_ctx.routes.len(), 1);
+    assert_eq!(get_vehicle_id(&solution_ctx.routes.first().unwrap().route.actor.vehicle), "v2");
+    assert_eq!(
+        solution_ctx.registry.available().map(|actor| get_vehicle_id(&actor.vehicle).clone()).collect::<Vec<_>>(),
+        vec!["v1"]
+    );
+}
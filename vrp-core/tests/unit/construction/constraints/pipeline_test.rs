@@ -1,6 +1,8 @@
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use crate::helpers::models::solution::{test_actor, test_tour_activity_without_job};
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
 use crate::models::common::Cost;
 use crate::models::problem::Job;
 use std::slice::Iter;
@@ -33,6 +35,39 @@ impl ConstraintModule for TestConstraintModule {
     }
 }
 
+/// Removes first activity from tour of `v1` vehicle and keeps tour size as route state.
+struct TestRemovalConstraintModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for TestRemovalConstraintModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {
+        unimplemented!()
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let size = ctx.route.tour.total();
+        ctx.state_mut().put_route_state(1, size);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| get_vehicle_id(&route_ctx.route.actor.vehicle) == "v1").for_each(
+            |route_ctx| {
+                route_ctx.route_mut().tour.remove_activity_at(1);
+            },
+        );
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
 struct TestHardActivityConstraint {
     violation: Option<ActivityConstraintViolation>,
 }
@@ -111,3 +146,31 @@ fn can_estimate_hard_activity_constraints() {
 
     assert_eq!(result, 12.0);
 }
+
+#[test]
+fn can_accept_route_state_of_changed_routes_on_accept_solution_state() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build();
+    let create_route_ctx = |vehicle: &str, ids: Vec<&str>| {
+        create_route_context_with_activities(
+            &fleet,
+            vehicle,
+            ids.into_iter().map(|id| test_tour_activity_with_job(test_single_with_id(id))).collect(),
+        )
+    };
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_ctx("v1", vec!["job1", "job2"]));
+    solution_ctx.routes.push(create_route_ctx("v2", vec!["job3"]));
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestRemovalConstraintModule { state_keys: vec![1], constraints: vec![] }));
+
+    pipeline.accept_solution_state(&mut solution_ctx);
+
+    let get_tour_size_state = |idx: usize| solution_ctx.routes[idx].state.get_route_state::<usize>(1).cloned();
+    assert_eq!(solution_ctx.routes[0].route.tour.job_count(), 1);
+    assert_eq!(get_tour_size_state(0), Some(3));
+    assert_eq!(get_tour_size_state(1), None);
+}
@@ -1,15 +1,17 @@
 mod timing {
+    use crate::construction::constraints::TransportConstraintModule;
     use crate::construction::constraints::{ActivityConstraintViolation, RouteConstraintViolation};
     use crate::construction::heuristics::*;
-    use crate::helpers::construction::constraints::create_constraint_pipeline_with_timing;
+    use crate::helpers::construction::constraints::*;
     use crate::helpers::models::domain::create_empty_solution_context;
     use crate::helpers::models::problem::*;
     use crate::helpers::models::solution::*;
     use crate::models::common::{Location, Schedule, TimeWindow, Timestamp};
-    use crate::models::problem::{Fleet, VehicleDetail};
+    use crate::models::problem::{create_matrix_transport_cost, Fleet, MatrixData, VehicleDetail};
     use crate::models::solution::{Activity, Place, Registry};
     use crate::utils::compare_floats;
     use std::cmp::Ordering;
+    use std::sync::Arc;
 
     fn create_detail(
         locations: (Option<Location>, Option<Location>),
@@ -258,6 +260,135 @@ mod timing {
         assert_eq!(compare_floats(result, 30.0), Ordering::Equal);
     }
 
+    #[test]
+    fn can_calculate_soft_activity_cost_for_open_start() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((None, Some(0)), Some((0., 1000.)))])
+                .build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![Box::new(
+                ActivityBuilder::default()
+                    .place(Place { location: 10, duration: 0.0, time: DEFAULT_ACTIVITY_TIME_WINDOW.clone() })
+                    .build(),
+            )],
+        );
+        let pipeline = create_constraint_pipeline_with_timing();
+        pipeline.accept_route_state(&mut route_ctx);
+        let target = Box::new(Activity {
+            place: Place { location: 30, duration: 0.0, time: DEFAULT_ACTIVITY_TIME_WINDOW },
+            schedule: DEFAULT_ACTIVITY_SCHEDULE,
+            job: None,
+        });
+        let activity_ctx = ActivityContext {
+            index: 0,
+            prev: route_ctx.route.tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(1),
+        };
+
+        let result = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(route_ctx.route.tour.start().unwrap().place.location, 10);
+        assert_eq!(compare_floats(result, 40.0), Ordering::Equal);
+    }
+
+    #[test]
+    fn can_update_activity_schedule_for_open_start() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((None, None), Some((0., 1000.)))])
+                .build()])
+            .build();
+        let mut solution_ctx = SolutionContext {
+            required: vec![],
+            ignored: vec![],
+            unassigned: Default::default(),
+            locked: Default::default(),
+            routes: vec![create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![
+                    Box::new(
+                        ActivityBuilder::default()
+                            .place(Place { location: 10, duration: 5.0, time: TimeWindow { start: 20.0, end: 30.0 } })
+                            .build(),
+                    ),
+                    Box::new(
+                        ActivityBuilder::default()
+                            .place(Place { location: 20, duration: 10.0, time: TimeWindow { start: 0.0, end: 100.0 } })
+                            .build(),
+                    ),
+                ],
+            )],
+            registry: Registry::new(&fleet),
+        };
+
+        create_constraint_pipeline_with_timing().accept_solution_state(&mut solution_ctx);
+
+        let route_ctx = solution_ctx.routes.first().unwrap();
+        let start = route_ctx.route.tour.start().unwrap();
+        assert_eq!(start.place.location, 10);
+        assert_eq!(start.schedule.departure, 20.);
+        assert_eq!(route_ctx.route.tour.get(1).unwrap().schedule, Schedule { arrival: 20.0, departure: 25.0 });
+        assert_eq!(route_ctx.route.tour.get(2).unwrap().schedule, Schedule { arrival: 35.0, departure: 45.0 });
+    }
+
+    #[test]
+    fn can_reschedule_departure_with_time_dependent_travel() {
+        let transport = create_matrix_transport_cost(vec![
+            MatrixData { profile: 0, timestamp: Some(0.), durations: vec![0., 100., 100., 0.], distances: vec![0.; 4] },
+            MatrixData { profile: 0, timestamp: Some(100.), durations: vec![0., 10., 10., 0.], distances: vec![0.; 4] },
+        ])
+        .unwrap();
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((Some(0), None), Some((0., 1000.)))])
+                .build()])
+            .build();
+        let mut solution_ctx = SolutionContext {
+            required: vec![],
+            ignored: vec![],
+            unassigned: Default::default(),
+            locked: Default::default(),
+            routes: vec![create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![Box::new(
+                    ActivityBuilder::default()
+                        .place(Place { location: 1, duration: 0.0, time: TimeWindow { start: 120.0, end: 200.0 } })
+                        .build(),
+                )],
+            )],
+            registry: Registry::new(&fleet),
+        };
+
+        create_constraint_pipeline_with_module(Box::new(TransportConstraintModule::new(
+            Arc::new(TestActivityCost::default()),
+            transport,
+            Arc::new(|_| (None, None)),
+            1,
+            2,
+            3,
+        )))
+        .accept_solution_state(&mut solution_ctx);
+
+        let route_ctx = solution_ctx.routes.first().unwrap();
+        let departure = route_ctx.route.tour.start().unwrap().schedule.departure;
+        let arrival = route_ctx.route.tour.get(1).unwrap().schedule.arrival;
+        assert!((departure - 110.).abs() < 1E-6);
+        assert!(arrival <= 120. && (arrival - 120.).abs() < 1E-6);
+    }
+
     #[test]
     fn can_stop_with_time_route_constraint() {
         let fleet = FleetBuilder::default()
@@ -11,6 +11,7 @@ use crate::construction::constraints::Demand;
 use crate::models::solution::Place as ActivityPlace;
 use crate::models::{Lock, LockDetail, LockOrder, LockPosition};
 use crate::refinement::objectives::MultiObjective;
+use crate::utils::DefaultRandom;
 
 #[test]
 fn can_create_adjacency_matrix_decipher() {
@@ -70,7 +71,7 @@ fn can_encode_decode_feasible_diverse_problem() {
     let adjacency_matrix = decipher.encode::<SparseMatrix>(&original_solution);
     assert_eq!(adjacency_matrix.to_vvec(), expected_matrix);
 
-    let restored_solution = decipher.decode(&adjacency_matrix);
+    let restored_solution = decipher.decode(&adjacency_matrix, Arc::new(DefaultRandom::default()));
 
     // TODO improve comparison
     assert_eq!(restored_solution.required.len(), original_solution.required.len());
@@ -100,7 +101,8 @@ fn can_handle_multi_job_in_wrong_order() {
         vec![0., 1., 0., 0., 0., 0., 2., 0., 0.],
     ];
 
-    let restored_solution = decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix));
+    let restored_solution =
+        decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix), Arc::new(DefaultRandom::default()));
     assert_eq!(restored_solution.routes.len(), 1);
     assert_eq!(restored_solution.required.len(), 1);
 
@@ -138,7 +140,8 @@ fn can_handle_single_job_capacity_violation() {
         vec![0., 1., 0., 0., 0., 0., 0., 0., 0.],
     ];
 
-    let restored_solution = decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix));
+    let restored_solution =
+        decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix), Arc::new(DefaultRandom::default()));
     assert_eq!(restored_solution.routes.len(), 2);
     assert_eq!(restored_solution.required.len(), 1);
 
@@ -175,7 +178,8 @@ fn can_handle_multi_job_capacity_violation() {
         vec![0., 0., 0., 0., 0., 0., 1., 0., 0.],
     ];
 
-    let restored_solution = decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix));
+    let restored_solution =
+        decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix), Arc::new(DefaultRandom::default()));
     assert_eq!(restored_solution.routes.len(), 1);
     assert_eq!(restored_solution.required.len(), 1);
 
@@ -214,7 +218,8 @@ fn can_handle_multi_job_incomplete_order() {
         vec![0., 0., 0., 0., 0., 0., 0., 2., 0.],
     ];
 
-    let restored_solution = decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix));
+    let restored_solution =
+        decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix), Arc::new(DefaultRandom::default()));
     assert_eq!(restored_solution.routes.len(), 2);
     assert_eq!(restored_solution.required.len(), 1);
 
@@ -273,7 +278,8 @@ fn can_handle_job_with_lock_impl(lock_order: LockOrder) {
         vec![0., 0., 0., 0., 0., 0., 0., 0., 0.],
     ];
 
-    let restored_solution = decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix));
+    let restored_solution =
+        decipher.decode(&SparseMatrix::from_vvec(&adjacency_matrix), Arc::new(DefaultRandom::default()));
     assert_eq!(restored_solution.routes.len(), 1);
     assert_eq!(restored_solution.required.len(), 1);
 
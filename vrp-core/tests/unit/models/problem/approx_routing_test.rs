@@ -0,0 +1,36 @@
+use super::*;
+
+parameterized_test! {can_calculate_distance, (metric, circuity, from, to, expected), {
+    can_calculate_distance_impl(metric, circuity, from, to, expected);
+}}
+
+can_calculate_distance! {
+    case01: (DistanceMetric::Euclidean, 1., (0., 0.), (3., 4.), 5.),
+    case02: (DistanceMetric::Euclidean, 1.4, (0., 0.), (3., 4.), 7.),
+    case03: (DistanceMetric::Manhattan, 1., (0., 0.), (3., -4.), 7.),
+    case04: (DistanceMetric::Manhattan, 2., (1., 1.), (3., 4.), 10.),
+    case05: (DistanceMetric::Haversine, 1., (52.52599, 13.45413), (52.5165, 13.3808), 5078.),
+    case06: (DistanceMetric::Haversine, 1.2, (52.52599, 13.45413), (52.5165, 13.3808), 6094.),
+}
+
+fn can_calculate_distance_impl(
+    metric: DistanceMetric,
+    circuity: f64,
+    from: (f64, f64),
+    to: (f64, f64),
+    expected: Distance,
+) {
+    let routing = ApproxRouting::new(1., circuity, metric);
+
+    assert_eq!(routing.distance(from, to).round(), expected);
+}
+
+#[test]
+fn can_create_matrices() {
+    let routing = ApproxRouting::new(2., 1., DistanceMetric::Euclidean);
+
+    let (durations, distances) = routing.get_matrices(&[(0., 0.), (3., 4.), (0., 1.)]);
+
+    assert_eq!(distances, vec![0., 5., 1., 5., 0., 18_f64.sqrt(), 1., 18_f64.sqrt(), 0.]);
+    assert_eq!(durations, distances.iter().map(|distance| distance / 2.).collect::<Vec<_>>());
+}
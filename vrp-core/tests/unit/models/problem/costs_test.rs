@@ -80,3 +80,47 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance(0, 0, 1, 0.), 1.);
     assert_eq!(costs.distance(1, 0, 1, 0.), 5.);
 }
+
+#[test]
+fn can_interpolate_distances() {
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(0, Some(0.), (100., 2), (10., 2)),
+            create_matrix_data(0, Some(10.), (200., 2), (20., 2)),
+        ],
+        2,
+    )
+    .unwrap();
+
+    for &(timestamp, distance) in &[(0., 10.), (10., 20.), (15., 20.), (3., 13.), (5., 15.), (7., 17.)] {
+        assert_eq!(costs.distance(0, 0, 1, timestamp), distance);
+    }
+}
+
+#[test]
+fn can_keep_fifo_property() {
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(0, Some(0.), (100., 2), (10., 2)),
+            create_matrix_data(0, Some(10.), (50., 2), (20., 2)),
+            create_matrix_data(1, Some(0.), (100., 2), (10., 2)),
+            create_matrix_data(1, Some(10.), (95., 2), (20., 2)),
+        ],
+        2,
+    )
+    .unwrap();
+
+    // NOTE travel time decreases faster than time passes, so it is better to wait
+    for &(timestamp, duration, distance) in &[(0., 60., 20.), (5., 55., 20.), (10., 50., 20.), (15., 50., 20.)] {
+        assert_eq!(costs.duration(0, 0, 1, timestamp), duration);
+        assert_eq!(costs.distance(0, 0, 1, timestamp), distance);
+    }
+
+    for &(timestamp, duration, distance) in &[(0., 100., 10.), (5., 97.5, 15.), (10., 95., 20.)] {
+        assert_eq!(costs.duration(1, 0, 1, timestamp), duration);
+        assert_eq!(costs.distance(1, 0, 1, timestamp), distance);
+    }
+
+    let arrivals = (0..20).map(|time| time as f64 + costs.duration(0, 0, 1, time as f64)).collect::<Vec<_>>();
+    assert!(arrivals.windows(2).all(|pair| pair[0] <= pair[1]));
+}
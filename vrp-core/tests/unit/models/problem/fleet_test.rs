@@ -1,4 +1,6 @@
-use crate::helpers::models::problem::{test_driver, test_vehicle, FleetBuilder};
+use crate::helpers::models::problem::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::{Costs, OvertimeCosts};
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -16,3 +18,59 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1.to_owned(), profile2.to_owned()]
     )
 }
+
+parameterized_test! {can_create_actors_from_driver_vehicle_pairings, (driver_times, expected), {
+    can_create_actors_from_driver_vehicle_pairings_impl(driver_times, expected);
+}}
+
+can_create_actors_from_driver_vehicle_pairings! {
+    case01: (vec![], vec![(0., 1000.)]),
+    case02: (vec![(100., 200.)], vec![(100., 200.)]),
+    case03: (vec![(100., 200.), (500., 1500.)], vec![(100., 200.), (500., 1000.)]),
+    case04: (vec![(1000., 1500.)], vec![]),
+}
+
+fn can_create_actors_from_driver_vehicle_pairings_impl(driver_times: Vec<(f64, f64)>, expected: Vec<(f64, f64)>) {
+    let driver = if driver_times.is_empty() {
+        test_driver()
+    } else {
+        test_driver_with_times(driver_times.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect())
+    };
+
+    let fleet = FleetBuilder::default().add_driver(driver).add_vehicle(test_vehicle(0)).build();
+
+    let mut times =
+        fleet.actors.iter().map(|actor| (actor.detail.time.start, actor.detail.time.end)).collect::<Vec<_>>();
+    times.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    assert_eq!(times, expected);
+}
+
+#[test]
+fn can_create_actors_for_each_driver() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver_with_times(vec![TimeWindow::new(0., 1000.)]))
+        .add_driver(test_driver_with_times(vec![TimeWindow::new(0., 1000.)]))
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+
+    assert_eq!(fleet.actors.len(), 4);
+    assert_eq!(fleet.drivers.len(), 2);
+}
+
+parameterized_test! {can_calculate_overtime_cost, (overtime, duration, expected), {
+    can_calculate_overtime_cost_impl(overtime, duration, expected);
+}}
+
+can_calculate_overtime_cost! {
+    case01: (None, 100., 0.),
+    case02: (Some((50., 2.)), 40., 0.),
+    case03: (Some((50., 2.)), 50., 0.),
+    case04: (Some((50., 2.)), 60., 20.),
+}
+
+fn can_calculate_overtime_cost_impl(overtime: Option<(f64, f64)>, duration: f64, expected: f64) {
+    let costs =
+        Costs { overtime: overtime.map(|(threshold, per_time)| OvertimeCosts { threshold, per_time }), ..test_costs() };
+
+    assert_eq!(costs.overtime_cost(duration), expected);
+}
@@ -91,6 +91,24 @@ returns_proper_job_neighbours! {
 }
 
 fn returns_proper_job_neighbours_impl(index: usize, expected: Vec<String>) {
+    returns_proper_job_neighbours_with_options_impl(index, expected, JobIndexOptions::default());
+}
+
+parameterized_test! {returns_proper_job_neighbours_with_options, (index, max_neighbours, is_lazy, expected), {
+    let options = JobIndexOptions { max_neighbours, is_lazy };
+    returns_proper_job_neighbours_with_options_impl(index, expected.iter().map(|s| s.to_string()).collect(), options);
+}}
+
+returns_proper_job_neighbours_with_options! {
+    case1: (2, Some(2), false, vec!["s1", "s3"]),
+    case2: (2, Some(2), true, vec!["s1", "s3"]),
+    case3: (3, Some(3), false, vec!["s2", "s4", "s1"]),
+    case4: (3, Some(0), false, Vec::<&str>::new()),
+    case5: (3, None, true, vec!["s2", "s4", "s1", "s0"]),
+    case6: (0, Some(10), true, vec!["s1", "s2", "s3", "s4"]),
+}
+
+fn returns_proper_job_neighbours_with_options_impl(index: usize, expected: Vec<String>, options: JobIndexOptions) {
     let fleet = FleetBuilder::default()
         .add_driver(test_driver())
         .add_vehicles(vec![
@@ -105,7 +123,7 @@ fn returns_proper_job_neighbours_impl(index: usize, expected: Vec<String>) {
         SingleBuilder::default().id("s3").location(Some(3)).build_as_job_ref(),
         SingleBuilder::default().id("s4").location(Some(4)).build_as_job_ref(),
     ];
-    let jobs = Jobs::new(&fleet, species.clone(), &create_profile_aware_transport_cost());
+    let jobs = Jobs::new_with_options(&fleet, species.clone(), &create_profile_aware_transport_cost(), options);
 
     let result: Vec<String> = jobs
         .neighbors(1, species.get(index).unwrap(), 0.0, u32::max_value() as f64)
@@ -1,6 +1,6 @@
-use crate::helpers::models::problem::{test_driver, test_vehicle_detail, FleetBuilder, VehicleBuilder};
+use crate::helpers::models::problem::*;
 use crate::models::common::TimeWindow;
-use crate::models::problem::{Actor, VehicleDetail};
+use crate::models::problem::{Actor, Fleet, VehicleDetail};
 use crate::models::solution::Registry;
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
@@ -61,3 +61,53 @@ fn can_provide_next_actors_from_registry() {
     assert_eq!(actors.first().unwrap().detail.start, Some(0));
     assert_eq!(actors.last().unwrap().detail.start, Some(1));
 }
+
+fn create_fleet_with_drivers(driver_times: Vec<Vec<(f64, f64)>>) -> Fleet {
+    FleetBuilder::default()
+        .add_drivers(
+            driver_times
+                .into_iter()
+                .map(|times| test_driver_with_times(times.into_iter().map(|(s, e)| TimeWindow::new(s, e)).collect()))
+                .collect(),
+        )
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+#[test]
+fn can_use_each_driver_and_vehicle_only_once_at_a_time() {
+    let fleet = create_fleet_with_drivers(vec![vec![(0., 1000.)], vec![(0., 1000.)]]);
+    let mut registry = Registry::new(&fleet);
+    assert_eq!(registry.available().count(), 4);
+
+    let actor = registry.available().next().unwrap();
+    registry.use_actor(&actor);
+
+    let available = registry.available().collect::<Vec<_>>();
+    assert_eq!(available.len(), 1);
+    assert!(!Arc::ptr_eq(&available[0].driver, &actor.driver));
+    assert!(!Arc::ptr_eq(&available[0].vehicle, &actor.vehicle));
+
+    registry.use_actor(&available[0]);
+    assert_eq!(registry.available().count(), 0);
+
+    registry.free_actor(&actor);
+    assert_eq!(registry.available().count(), 1);
+
+    registry.free_actor(&available[0]);
+    assert_eq!(registry.available().count(), 4);
+}
+
+#[test]
+fn can_use_driver_with_multiple_vehicles_at_different_times() {
+    let fleet = create_fleet_with_drivers(vec![vec![(0., 100.), (100., 200.)]]);
+    let mut registry = Registry::new(&fleet);
+    assert_eq!(registry.available().count(), 4);
+
+    let actor = registry.available().find(|actor| actor.detail.time.start == 0.).unwrap();
+    registry.use_actor(&actor);
+
+    let available = registry.available().collect::<Vec<_>>();
+    assert_eq!(available.len(), 2);
+    assert!(available.iter().all(|actor| actor.detail.time.start == 100.));
+}
@@ -0,0 +1,28 @@
+use super::*;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_problem};
+use crate::refinement::objectives::MeasurableObjectiveCost;
+
+parameterized_test! {can_accept_solution, (new_cost, old_cost, expected), {
+    can_accept_solution_impl(new_cost, old_cost, expected);
+}}
+
+can_accept_solution! {
+    case_01: (90., 100., true),
+    case_02: (101., 100., true),
+    case_03: (102., 100., false),
+    case_04: (100., 100., true),
+}
+
+fn can_accept_solution_impl(new_cost: f64, old_cost: f64, expected: bool) {
+    let mut refinement_ctx = RefinementContext::new(create_empty_problem());
+    refinement_ctx.population.add((
+        create_empty_insertion_context(),
+        Box::new(MeasurableObjectiveCost::new(old_cost)),
+        0,
+    ));
+    let individuum = (create_empty_insertion_context(), Box::new(MeasurableObjectiveCost::new(new_cost)) as _, 0);
+
+    let result = RecordToRecord::new(0.01).is_accepted(&mut refinement_ctx, &individuum);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_problem};
+use crate::refinement::objectives::MeasurableObjectiveCost;
+use crate::utils::SeededRandom;
+use std::sync::Arc;
+
+fn create_insertion_context() -> InsertionContext {
+    InsertionContext { random: Arc::new(SeededRandom::new(0)), ..create_empty_insertion_context() }
+}
+
+parameterized_test! {can_accept_solution, (new_cost, old_cost, generation, expected), {
+    can_accept_solution_impl(new_cost, old_cost, generation, expected);
+}}
+
+can_accept_solution! {
+    case_01: (90., 100., 0, true),
+    case_02: (100.0001, 100., 0, true),
+    case_03: (200., 100., 0, false),
+    case_04: (100., 100., 100, true),
+    case_05: (100.1, 100., 100, false),
+}
+
+fn can_accept_solution_impl(new_cost: f64, old_cost: f64, generation: usize, expected: bool) {
+    let mut refinement_ctx = RefinementContext::new(create_empty_problem());
+    refinement_ctx.generation = generation;
+    refinement_ctx.population.add((create_insertion_context(), Box::new(MeasurableObjectiveCost::new(old_cost)), 0));
+    let individuum = (create_insertion_context(), Box::new(MeasurableObjectiveCost::new(new_cost)) as _, generation);
+
+    let result = SimulatedAnnealing::new_with_schedule(CoolingSchedule::new_with_generations(100))
+        .is_accepted(&mut refinement_ctx, &individuum);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_decrease_temperature_with_generations() {
+    let mut refinement_ctx = RefinementContext::new(create_empty_problem());
+    let acceptance = SimulatedAnnealing::new(0.1, 0.001, CoolingSchedule::new_with_generations(100)).unwrap();
+
+    let temperatures = [0, 50, 100, 200]
+        .iter()
+        .map(|&generation| {
+            refinement_ctx.generation = generation;
+            acceptance.get_temperature(&refinement_ctx)
+        })
+        .collect::<Vec<_>>();
+
+    assert!((temperatures[0] - 0.1).abs() < 1E-9);
+    assert!((temperatures[1] - 0.01).abs() < 1E-9);
+    assert!((temperatures[2] - 0.001).abs() < 1E-9);
+    assert!((temperatures[3] - 0.001).abs() < 1E-9);
+}
+
+parameterized_test! {can_validate_temperatures, (initial_temperature, final_temperature, expected), {
+    can_validate_temperatures_impl(initial_temperature, final_temperature, expected);
+}}
+
+can_validate_temperatures! {
+    case_01: (0.1, 0.001, true),
+    case_02: (0., 0.001, false),
+    case_03: (0.1, -0.001, false),
+}
+
+fn can_validate_temperatures_impl(initial_temperature: f64, final_temperature: f64, expected: bool) {
+    let result =
+        SimulatedAnnealing::new(initial_temperature, final_temperature, CoolingSchedule::new_with_generations(1));
+
+    assert_eq!(result.is_ok(), expected);
+}
+
+#[test]
+fn can_start_time_schedule_with_refinement() {
+    let refinement_ctx = RefinementContext::new(create_empty_problem());
+    let schedule = CoolingSchedule::new_with_time(1.);
+
+    std::thread::sleep(std::time::Duration::from_millis(100));
+
+    assert!(schedule.get_progress(&refinement_ctx) < 0.05);
+}
@@ -0,0 +1,34 @@
+use super::*;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_problem};
+use crate::refinement::objectives::MeasurableObjectiveCost;
+
+parameterized_test! {can_accept_solution, (new_cost, old_cost, generation, expected), {
+    can_accept_solution_impl(new_cost, old_cost, generation, expected);
+}}
+
+can_accept_solution! {
+    case_01: (90., 100., 0, true),
+    case_02: (101., 100., 0, true),
+    case_03: (101., 100., 60, false),
+    case_04: (103., 100., 0, false),
+    case_05: (100., 100., 0, true),
+    case_06: (100., 100., 100, true),
+    case_07: (100.1, 100., 100, false),
+}
+
+fn can_accept_solution_impl(new_cost: f64, old_cost: f64, generation: usize, expected: bool) {
+    let mut refinement_ctx = RefinementContext::new(create_empty_problem());
+    refinement_ctx.generation = generation;
+    refinement_ctx.population.add((
+        create_empty_insertion_context(),
+        Box::new(MeasurableObjectiveCost::new(old_cost)),
+        0,
+    ));
+    let individuum =
+        (create_empty_insertion_context(), Box::new(MeasurableObjectiveCost::new(new_cost)) as _, generation);
+
+    let result = ThresholdAccepting::new(0.02, CoolingSchedule::new_with_generations(100))
+        .is_accepted(&mut refinement_ctx, &individuum);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,46 @@
+use super::*;
+use crate::utils::SeededRandom;
+
+fn create_settings(segment_size: usize) -> AdaptiveSettings {
+    AdaptiveSettings { segment_size, ..AdaptiveSettings::default() }
+}
+
+#[test]
+fn can_remember_last_selected_operator() {
+    let random = SeededRandom::new(0);
+    let mut adaptive = AdaptiveWeights::new(&[0, 10, 0]);
+
+    let index = adaptive.select(&random);
+    adaptive.update(&MutationOutcome::NewBest, &create_settings(1));
+
+    assert_eq!(index, 1);
+    assert_eq!(adaptive.weights(), &[0., 10. * 0.9 + 0.1 * 33., 0.]);
+}
+
+#[test]
+fn can_update_weights_only_at_segment_end() {
+    let settings = create_settings(4);
+    let mut adaptive = AdaptiveWeights::new(&[10, 10]);
+
+    vec![(0, MutationOutcome::NewBest), (0, MutationOutcome::Accepted), (1, MutationOutcome::Rejected)]
+        .into_iter()
+        .for_each(|(index, outcome)| {
+            adaptive.set_last(index);
+            adaptive.update(&outcome, &settings);
+            assert_eq!(adaptive.weights(), &[10., 10.]);
+        });
+
+    adaptive.set_last(1);
+    adaptive.update(&MutationOutcome::Rejected, &settings);
+
+    assert_eq!(adaptive.weights(), &[10. * 0.9 + 0.1 * (33. + 9.) / 2., 9.]);
+}
+
+#[test]
+fn can_ignore_outcome_without_selected_operator() {
+    let mut adaptive = AdaptiveWeights::new(&[10, 10]);
+
+    adaptive.update(&MutationOutcome::NewBest, &create_settings(1));
+
+    assert_eq!(adaptive.weights(), &[10., 10.]);
+}
@@ -0,0 +1,129 @@
+use super::*;
+use crate::helpers::refinement::generate_matrix_routes;
+use crate::models::Problem;
+use crate::refinement::objectives::{MeasurableObjectiveCost, Objective, TotalTransportCost};
+use crate::utils::SeededRandom;
+use std::sync::Arc;
+
+fn create_insertion_ctx(matrix: (usize, usize), moves: Vec<((usize, usize), (usize, usize))>) -> InsertionContext {
+    create_insertion_ctx_with_objective(matrix, moves, None)
+}
+
+fn create_insertion_ctx_with_objective(
+    matrix: (usize, usize),
+    moves: Vec<((usize, usize), (usize, usize))>,
+    objective: Option<Arc<dyn Objective + Send + Sync>>,
+) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(matrix.0, matrix.1);
+    let problem = match objective {
+        Some(objective) => Problem { objective, ..problem },
+        None => problem,
+    };
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(SeededRandom::new(0)),
+    );
+
+    moves.into_iter().for_each(|((from_route, from_idx), (to_route, to_idx))| {
+        let routes = &mut insertion_ctx.solution.routes;
+        let activity = Box::new(routes[from_route].route.tour.get(from_idx).unwrap().deep_copy());
+        routes[from_route].route_mut().tour.remove_activity_at(from_idx);
+        routes[to_route].route_mut().tour.insert_at(activity, to_idx);
+    });
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+fn get_cost(insertion_ctx: &InsertionContext) -> Cost {
+    TotalTransportCost::default()
+        .estimate_cost(&mut RefinementContext::new(insertion_ctx.problem.clone()), insertion_ctx)
+        .value()
+}
+
+fn create_operator(name: &str) -> Box<dyn LocalOperator + Send + Sync> {
+    match name {
+        "two_opt" => Box::new(TwoOpt::default()),
+        "or_opt" => Box::new(OrOpt::default()),
+        "relocate" => Box::new(Relocate::default()),
+        "exchange" => Box::new(Exchange::default()),
+        "cross_exchange" => Box::new(CrossExchange::default()),
+        _ => unreachable!(),
+    }
+}
+
+parameterized_test! {can_improve_solution_with_local_operator, (operator, matrix, moves), {
+    can_improve_solution_with_local_operator_impl(operator, matrix, moves);
+}}
+
+can_improve_solution_with_local_operator! {
+    case_01: ("two_opt", (8, 1), vec![((0, 2), (0, 6)), ((0, 6), (0, 3))]),
+    case_02: ("or_opt", (8, 1), vec![((0, 2), (0, 6))]),
+    case_03: ("relocate", (4, 2), vec![((0, 2), (1, 2))]),
+    case_04: ("exchange", (4, 2), vec![((0, 2), (1, 2)), ((1, 1), (0, 2))]),
+    case_05: ("cross_exchange", (4, 2), vec![((0, 2), (1, 2)), ((0, 2), (1, 3)), ((1, 1), (0, 2)), ((1, 2), (0, 3))]),
+}
+
+fn can_improve_solution_with_local_operator_impl(
+    operator: &str,
+    matrix: (usize, usize),
+    moves: Vec<((usize, usize), (usize, usize))>,
+) {
+    let insertion_ctx = create_insertion_ctx(matrix, moves);
+    let original_cost = get_cost(&insertion_ctx);
+    let mutation = LocalSearchMutation::new(vec![create_operator(operator)], 10, 8);
+    let mut refinement_ctx = RefinementContext::new(insertion_ctx.problem.clone());
+
+    let insertion_ctx = (0..5).fold(insertion_ctx, |ctx, _| mutation.mutate(&mut refinement_ctx, ctx));
+
+    assert!(get_cost(&insertion_ctx) < original_cost);
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        matrix.0 * matrix.1
+    );
+}
+
+#[test]
+fn can_keep_locked_jobs_in_place() {
+    let mut insertion_ctx = create_insertion_ctx((8, 1), vec![((0, 2), (0, 6))]);
+    let jobs = insertion_ctx.solution.routes[0].route.tour.jobs().collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(jobs.into_iter());
+    let original_cost = get_cost(&insertion_ctx);
+
+    let insertion_ctx = LocalSearchMutation::default()
+        .mutate(&mut RefinementContext::new(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_cost(&insertion_ctx), original_cost);
+}
+
+/// An objective which prefers solutions with higher transport cost.
+struct MaxTransportCost {}
+
+impl Objective for MaxTransportCost {
+    fn estimate_cost(
+        &self,
+        refinement_ctx: &mut RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> ObjectiveCostType {
+        let cost = TotalTransportCost::default().estimate_cost(refinement_ctx, insertion_ctx).value();
+
+        Box::new(MeasurableObjectiveCost::new(-cost))
+    }
+
+    fn is_goal_satisfied(&self, _: &mut RefinementContext, _: &InsertionContext) -> Option<bool> {
+        None
+    }
+}
+
+#[test]
+fn can_use_problem_objective_to_accept_moves() {
+    let insertion_ctx =
+        create_insertion_ctx_with_objective((8, 1), vec![((0, 2), (0, 6))], Some(Arc::new(MaxTransportCost {})));
+    let original_cost = get_cost(&insertion_ctx);
+
+    let insertion_ctx = LocalSearchMutation::default()
+        .mutate(&mut RefinementContext::new(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert!(get_cost(&insertion_ctx) >= original_cost);
+}
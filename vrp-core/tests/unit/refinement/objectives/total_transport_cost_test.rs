@@ -1,3 +1,4 @@
+use crate::construction::constraints::EXTRA_COST_KEY;
 use crate::construction::heuristics::{InsertionContext, RouteContext, RouteState, SolutionContext};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_timing;
 use crate::helpers::models::problem::*;
@@ -12,8 +13,16 @@ use crate::utils::DefaultRandom;
 use hashbrown::HashMap;
 use std::sync::Arc;
 
-#[test]
-fn can_calculate_transport_cost() {
+parameterized_test! {can_calculate_transport_cost, (extra_cost, expected), {
+    can_calculate_transport_cost_impl(extra_cost, expected);
+}}
+
+can_calculate_transport_cost! {
+    case01: (None, 382.),
+    case02: (Some(18.), 400.),
+}
+
+fn can_calculate_transport_cost_impl(extra_cost: Option<f64>, expected: f64) {
     let fleet = Arc::new(
         FleetBuilder::default()
             .add_driver(test_driver())
@@ -34,6 +43,10 @@ fn can_calculate_transport_cost() {
         )),
         state: Arc::new(RouteState::default()),
     };
+    let mut route2_state = RouteState::default();
+    if let Some(extra_cost) = extra_cost {
+        route2_state.put_route_state(EXTRA_COST_KEY, extra_cost);
+    }
     let route2 = RouteContext {
         route: Arc::new(create_route_with_start_end_activities(
             &fleet,
@@ -42,7 +55,7 @@ fn can_calculate_transport_cost() {
             test_tour_activity_with_schedule(Schedule::new(11., 11.)),
             vec![test_tour_activity_with_location_and_duration(5, 1.)],
         )),
-        state: Arc::new(RouteState::default()),
+        state: Arc::new(route2_state),
     };
     let activity = Arc::new(SimpleActivityCost::default());
     let transport = TestTransportCost::new_shared();
@@ -87,9 +100,11 @@ fn can_calculate_transport_cost() {
     // driving: 10
     // fixed: 100
 
-    // total: (70 * 2 + 100) + (21 * 2 + 100) = 382
+    // extra: optional
+
+    // total: (70 * 2 + 100) + (21 * 2 + 100) + extra = 382 + extra
 
     let result = TotalTransportCost::default().estimate_cost(&mut refinement_ctx, &insertion_ctx);
 
-    assert_eq!(result.value(), 382.0);
+    assert_eq!(result.value(), expected);
 }
@@ -0,0 +1,34 @@
+use super::*;
+use rand::seq::SliceRandom;
+
+fn get_values(random: &dyn Random) -> (Vec<i32>, Vec<f64>, Vec<usize>) {
+    let ints = (0..10).map(|_| random.uniform_int(0, 100)).collect();
+    let reals = (0..10).map(|_| random.uniform_real(0., 1.)).collect();
+    let mut items = (0..10).collect::<Vec<usize>>();
+    items.shuffle(&mut random.get_rng());
+
+    (ints, reals, items)
+}
+
+#[test]
+fn can_produce_same_values_with_same_seed() {
+    assert_eq!(get_values(&SeededRandom::new(42)), get_values(&SeededRandom::new(42)));
+}
+
+#[test]
+fn can_produce_different_values_with_different_seed() {
+    assert_ne!(get_values(&SeededRandom::new(42)), get_values(&SeededRandom::new(43)));
+}
+
+#[test]
+fn can_produce_values_within_range() {
+    let random = SeededRandom::new(0);
+
+    (0..100).for_each(|_| {
+        let value = random.uniform_int(-2, 2);
+        assert!(value >= -2 && value <= 2);
+
+        let value = random.uniform_real(1., 2.);
+        assert!(value >= 1. && value < 2.);
+    });
+}
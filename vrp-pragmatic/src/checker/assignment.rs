@@ -0,0 +1,224 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/assignment_test.rs"]
+mod assignment_test;
+
+use super::*;
+use std::collections::HashSet;
+
+/// Checks assignment of jobs and vehicles.
+pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
+    let errors = vec![check_vehicles(ctx), check_drivers(ctx), check_jobs(ctx)]
+        .into_iter()
+        .flat_map(|result| result.err().into_iter().flatten())
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks that vehicles in each tour are used once per shift and driver and they are known in problem.
+fn check_vehicles(ctx: &CheckerContext) -> Result<(), Vec<String>> {
+    let all_vehicles: HashSet<_> = ctx.problem.fleet.vehicles.iter().flat_map(|v| v.vehicle_ids.iter()).collect();
+    let mut used_vehicles = HashSet::<(String, usize, Option<String>)>::new();
+
+    let results = ctx
+        .solution
+        .tours
+        .iter()
+        .map(|tour| {
+            if !all_vehicles.contains(&tour.vehicle_id) {
+                return Err(format!("Used vehicle with unknown id: {}", tour.vehicle_id));
+            }
+
+            if !(used_vehicles.insert((tour.vehicle_id.to_string(), tour.shift_index, tour.driver_id.clone()))) {
+                Err(format!("Vehicle with '{}' id used more than once for shift {}", tour.vehicle_id, tour.shift_index))
+            } else {
+                Ok(())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
+
+/// Checks that drivers are known in problem, tours are served within driver shifts and each driver
+/// and each vehicle are not used in different tours at the same time.
+fn check_drivers(ctx: &CheckerContext) -> Result<(), Vec<String>> {
+    let all_drivers: HashMap<_, _> = ctx
+        .problem
+        .fleet
+        .drivers
+        .iter()
+        .flat_map(|drivers| drivers.iter().map(|driver| (&driver.id, driver)))
+        .collect();
+
+    let get_tour_time = |tour: &Tour| {
+        let start = tour.stops.first().map_or(0., |stop| parse_time(&stop.time.departure));
+        let end = tour.stops.last().map_or(0., |stop| parse_time(&stop.time.arrival));
+        TimeWindow::new(start, end)
+    };
+
+    let tours = ctx.solution.tours.iter().map(|tour| (tour, get_tour_time(tour))).collect::<Vec<_>>();
+
+    let driver_results = tours.iter().map(|(tour, time)| match &tour.driver_id {
+        Some(driver_id) => match all_drivers.get(driver_id) {
+            Some(driver) => {
+                let is_available = driver.shifts.is_empty()
+                    || driver.shifts.iter().any(|shift| {
+                        matches!(parse_time_window(&shift.time), Ok(shift) if shift.start <= time.start && time.end <= shift.end)
+                    });
+
+                if is_available {
+                    Ok(())
+                } else {
+                    Err(format!("Driver '{}' is not available for tour of vehicle '{}'", driver_id, tour.vehicle_id))
+                }
+            }
+            None => Err(format!("Used driver with unknown id: {}", driver_id)),
+        },
+        None if !all_drivers.is_empty() => Err(format!("Tour of vehicle '{}' has no driver", tour.vehicle_id)),
+        _ => Ok(()),
+    });
+
+    let overlap_results = tours.iter().enumerate().flat_map(|(idx, (tour, time))| {
+        tours.iter().skip(idx + 1).map(move |(other, other_time)| {
+            if time.start >= other_time.end || other_time.start >= time.end {
+                return Ok(());
+            }
+
+            if let (Some(driver_id), Some(other_driver_id)) = (&tour.driver_id, &other.driver_id) {
+                if driver_id == other_driver_id {
+                    return Err(format!("Driver '{}' is used in multiple tours at the same time", driver_id));
+                }
+            }
+
+            if tour.vehicle_id == other.vehicle_id {
+                return Err(format!("Vehicle '{}' is used in multiple tours at the same time", tour.vehicle_id));
+            }
+
+            Ok(())
+        })
+    });
+
+    combine_error_results(&driver_results.chain(overlap_results).collect::<Vec<_>>())
+}
+
+fn check_jobs(ctx: &CheckerContext) -> Result<(), Vec<String>> {
+    struct JobAssignment {
+        pub tour_info: (String, usize),
+        pub pickups: Vec<usize>,
+        pub deliveries: Vec<usize>,
+        pub replacements: Vec<usize>,
+        pub services: Vec<usize>,
+    }
+    let new_assignment = |tour_info: (String, usize)| JobAssignment {
+        tour_info,
+        pickups: vec![],
+        deliveries: vec![],
+        replacements: vec![],
+        services: vec![],
+    };
+    let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
+
+    let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
+    let mut used_jobs = HashMap::<String, JobAssignment>::new();
+
+    let mut results = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .enumerate()
+                .filter(|(_, activity)| activity_types.contains(&activity.activity_type.as_str()))
+                .map(move |(idx, activity)| (tour, idx, activity))
+        })
+        .map(|(tour, idx, activity)| {
+            let tour_info = (tour.vehicle_id.clone(), tour.shift_index);
+            let asgn = used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
+
+            if asgn.tour_info != tour_info {
+                return Err(format!("Job served in multiple tours: '{}'", activity.job_id));
+            }
+
+            match activity.activity_type.as_str() {
+                "pickup" => asgn.pickups.push(idx),
+                "delivery" => asgn.deliveries.push(idx),
+                "service" => asgn.services.push(idx),
+                "replacement" => asgn.replacements.push(idx),
+                _ => {}
+            }
+
+            Ok(())
+        })
+        .collect::<Vec<_>>();
+
+    results.extend(used_jobs.iter().map(|(id, asgn)| {
+        // TODO validate whether each job task is served once
+        let job = all_jobs.get(id).ok_or(format!("Cannot find job with id {}", id))?;
+        let expected_tasks = job.pickups.as_ref().map_or(0, |p| p.len())
+            + job.deliveries.as_ref().map_or(0, |d| d.len())
+            + job.services.as_ref().map_or(0, |s| s.len())
+            + job.replacements.as_ref().map_or(0, |r| r.len());
+        let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
+
+        if expected_tasks != assigned_tasks {
+            return Err(format!(
+                "Not all tasks served for '{}', expected: {}, assigned: {}",
+                id, expected_tasks, assigned_tasks
+            ));
+        }
+
+        if !asgn.deliveries.is_empty() {
+            if asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
+                return Err(format!("Found pickup after delivery for '{}'", id));
+            }
+        }
+
+        Ok(())
+    }));
+
+    let all_unassigned_jobs = ctx
+        .solution
+        .unassigned
+        .iter()
+        .filter(|job| !job.job_id.ends_with("_break"))
+        .map(|job| job.job_id.clone())
+        .collect::<Vec<_>>();
+
+    let unique_unassigned_jobs = all_unassigned_jobs.iter().cloned().collect::<HashSet<_>>();
+
+    if unique_unassigned_jobs.len() != all_unassigned_jobs.len() {
+        results.push(Err("Duplicated job ids in the list of unassigned jobs".to_string()));
+    }
+
+    results.extend(unique_unassigned_jobs.iter().map(|job_id| {
+        if !all_jobs.contains_key(job_id) {
+            return Err(format!("Unknown job id in the list of unassigned jobs: '{}'", job_id));
+        }
+
+        if used_jobs.contains_key(job_id) {
+            return Err(format!("Job present as assigned and unassigned: '{}'", job_id));
+        }
+
+        Ok(())
+    }));
+
+    let all_used_job =
+        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<Vec<_>>();
+
+    if all_used_job.len() != all_jobs.len() {
+        results.push(Err(format!(
+            "Amount of jobs present in problem and solution doesn't match: {} vs {}",
+            all_jobs.len(),
+            all_used_job.len()
+        )));
+    }
+
+    combine_error_results(&results)
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/compatibility_test.rs"]
+mod compatibility_test;
+
+use super::*;
+use std::collections::HashSet;
+
+/// Checks that jobs with different compatibility tags are not served in the same tour between reloads.
+pub fn check_compatibility(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let results = context
+        .solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let intervals = tour.stops.iter().flat_map(|stop| stop.activities.iter()).fold(
+                vec![HashSet::new()],
+                |mut acc, activity| {
+                    if activity.activity_type == "reload" {
+                        acc.push(HashSet::new());
+                    } else if let Some(compatibility) =
+                        context.get_job_by_id(&activity.job_id).and_then(|job| job.compatibility.as_ref())
+                    {
+                        acc.last_mut().unwrap().insert(compatibility.clone());
+                    }
+
+                    acc
+                },
+            );
+
+            match intervals.iter().find(|compatibilities| compatibilities.len() > 1) {
+                Some(compatibilities) => {
+                    let mut compatibilities = compatibilities.iter().cloned().collect::<Vec<_>>();
+                    compatibilities.sort();
+
+                    Err(format!(
+                        "Jobs with different compatibility are served together in tour '{}': {:?}",
+                        tour.vehicle_id, compatibilities
+                    ))
+                }
+                None => Ok(()),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
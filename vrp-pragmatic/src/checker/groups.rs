@@ -0,0 +1,46 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/groups_test.rs"]
+mod groups_test;
+
+use super::*;
+use std::collections::HashSet;
+
+/// Checks that jobs of the same group are served in the same tour or not served at all.
+pub fn check_groups(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let groups = context.problem.plan.jobs.iter().fold(HashMap::<String, Vec<String>>::new(), |mut acc, job| {
+        if let Some(group) = job.group.as_ref() {
+            acc.entry(group.clone()).or_insert_with(Vec::new).push(job.id.clone());
+        }
+        acc
+    });
+
+    let tours = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .map(move |activity| (activity.job_id.clone(), (tour.vehicle_id.clone(), tour.shift_index)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let results = groups
+        .iter()
+        .map(|(group, job_ids)| {
+            let used_tours = job_ids.iter().filter_map(|job_id| tours.get(job_id)).collect::<HashSet<_>>();
+            let assigned = job_ids.iter().filter(|job_id| tours.contains_key(*job_id)).count();
+
+            if used_tours.len() > 1 {
+                Err(format!("Jobs of group '{}' are served in {} tours", group, used_tours.len()))
+            } else if assigned > 0 && assigned < job_ids.len() {
+                Err(format!("Jobs of group '{}' are partially assigned: {} of {}", group, assigned, job_ids.len()))
+            } else {
+                Ok(())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/limits_test.rs"]
+mod limits_test;
+
+use super::*;
+use std::collections::HashSet;
+
+/// Checks that tours are served within vehicle shift time and do not violate vehicle limits:
+/// * max distance and shift time
+/// * max activities, max and min jobs
+pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let results = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let errors = match get_tour_limit_errors(context, tour) {
+                Ok(errors) => errors,
+                Err(error) => vec![error],
+            };
+
+            errors.into_iter().map(Err)
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
+
+fn get_tour_limit_errors(context: &CheckerContext, tour: &Tour) -> Result<Vec<String>, String> {
+    let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+    let shift = context.get_vehicle_shift(tour)?;
+
+    let (first, last) = match (tour.stops.first(), tour.stops.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Err(format!("Tour of vehicle '{}' has no stops", tour.vehicle_id)),
+    };
+
+    let departure = parse_time(&first.time.departure);
+    let arrival = parse_time(&last.time.arrival);
+    let duration = arrival - departure;
+    let distance = last.distance as f64;
+
+    let job_ids = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| context.get_job_by_id(&activity.job_id).is_some())
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    let activities = job_ids.len();
+    let jobs = job_ids.iter().collect::<HashSet<_>>().len();
+
+    // NOTE tours with locked jobs are not removed when they have less jobs than required
+    let has_relations = context
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .any(|relation| relation.vehicle_id == tour.vehicle_id && !relation.jobs.is_empty());
+
+    let limits = vehicle.limits.clone().unwrap_or(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        max_activities: None,
+        max_jobs: None,
+        min_jobs: None,
+    });
+
+    let errors = vec![
+        (departure < parse_time(&shift.start.time), format!("departure is before shift start '{}'", shift.start.time)),
+        (
+            matches!(&shift.end, Some(end) if arrival > parse_time(&end.time)),
+            format!("arrival is after shift end '{:?}'", shift.end.as_ref().map(|end| &end.time)),
+        ),
+        (
+            matches!(limits.max_distance, Some(max) if distance > max),
+            format!("distance '{}' exceeds max distance '{:?}'", distance, limits.max_distance),
+        ),
+        (
+            matches!(limits.shift_time, Some(max) if duration > max),
+            format!("duration '{}' exceeds shift time '{:?}'", duration, limits.shift_time),
+        ),
+        (
+            matches!(limits.max_activities, Some(max) if activities > max),
+            format!("amount of activities '{}' exceeds max activities '{:?}'", activities, limits.max_activities),
+        ),
+        (
+            matches!(limits.max_jobs, Some(max) if jobs > max),
+            format!("amount of jobs '{}' exceeds max jobs '{:?}'", jobs, limits.max_jobs),
+        ),
+        (
+            matches!(limits.min_jobs, Some(min) if jobs > 0 && jobs < min && !has_relations),
+            format!("amount of jobs '{}' is less than min jobs '{:?}'", jobs, limits.min_jobs),
+        ),
+    ]
+    .into_iter()
+    .filter(|(is_violated, _)| *is_violated)
+    .map(|(_, message)| format!("Tour of vehicle '{}': {}", tour.vehicle_id, message))
+    .collect();
+
+    Ok(errors)
+}
@@ -0,0 +1,355 @@
+//! This module provides functionality to automatically check that given solution is feasible
+//! which means that there is no constraint violations.
+
+#[cfg(test)]
+#[path = "../../tests/unit/checker/checker_test.rs"]
+mod checker_test;
+
+use crate::json::problem::*;
+use crate::json::solution::*;
+use crate::json::Location;
+use crate::{parse_time, parse_time_safe};
+use std::collections::HashMap;
+use std::fmt;
+use vrp_core::models::common::TimeWindow;
+
+/// Stores problem and solution together and provides some helper methods.
+pub struct CheckerContext {
+    /// An original problem definition.
+    pub problem: Problem,
+    /// Routing matrices used to solve the problem.
+    pub matrices: Option<Vec<Matrix>>,
+    /// A solution to be checked.
+    pub solution: Solution,
+    job_map: HashMap<String, Job>,
+}
+
+/// Represents all possible activity types.
+pub enum ActivityType {
+    /// A departure or arrival activity.
+    Terminal,
+    /// A job activity.
+    Job(Job),
+    /// A vehicle break activity.
+    Break(VehicleBreak),
+    /// A vehicle reload activity.
+    Reload(VehicleReload),
+}
+
+/// Specifies a group of rules checked in the solution.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CheckRule {
+    /// Solution format rules.
+    Format,
+    /// Jobs and vehicles assignment rules.
+    Assignment,
+    /// Vehicle capacity and load change rules.
+    Capacity,
+    /// Vehicle break rules.
+    Breaks,
+    /// Job relation rules.
+    Relations,
+    /// Job max ride time rules.
+    RideTime,
+    /// Job group rules.
+    Groups,
+    /// Job compatibility rules.
+    Compatibility,
+    /// Job time window rules.
+    TimeWindows,
+    /// Job skills rules.
+    Skills,
+    /// Vehicle shift and tour size limit rules.
+    Limits,
+    /// Stop schedule and distance rules checked against routing matrices.
+    Routing,
+}
+
+/// Represents a rule violation found in the solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// A violated rule.
+    pub rule: CheckRule,
+    /// A violation description.
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}: {}", self.rule, self.message)
+    }
+}
+
+/// Checks solution against all known rules and returns list of violations, if there are some.
+pub fn check_solution(ctx: &CheckerContext) -> Result<(), Vec<Violation>> {
+    // NOTE other rules expect that solution has proper format
+    check_format(ctx).map_err(|message| vec![Violation { rule: CheckRule::Format, message }])?;
+
+    let violations = vec![
+        (CheckRule::Assignment, check_assignment(ctx)),
+        (CheckRule::Capacity, check_vehicle_load(ctx)),
+        (CheckRule::Breaks, check_breaks(ctx)),
+        (CheckRule::Relations, check_relations(ctx)),
+        (CheckRule::RideTime, check_ride_time(ctx)),
+        (CheckRule::Groups, check_groups(ctx)),
+        (CheckRule::Compatibility, check_compatibility(ctx)),
+        (CheckRule::TimeWindows, check_time_windows(ctx)),
+        (CheckRule::Skills, check_skills(ctx)),
+        (CheckRule::Limits, check_limits(ctx)),
+        (CheckRule::Routing, check_routing(ctx)),
+    ]
+    .into_iter()
+    .flat_map(|(rule, result)| {
+        result.err().into_iter().flatten().map(move |message| Violation { rule: rule.clone(), message })
+    })
+    .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+impl CheckerContext {
+    /// Creates a new instance of `CheckerContext`.
+    pub fn new(problem: Problem, matrices: Option<Vec<Matrix>>, solution: Solution) -> Self {
+        let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
+
+        Self { problem, matrices, solution, job_map }
+    }
+
+    /// Gets vehicle by its id.
+    pub fn get_vehicle(&self, vehicle_id: &str) -> Result<&VehicleType, String> {
+        self.problem
+            .fleet
+            .vehicles
+            .iter()
+            .find(|v| v.vehicle_ids.iter().any(|id| id == vehicle_id))
+            .ok_or(format!("Cannot find vehicle with id '{}'", vehicle_id))
+    }
+
+    /// Gets job by its id.
+    pub fn get_job_by_id(&self, job_id: &str) -> Option<&Job> {
+        self.job_map.get(job_id)
+    }
+
+    /// Gets activity operation time range in seconds since Unix epoch.
+    pub fn get_activity_time(&self, stop: &Stop, activity: &Activity) -> TimeWindow {
+        let time = activity
+            .time
+            .clone()
+            .unwrap_or_else(|| Interval { start: stop.time.arrival.clone(), end: stop.time.departure.clone() });
+
+        TimeWindow::new(parse_time(&time.start), parse_time(&time.end))
+    }
+
+    /// Gets activity location.
+    pub fn get_activity_location(&self, stop: &Stop, activity: &Activity) -> Location {
+        activity.location.clone().unwrap_or_else(|| stop.location.clone())
+    }
+
+    /// Gets vehicle shift where activity is used.
+    pub fn get_vehicle_shift(&self, tour: &Tour) -> Result<VehicleShift, String> {
+        let tour_time = TimeWindow::new(
+            parse_time(&tour.stops.first().as_ref().ok_or_else(|| format!("Cannot get first activity"))?.time.arrival),
+            parse_time(&tour.stops.last().as_ref().ok_or_else(|| format!("Cannot get last activity"))?.time.arrival),
+        );
+
+        self.get_vehicle(tour.vehicle_id.as_str())?
+            .shifts
+            .iter()
+            .find(|shift| {
+                let shift_time = TimeWindow::new(
+                    parse_time(&shift.start.time),
+                    shift.end.as_ref().map_or_else(|| std::f64::MAX, |place| parse_time(&place.time)),
+                );
+                shift_time.intersects(&tour_time)
+            })
+            .cloned()
+            .ok_or_else(|| format!("Cannot find shift for tour with vehicle if: '{}'", tour.vehicle_id))
+    }
+
+    /// Returns stop's activity type names.
+    pub fn get_stop_activity_types(&self, stop: &Stop) -> Vec<String> {
+        stop.activities.iter().map(|a| a.activity_type.clone()).collect()
+    }
+
+    /// Gets wrapped activity type.
+    pub fn get_activity_type(&self, tour: &Tour, stop: &Stop, activity: &Activity) -> Result<ActivityType, String> {
+        let shift = self.get_vehicle_shift(tour)?;
+        let time = self.get_activity_time(stop, activity);
+        let location = self.get_activity_location(stop, activity);
+
+        match activity.activity_type.as_str() {
+            "departure" | "arrival" => Ok(ActivityType::Terminal),
+            "pickup" | "delivery" | "service" | "replacement" => {
+                self.job_map.get(activity.job_id.as_str()).map_or_else(
+                    || Err(format!("Cannot find job with id '{}'", activity.job_id)),
+                    |job| Ok(ActivityType::Job(job.clone())),
+                )
+            }
+            "break" => shift
+                .breaks
+                .as_ref()
+                .and_then(|breaks| {
+                    breaks.iter().find(|b| match &b.time {
+                        VehicleBreakTime::TimeWindow(tw) => {
+                            matches!(parse_time_window(tw), Ok(tw) if tw.intersects(&time))
+                        }
+                        VehicleBreakTime::TimeOffset(offset) => match (tour.stops.first(), offset.as_slice()) {
+                            // NOTE make expected time window wider due to reschedule departure
+                            (Some(stop), [start, end]) => TimeWindow::new(
+                                parse_time(&stop.time.arrival) + *start,
+                                parse_time(&stop.time.departure) + *end,
+                            )
+                            .intersects(&time),
+                            _ => false,
+                        },
+                    })
+                })
+                .map(|b| ActivityType::Break(b.clone()))
+                .ok_or_else(|| format!("Cannot find break for tour '{}'", tour.vehicle_id)),
+            "reload" => shift
+                .reloads
+                .as_ref()
+                // TODO match reload's time windows
+                .and_then(|reload| reload.iter().find(|r| r.location == location && r.tag == activity.job_tag))
+                .map(|r| ActivityType::Reload(r.clone()))
+                .ok_or_else(|| format!("Cannot find reload for tour '{}'", tour.vehicle_id)),
+
+            _ => Err(format!("Unknown activity type: '{}'", activity.activity_type)),
+        }
+    }
+
+    /// Visits job task if activity belongs to the job or calls other visitor otherwise.
+    pub fn visit_job<F1, F2, R>(
+        &self,
+        activity: &Activity,
+        activity_type: &ActivityType,
+        job_visitor: F1,
+        other_visitor: F2,
+    ) -> Result<R, String>
+    where
+        F1: Fn(&Job, &JobTask) -> R,
+        F2: Fn() -> R,
+    {
+        match activity_type {
+            ActivityType::Job(job) => {
+                let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
+                let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
+
+                let tasks = match activity.activity_type.as_str() {
+                    "pickup" => &job.pickups,
+                    "delivery" => &job.deliveries,
+                    "service" => &job.services,
+                    _ => &job.replacements,
+                };
+
+                if pickups < 2 && deliveries < 2 {
+                    tasks.as_ref().and_then(|task| task.first())
+                } else {
+                    activity.job_tag.as_ref().ok_or(format!("Multi job activity must have tag {}", activity.job_id))?;
+
+                    tasks.iter().flat_map(|tasks| tasks.iter()).find(|task| task.tag == activity.job_tag)
+                }
+                .map(|task| job_visitor(job, task))
+            }
+            .ok_or("Cannot match activity to job place".to_string()),
+            _ => Ok(other_visitor()),
+        }
+    }
+}
+
+/// Checks that solution has tours with stops and all its times can be parsed.
+fn check_format(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        if tour.stops.is_empty() {
+            return Err(format!("Tour of vehicle '{}' has no stops", tour.vehicle_id));
+        }
+
+        tour.stops.iter().try_for_each(|stop| {
+            let activity_times = stop
+                .activities
+                .iter()
+                .filter_map(|activity| activity.time.as_ref())
+                .flat_map(|time| vec![&time.start, &time.end]);
+
+            vec![&stop.time.arrival, &stop.time.departure].into_iter().chain(activity_times).try_for_each(|time| {
+                parse_time_safe(time)
+                    .map(|_| ())
+                    .map_err(|_| format!("Cannot parse time '{}' in tour of vehicle '{}'", time, tour.vehicle_id))
+            })
+        })
+    })
+}
+
+/// Collects errors of all given results, so a rule can report every violation instead of the first one.
+fn combine_error_results(results: &[Result<(), String>]) -> Result<(), Vec<String>> {
+    let errors = results.iter().cloned().flat_map(|result| result.err().into_iter()).collect::<Vec<String>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn parse_time_window(tw: &Vec<String>) -> Result<TimeWindow, String> {
+    match tw.as_slice() {
+        [start, end] => parse_time_safe(start)
+            .and_then(|start| parse_time_safe(end).map(|end| TimeWindow::new(start, end)))
+            .map_err(|_| format!("Cannot parse time window: '{:?}'", tw)),
+        _ => Err(format!("Invalid time window: '{:?}'", tw)),
+    }
+}
+
+fn get_time_window(stop: &Stop, activity: &Activity) -> TimeWindow {
+    let (start, end) = activity
+        .time
+        .as_ref()
+        .map_or_else(|| (&stop.time.arrival, &stop.time.departure), |interval| (&interval.start, &interval.end));
+
+    TimeWindow::new(parse_time(start), parse_time(end))
+}
+
+fn get_location(stop: &Stop, activity: &Activity) -> Location {
+    activity.location.as_ref().unwrap_or_else(|| &stop.location).clone()
+}
+
+fn same_locations(left: &Location, right: &Location) -> bool {
+    left == right
+}
+
+mod assignment;
+pub use self::assignment::*;
+
+mod capacity;
+pub use self::capacity::*;
+
+mod breaks;
+pub use self::breaks::*;
+
+mod relations;
+pub use self::relations::*;
+
+mod ride_time;
+pub use self::ride_time::*;
+
+mod groups;
+pub use self::groups::*;
+
+mod compatibility;
+pub use self::compatibility::*;
+
+mod time_windows;
+pub use self::time_windows::*;
+
+mod skills;
+pub use self::skills::*;
+
+mod limits;
+pub use self::limits::*;
+
+mod routing;
+pub use self::routing::*;
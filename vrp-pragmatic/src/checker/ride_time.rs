@@ -0,0 +1,50 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/ride_time_test.rs"]
+mod ride_time_test;
+
+use super::*;
+
+/// Checks that time between pickup and delivery of each job does not exceed its max ride time.
+pub fn check_ride_time(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let results = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let rides = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+                .fold(HashMap::<String, (Option<f64>, Option<f64>)>::new(), |mut acc, (stop, activity)| {
+                    let time = get_time_window(stop, activity);
+                    let entry = acc.entry(activity.job_id.clone()).or_insert((None, None));
+
+                    match activity.activity_type.as_str() {
+                        "pickup" if entry.0.is_none() => entry.0 = Some(time.end),
+                        "delivery" => entry.1 = Some(time.start),
+                        _ => {}
+                    }
+
+                    acc
+                });
+
+            rides.into_iter().map(move |(job_id, ride)| {
+                let max_ride_time = context.get_job_by_id(&job_id).and_then(|job| job.max_ride_time);
+
+                match (max_ride_time, ride) {
+                    (Some(max_ride_time), (Some(departure), Some(arrival))) if arrival - departure > max_ride_time => {
+                        Err(format!(
+                            "Ride time of job '{}' is '{}', but max ride time is '{}'",
+                            job_id,
+                            arrival - departure,
+                            max_ride_time
+                        ))
+                    }
+                    _ => Ok(()),
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/routing_test.rs"]
+mod routing_test;
+
+use super::*;
+use crate::json::coord_index::CoordIndex;
+
+/// Checks that stop arrival times and distances are consistent with routing matrices. The check
+/// is skipped when matrices are not provided or profile has time dependent matrices.
+pub fn check_routing(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let matrices = match context.matrices.as_ref() {
+        Some(matrices) => matrices,
+        None => return Ok(()),
+    };
+
+    let coord_index = &CoordIndex::new(&context.problem);
+
+    let results = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let matrix = context.get_vehicle(&tour.vehicle_id).ok().and_then(|vehicle| {
+                let mut profile_matrices = matrices.iter().filter(|matrix| matrix.profile == vehicle.profile);
+                match (profile_matrices.next(), profile_matrices.next()) {
+                    (Some(matrix), None) => Some(matrix),
+                    _ => None,
+                }
+            });
+
+            tour.stops.windows(2).zip(1..).filter_map(move |(leg, idx)| match (matrix, leg) {
+                (Some(matrix), [from, to]) => Some(check_leg(coord_index, matrix, tour, idx, from, to)),
+                _ => None,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
+
+fn check_leg(
+    coord_index: &CoordIndex,
+    matrix: &Matrix,
+    tour: &Tour,
+    idx: usize,
+    from: &Stop,
+    to: &Stop,
+) -> Result<(), String> {
+    let get_index = |location: &Location| {
+        coord_index.get_by_loc(location).ok_or_else(|| format!("Cannot find index for location '{:?}'", location))
+    };
+
+    let size = (matrix.travel_times.len() as f64).sqrt().round() as usize;
+    let matrix_idx = get_index(&from.location)? * size + get_index(&to.location)?;
+
+    let (duration, distance) = match (matrix.travel_times.get(matrix_idx), matrix.distances.get(matrix_idx)) {
+        (Some(duration), Some(distance)) => (*duration as f64, *distance as i32),
+        _ => return Err(format!("Cannot find routing data for stop {} in tour '{}'", idx, tour.vehicle_id)),
+    };
+
+    let expected_arrival = parse_time(&from.time.departure) + duration;
+    let actual_arrival = parse_time(&to.time.arrival);
+    if expected_arrival != actual_arrival {
+        return Err(format!(
+            "Arrival time at stop {} in tour '{}' does not match routing: expected '{}', got '{}'",
+            idx, tour.vehicle_id, expected_arrival, actual_arrival
+        ));
+    }
+
+    let expected_distance = from.distance + distance;
+    if expected_distance != to.distance {
+        return Err(format!(
+            "Distance at stop {} in tour '{}' does not match routing: expected '{}', got '{}'",
+            idx, tour.vehicle_id, expected_distance, to.distance
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/skills_test.rs"]
+mod skills_test;
+
+use super::*;
+use std::collections::HashSet;
+
+/// Checks that job skills are satisfied by skills of vehicle and driver which serve the job.
+pub fn check_skills(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let results = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let mut job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+            job_ids.sort();
+            job_ids.dedup();
+
+            job_ids.into_iter().filter_map(move |job_id| context.get_job_by_id(&job_id).map(|job| (tour, job)))
+        })
+        .map(|(tour, job)| {
+            let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+            let driver = tour.driver_id.as_ref().and_then(|driver_id| {
+                context.problem.fleet.drivers.iter().flatten().find(|driver| driver.id == *driver_id)
+            });
+
+            let skills = vehicle
+                .skills
+                .iter()
+                .flatten()
+                .chain(driver.and_then(|driver| driver.skills.as_ref()).into_iter().flatten())
+                .collect::<HashSet<_>>();
+
+            let (all_of, one_of, none_of) = match &job.skills {
+                Some(JobSkills::List(all_of)) => (Some(all_of), None, None),
+                Some(JobSkills::Sets(sets)) => (sets.all_of.as_ref(), sets.one_of.as_ref(), sets.none_of.as_ref()),
+                None => (None, None, None),
+            };
+
+            let is_valid = all_of.iter().flat_map(|all_of| all_of.iter()).all(|skill| skills.contains(skill))
+                && one_of.iter().all(|one_of| one_of.iter().any(|skill| skills.contains(skill)))
+                && !none_of.iter().flat_map(|none_of| none_of.iter()).any(|skill| skills.contains(skill));
+
+            if is_valid {
+                Ok(())
+            } else {
+                Err(format!("Job '{}' is served in tour '{}' which has no required skills", job.id, tour.vehicle_id))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
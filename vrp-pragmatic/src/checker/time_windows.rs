@@ -0,0 +1,53 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/time_windows_test.rs"]
+mod time_windows_test;
+
+use super::*;
+
+/// Checks that job activities are served within time windows of their places. Time window end is
+/// extended by max lateness when place has it.
+pub fn check_time_windows(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let results = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(move |stop| stop.activities.iter().map(move |activity| (tour, stop, activity)))
+        })
+        .map(|(tour, stop, activity)| {
+            let activity_type = context.get_activity_type(tour, stop, activity)?;
+            let time = get_time_window(stop, activity);
+            let location = get_location(stop, activity);
+
+            let is_valid = context.visit_job(
+                activity,
+                &activity_type,
+                |_, task| {
+                    task.places.iter().filter(|place| same_locations(&place.location, &location)).any(|place| {
+                        let max_lateness = place.lateness.as_ref().map_or(0., |lateness| lateness.max);
+
+                        match &place.times {
+                            Some(times) => times
+                                .iter()
+                                .filter_map(|tw| parse_time_window(tw).ok())
+                                .any(|tw| time.start <= tw.end + max_lateness && time.end >= tw.start),
+                            None => true,
+                        }
+                    })
+                },
+                || true,
+            )?;
+
+            if is_valid {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Activity of job '{}' is served outside of its time windows in tour '{}': '{:?}'",
+                    activity.job_id, tour.vehicle_id, time
+                ))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    combine_error_results(&results)
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/compatibility_test.rs"]
+mod compatibility_test;
+
+use crate::constraints::{as_single_job, COMPATIBILITY_KEY};
+use std::collections::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::{Job, Multi};
+use vrp_core::models::solution::{Activity, Route};
+
+/// Keeps jobs with different compatibility tags apart: they cannot be served in the same tour or,
+/// if vehicle has reloads, between the same reloads. Jobs without a tag are compatible with any job.
+pub struct CompatibilityModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl CompatibilityModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(CompatibilityHardActivityConstraint { code }))],
+            keys: vec![COMPATIBILITY_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for CompatibilityModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let intervals = get_intervals(ctx)
+            .into_iter()
+            .map(|(start_idx, end_idx)| {
+                let compatibility = ctx
+                    .route
+                    .tour
+                    .activities_slice(start_idx, end_idx)
+                    .iter()
+                    .filter_map(|activity| get_compatibility(activity))
+                    .next();
+
+                (start_idx, end_idx, compatibility)
+            })
+            .collect::<Vec<_>>();
+
+        ctx.state_mut().put_route_state(COMPATIBILITY_KEY, intervals);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE removal of reloads merges their intervals, so jobs with different tags can end up
+        // between the same reloads: such jobs are unassigned and states of changed routes are
+        // updated by constraint pipeline
+        let jobs = ctx.routes.iter_mut().fold(HashSet::new(), |mut acc, route_ctx| {
+            let jobs = get_incompatible_jobs(&route_ctx.route);
+
+            jobs.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+
+            acc.extend(jobs);
+
+            acc
+        });
+
+        ctx.unassigned.extend(jobs.into_iter().map(|job| (job, self.code)));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CompatibilityHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for CompatibilityHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let target = get_compatibility(activity_ctx.target)?;
+
+        let interval_compatibility = route_ctx
+            .state
+            .get_route_state::<Vec<(usize, usize, Option<String>)>>(COMPATIBILITY_KEY)
+            .and_then(|intervals| {
+                intervals
+                    .iter()
+                    .find(|(start_idx, end_idx, _)| *start_idx <= activity_ctx.index && activity_ctx.index <= *end_idx)
+            })
+            .and_then(|(_, _, compatibility)| compatibility.as_ref());
+
+        match interval_compatibility {
+            Some(compatibility) if *compatibility != target => {
+                Some(ActivityConstraintViolation { code: self.code, stopped: false })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns reload intervals of the route or the whole tour as one interval.
+fn get_intervals(ctx: &RouteContext) -> Vec<(usize, usize)> {
+    ctx.state
+        .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS)
+        .cloned()
+        .unwrap_or_else(|| vec![(0, ctx.route.tour.total().max(1) - 1)])
+}
+
+/// Returns jobs which compatibility differs from the first one found in their reload interval.
+fn get_incompatible_jobs(route: &Route) -> HashSet<Job> {
+    route_intervals(route, Box::new(|activity| is_reload(activity))).into_iter().fold(
+        HashSet::new(),
+        |mut acc, (start_idx, end_idx)| {
+            let activities = route.tour.activities_slice(start_idx, end_idx);
+            let compatibility = activities.iter().filter_map(|activity| get_compatibility(activity)).next();
+
+            acc.extend(
+                activities
+                    .iter()
+                    .filter(|activity| {
+                        matches!(get_compatibility(activity), Some(other) if Some(&other) != compatibility.as_ref())
+                    })
+                    .filter_map(|activity| activity.retrieve_job()),
+            );
+
+            acc
+        },
+    )
+}
+
+fn is_reload(activity: &Activity) -> bool {
+    as_single_job(activity, |job| matches!(job.dimens.get_value::<String>("type"), Some(t) if t == "reload")).is_some()
+}
+
+fn get_compatibility(activity: &Activity) -> Option<String> {
+    let single = activity.job.as_ref()?;
+
+    single
+        .dimens
+        .get_value::<String>("compatibility")
+        .cloned()
+        .or_else(|| Multi::roots(single).and_then(|multi| multi.dimens.get_value::<String>("compatibility").cloned()))
+}
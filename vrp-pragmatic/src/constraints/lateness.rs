@@ -0,0 +1,104 @@
+use crate::extensions::get_activity_lateness;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost};
+use vrp_core::models::solution::Activity;
+
+/// Penalizes late arrival at activities with soft time windows: total lateness cost of the route
+/// is kept as its extra cost.
+pub struct LatenessModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl LatenessModule {
+    pub fn new(activity: Arc<dyn ActivityCost + Send + Sync>, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(LatenessSoftActivityConstraint {
+                activity,
+                transport,
+            }))],
+            keys: vec![EXTRA_COST_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for LatenessModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cost = ctx
+            .route
+            .tour
+            .all_activities()
+            .map(|activity| get_lateness_cost(activity, activity.schedule.arrival))
+            .sum::<Cost>();
+
+        ctx.state_mut().put_route_state(EXTRA_COST_KEY, cost);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Estimates change of lateness costs caused by activity insertion: lateness of the target activity
+/// and lateness of the following activities which are shifted in time.
+struct LatenessSoftActivityConstraint {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for LatenessSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let actor = route_ctx.route.actor.as_ref();
+        let profile = actor.vehicle.profile;
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(profile, prev.place.location, target.place.location, prev.schedule.departure);
+        let departure = arrival.max(target.place.time.start) + self.activity.duration(actor, target, arrival);
+        let target_cost = get_lateness_cost(target, arrival);
+
+        let next = match activity_ctx.next {
+            Some(next) => next,
+            None => return target_cost,
+        };
+
+        let next_arrival =
+            departure + self.transport.duration(profile, target.place.location, next.place.location, departure);
+        let mut shift = next_arrival - next.schedule.arrival;
+        let mut shift_cost = 0.;
+
+        for activity in route_ctx.route.tour.all_activities().skip(activity_ctx.index + 1) {
+            if shift <= 0. {
+                break;
+            }
+
+            let old_arrival = activity.schedule.arrival;
+            let new_arrival = old_arrival + shift;
+
+            shift_cost += get_lateness_cost(activity, new_arrival) - get_lateness_cost(activity, old_arrival);
+            shift = new_arrival.max(activity.place.time.start) - old_arrival.max(activity.place.time.start);
+        }
+
+        target_cost + shift_cost
+    }
+}
+
+fn get_lateness_cost(activity: &Activity, arrival: Timestamp) -> Cost {
+    get_activity_lateness(activity, arrival).map_or(0., |(_, cost)| cost)
+}
@@ -8,6 +8,14 @@ use vrp_core::models::common::{Dimensions, IdDimension, ValueDimension};
 use vrp_core::models::problem::{Costs, Single};
 use vrp_core::models::solution::{Activity, Route};
 
+/// A key of activity state which keeps max delay of arrival which does not violate ride time
+/// of any job picked up before the activity.
+const MAX_RIDE_DELAY_KEY: i32 = 101;
+/// A key of route state which keeps amount of activities and jobs in the tour.
+const TOUR_SIZE_KEY: i32 = 102;
+/// A key of route state which keeps compatibility of jobs in each reload interval.
+const COMPATIBILITY_KEY: i32 = 103;
+
 fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
 where
     F: Fn(&Arc<Single>) -> bool,
@@ -65,4 +73,16 @@ mod reachable;
 pub use self::reachable::ReachableModule;
 
 mod skills;
-pub use self::skills::SkillsModule;
+pub use self::skills::{JobSkills, SkillsModule};
+
+mod ride_time;
+pub use self::ride_time::RideTimeModule;
+
+mod tour_size;
+pub use self::tour_size::*;
+
+mod compatibility;
+pub use self::compatibility::CompatibilityModule;
+
+mod lateness;
+pub use self::lateness::LatenessModule;
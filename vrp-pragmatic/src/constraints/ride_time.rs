@@ -0,0 +1,160 @@
+use crate::constraints::MAX_RIDE_DELAY_KEY;
+use std::collections::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Timestamp, ValueDimension};
+use vrp_core::models::problem::{Job, Multi, TransportCost};
+use vrp_core::models::solution::{Activity, TourActivity};
+
+/// Limits time between pickup and delivery of multi job: time between departure from the earliest
+/// pickup and arrival to the latest delivery cannot exceed job's max ride time. As such job has either
+/// single pickup or single delivery (see E1107 validation), this limits ride time of each pickup and
+/// delivery pair.
+pub struct RideTimeModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl RideTimeModule {
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(RideTimeHardActivityConstraint {
+                transport,
+                code,
+            }))],
+            keys: vec![MAX_RIDE_DELAY_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for RideTimeModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let (route, state) = ctx.as_mut();
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+        // NOTE keeps indices of the earliest pickup and the latest delivery for each multi job
+        let bounds = activities.iter().enumerate().fold(HashMap::new(), |mut acc, (idx, activity)| {
+            if let Some((multi, activity_type)) = get_multi_with_type(activity) {
+                let key = multi.as_ref() as *const Multi as usize;
+                let entry = acc.entry(key).or_insert((None, None));
+                match activity_type.as_str() {
+                    "pickup" if entry.0.is_none() => entry.0 = Some(idx),
+                    "delivery" => entry.1 = Some(idx),
+                    _ => {}
+                }
+            }
+            acc
+        });
+
+        // NOTE keeps allowed arrival delay for each ride in progress
+        let mut rides: Vec<(usize, Duration)> = vec![];
+
+        activities.iter().enumerate().skip(1).rev().for_each(|(idx, activity)| {
+            let waiting = (activity.place.time.start - activity.schedule.arrival).max(0.);
+            rides.iter_mut().for_each(|(_, delay)| *delay += waiting);
+
+            if let Some((multi, _)) = get_multi_with_type(activity) {
+                let key = multi.as_ref() as *const Multi as usize;
+
+                match bounds.get(&key) {
+                    Some((Some(pickup_idx), _)) if *pickup_idx == idx => rides.retain(|(other, _)| *other != key),
+                    Some((Some(pickup_idx), Some(delivery_idx))) if *delivery_idx == idx => {
+                        if let Some(max_ride_time) = get_max_ride_time(&multi) {
+                            let departure = activities[*pickup_idx].schedule.departure;
+                            rides.push((key, max_ride_time - (activity.schedule.arrival - departure)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let max_delay = rides.iter().map(|(_, delay)| *delay).fold(f64::MAX, f64::min);
+            state.put_activity_state(MAX_RIDE_DELAY_KEY, activity, max_delay);
+        });
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct RideTimeHardActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    code: i32,
+}
+
+impl HardActivityConstraint for RideTimeHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(profile, prev.place.location, target.place.location, prev.schedule.departure);
+
+        if let Some((multi, activity_type)) = get_multi_with_type(target) {
+            if activity_type == "delivery" {
+                let departure = get_pickup_departure(route_ctx.route.tour.all_activities(), &multi);
+
+                if let (Some(max_ride_time), Some(departure)) = (get_max_ride_time(&multi), departure) {
+                    if arrival - departure > max_ride_time {
+                        return Some(ActivityConstraintViolation { code: self.code, stopped: false });
+                    }
+                }
+            }
+        }
+
+        if let Some(next) = activity_ctx.next {
+            if let Some(max_delay) = route_ctx.state.get_activity_state::<Duration>(MAX_RIDE_DELAY_KEY, next) {
+                let departure = arrival.max(target.place.time.start) + target.place.duration;
+                let next_arrival =
+                    departure + self.transport.duration(profile, target.place.location, next.place.location, departure);
+
+                if next_arrival - next.schedule.arrival > *max_delay {
+                    return Some(ActivityConstraintViolation { code: self.code, stopped: false });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn get_multi_with_type(activity: &Activity) -> Option<(Arc<Multi>, &String)> {
+    let single = activity.job.as_ref()?;
+
+    Multi::roots(single).zip(single.dimens.get_value::<String>("type"))
+}
+
+fn get_max_ride_time(multi: &Multi) -> Option<Duration> {
+    multi.dimens.get_value::<Duration>("max_ride_time").cloned()
+}
+
+/// Returns departure time from the earliest pickup of given multi job.
+fn get_pickup_departure<'a>(
+    mut activities: impl Iterator<Item = &'a TourActivity>,
+    multi: &Arc<Multi>,
+) -> Option<Timestamp> {
+    activities
+        .find(|activity| {
+            matches!(get_multi_with_type(activity),
+                Some((other, activity_type)) if Arc::ptr_eq(multi, &other) && activity_type == "pickup")
+        })
+        .map(|activity| activity.schedule.departure)
+}
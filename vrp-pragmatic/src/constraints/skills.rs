@@ -4,8 +4,20 @@ use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
 use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::Job;
+use vrp_core::models::problem::{Actor, Job};
 
+/// Specifies skills required by job.
+#[derive(Clone, Debug, Default)]
+pub struct JobSkills {
+    /// All these skills have to be present.
+    pub all_of: Option<HashSet<String>>,
+    /// At least one of these skills has to be present.
+    pub one_of: Option<HashSet<String>>,
+    /// None of these skills has to be present.
+    pub none_of: Option<HashSet<String>>,
+}
+
+/// Checks that job skills are satisfied by vehicle or driver skills.
 pub struct SkillsModule {
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
@@ -42,11 +54,15 @@ struct SkillsHardRouteConstraint {
 
 impl HardRouteConstraint for SkillsHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
-        if let Some(requirement) = job.dimens().get_value::<HashSet<String>>("skills") {
-            if let Some(skills) = ctx.route.actor.vehicle.dimens.get_value::<HashSet<String>>("skills") {
-                if requirement.is_subset(skills) {
-                    return None;
-                }
+        if let Some(skills) = job.dimens().get_value::<JobSkills>("skills") {
+            let actor = ctx.route.actor.as_ref();
+
+            let all_of = skills.all_of.iter().flatten().all(|skill| has_skill(actor, skill));
+            let one_of = skills.one_of.iter().all(|one_of| one_of.iter().any(|skill| has_skill(actor, skill)));
+            let none_of = !skills.none_of.iter().flatten().any(|skill| has_skill(actor, skill));
+
+            if all_of && one_of && none_of {
+                return None;
             }
 
             Some(RouteConstraintViolation { code: self.code })
@@ -55,3 +71,11 @@ impl HardRouteConstraint for SkillsHardRouteConstraint {
         }
     }
 }
+
+/// Checks whether skill is provided either by vehicle or driver.
+fn has_skill(actor: &Actor, skill: &String) -> bool {
+    let vehicle_skills = actor.vehicle.dimens.get_value::<HashSet<String>>("skills");
+    let driver_skills = actor.driver.dimens.get_value::<HashSet<String>>("skills");
+
+    vehicle_skills.iter().chain(driver_skills.iter()).any(|skills| skills.contains(skill))
+}
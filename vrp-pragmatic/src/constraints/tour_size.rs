@@ -0,0 +1,146 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/tour_size_test.rs"]
+mod tour_size_test;
+
+use crate::constraints::{get_vehicle_id_from_job, TOUR_SIZE_KEY};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::{Actor, Job};
+use vrp_core::models::solution::Tour;
+
+/// Specifies limits on amount of activities and jobs in the tour.
+#[derive(Clone, Debug, Default)]
+pub struct TourSizeLimits {
+    /// Max amount of job activities in the tour.
+    pub max_activities: Option<usize>,
+    /// Max amount of jobs in the tour.
+    pub max_jobs: Option<usize>,
+    /// Min amount of jobs in the tour, if it is used.
+    pub min_jobs: Option<usize>,
+}
+
+/// A function which returns tour size limits for given actor.
+pub type TourSizeLimitFunc = Arc<dyn Fn(&Actor) -> TourSizeLimits + Send + Sync>;
+
+/// Limits amount of activities and jobs served in one tour. Breaks and reloads are not counted.
+/// Tours which have less jobs than required minimum are removed: while insertion is in progress,
+/// their jobs are returned to required ones, so they can be inserted into other tours, otherwise
+/// they are reported as unassigned.
+pub struct TourSizeModule {
+    code: i32,
+    limit_func: TourSizeLimitFunc,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TourSizeModule {
+    pub fn new(limit_func: TourSizeLimitFunc, code: i32) -> Self {
+        Self {
+            code,
+            limit_func: limit_func.clone(),
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(TourSizeHardRouteConstraint { code, limit_func }))],
+            keys: vec![TOUR_SIZE_KEY],
+        }
+    }
+
+    /// Removes tours which have less jobs than required.
+    fn remove_small_tours(&self, ctx: &mut SolutionContext) {
+        let locked = &ctx.locked;
+        let (small, routes): (Vec<_>, Vec<_>) = ctx.routes.drain(..).partition(|rc| {
+            let (_, jobs) = get_tour_size(&rc.route.tour);
+            let min_jobs = (self.limit_func)(rc.route.actor.as_ref()).min_jobs.unwrap_or(0);
+
+            jobs > 0 && jobs < min_jobs && rc.route.tour.jobs().all(|job| !locked.contains(&job))
+        });
+
+        ctx.routes = routes;
+
+        let is_insertion = !ctx.required.is_empty();
+
+        small.into_iter().for_each(|rc| {
+            ctx.registry.free_actor(&rc.route.actor);
+            rc.route.tour.jobs().for_each(|job| {
+                if is_conditional_job(&job) {
+                    ctx.ignored.push(job);
+                } else if is_insertion {
+                    ctx.required.push(job);
+                } else {
+                    ctx.unassigned.insert(job, self.code);
+                }
+            });
+        });
+    }
+}
+
+impl ConstraintModule for TourSizeModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let size = get_tour_size(&ctx.route.tour);
+        ctx.state_mut().put_route_state(TOUR_SIZE_KEY, size);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.remove_small_tours(ctx);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TourSizeHardRouteConstraint {
+    code: i32,
+    limit_func: TourSizeLimitFunc,
+}
+
+impl HardRouteConstraint for TourSizeHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if is_conditional_job(job) {
+            return None;
+        }
+
+        let limits = (self.limit_func)(ctx.route.actor.as_ref());
+        let (activities, jobs) = ctx
+            .state
+            .get_route_state::<(usize, usize)>(TOUR_SIZE_KEY)
+            .cloned()
+            .unwrap_or_else(|| get_tour_size(&ctx.route.tour));
+
+        let job_activities = job.as_multi().map_or(1, |multi| multi.jobs.len());
+
+        let is_violated = matches!(limits.max_activities, Some(max) if activities + job_activities > max)
+            || matches!(limits.max_jobs, Some(max) if jobs + 1 > max);
+
+        if is_violated {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns amount of job activities and jobs in the tour.
+fn get_tour_size(tour: &Tour) -> (usize, usize) {
+    let activities = tour
+        .all_activities()
+        .filter_map(|activity| activity.job.as_ref())
+        .filter(|single| get_vehicle_id_from_job(single).is_none())
+        .count();
+    let jobs = tour.jobs().filter(|job| !is_conditional_job(job)).count();
+
+    (activities, jobs)
+}
+
+/// Checks whether job is break or reload.
+fn is_conditional_job(job: &Job) -> bool {
+    matches!(job.as_single(), Some(single) if get_vehicle_id_from_job(single).is_some())
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+#[path = "../../tests/unit/extensions/lateness_test.rs"]
+mod lateness_test;
+
+use vrp_core::models::common::{Cost, Dimensions, Duration, TimeSpan, Timestamp, ValueDimension};
+use vrp_core::models::problem::Place;
+use vrp_core::models::solution::{Activity, Place as ActivityPlace};
+use vrp_core::utils::compare_floats;
+
+/// Specifies soft time windows of job place.
+#[derive(Clone, Debug)]
+pub struct PlaceLateness {
+    /// A maximum allowed lateness: place time windows are extended by this value.
+    pub max: Duration,
+    /// A cost per time unit of lateness.
+    pub cost: Cost,
+}
+
+const PLACES_LATENESS_KEY: &str = "places_lateness";
+
+/// Sets lateness of job places, one item per place.
+pub fn set_places_lateness(dimens: &mut Dimensions, lateness: Vec<Option<PlaceLateness>>) {
+    if lateness.iter().any(|lateness| lateness.is_some()) {
+        dimens.set_value(PLACES_LATENESS_KEY, lateness);
+    }
+}
+
+/// Returns lateness of activity which starts at given arrival time and its cost.
+/// Activity without soft time windows has no lateness.
+pub fn get_activity_lateness(activity: &Activity, arrival: Timestamp) -> Option<(Duration, Cost)> {
+    let single = activity.job.as_ref()?;
+    let lateness = single.dimens.get_value::<Vec<Option<PlaceLateness>>>(PLACES_LATENESS_KEY)?;
+
+    let lateness = single
+        .places
+        .iter()
+        .zip(lateness.iter())
+        .find(|(place, _)| is_same_place(place, &activity.place))
+        .and_then(|(_, lateness)| lateness.as_ref())?;
+
+    // NOTE time window end is already extended by max lateness
+    let end = activity.place.time.end - lateness.max;
+    let lateness_time = (arrival.max(activity.place.time.start) - end).max(0.);
+
+    Some((lateness_time, lateness_time * lateness.cost))
+}
+
+fn is_same_place(place: &Place, activity_place: &ActivityPlace) -> bool {
+    let is_same_time = |start: Timestamp, end: Timestamp| {
+        compare_floats(start, activity_place.time.start).is_eq() && compare_floats(end, activity_place.time.end).is_eq()
+    };
+
+    place.location.map_or(true, |location| location == activity_place.location)
+        && compare_floats(place.duration, activity_place.duration).is_eq()
+        && place.times.iter().any(|time| match time {
+            TimeSpan::Window(tw) => is_same_time(tw.start, tw.end),
+            TimeSpan::Offset(_) => false,
+        })
+}
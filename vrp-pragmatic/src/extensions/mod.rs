@@ -1,5 +1,8 @@
 //! Contains some algorithm extensions.
 
+mod lateness;
+pub use self::lateness::*;
+
 mod multi_dim_capacity;
 pub use self::multi_dim_capacity::MultiDimensionalCapacity;
 
@@ -1,28 +1,24 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::Actor;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::{Actor, ActorDetail};
 
-/// A actor group key implementation which creates groups using "type" dimension.
+/// A actor group key implementation which creates groups using "type" dimension and driver id.
 pub fn create_typed_actor_groups(actors: &[Arc<Actor>]) -> Box<dyn Fn(&Arc<Actor>) -> usize + Send + Sync> {
-    let unique_type_keys: HashSet<_> = actors
-        .iter()
-        .map(|a| (a.vehicle.dimens.get_value::<String>("type_id").cloned().unwrap(), a.detail.clone()))
-        .collect();
+    let unique_type_keys: HashSet<_> = actors.iter().map(|a| get_group_key(a)).collect();
 
     let type_key_map: HashMap<_, _> = unique_type_keys.into_iter().zip(0_usize..).collect();
 
-    let groups: HashMap<_, _> = actors
-        .iter()
-        .map(|a| {
-            (
-                a.clone(),
-                *type_key_map
-                    .get(&(a.vehicle.dimens.get_value::<String>("type_id").cloned().unwrap(), a.detail.clone()))
-                    .unwrap(),
-            )
-        })
-        .collect();
+    let groups: HashMap<_, _> =
+        actors.iter().map(|a| (a.clone(), *type_key_map.get(&get_group_key(a)).unwrap())).collect();
 
     Box::new(move |a| *groups.get(a).unwrap())
 }
+
+fn get_group_key(actor: &Arc<Actor>) -> (String, Option<String>, ActorDetail) {
+    (
+        actor.vehicle.dimens.get_value::<String>("type_id").cloned().unwrap(),
+        actor.driver.dimens.get_id().cloned(),
+        actor.detail.clone(),
+    )
+}
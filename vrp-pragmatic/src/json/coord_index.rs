@@ -6,15 +6,23 @@ use std::cmp::Ordering::Less;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
-/// A helper struct which keeps track of coordinate mapping.
+/// A helper struct which keeps track of coordinate mapping. Geocoordinates are indexed in order of their
+/// appearance, index references are mapped directly to routing matrix indices.
 pub struct CoordIndex {
     direct_index: HashMap<Location, usize>,
     reverse_index: HashMap<usize, Location>,
+    coordinates: usize,
+    has_references: bool,
 }
 
 impl CoordIndex {
     pub fn new(problem: &Problem) -> Self {
-        let mut index = Self { direct_index: Default::default(), reverse_index: Default::default() };
+        let mut index = Self {
+            direct_index: Default::default(),
+            reverse_index: Default::default(),
+            coordinates: 0,
+            has_references: false,
+        };
 
         // process plan
         problem.plan.jobs.iter().for_each(|job| {
@@ -32,10 +40,12 @@ impl CoordIndex {
         // process fleet
         problem.fleet.vehicles.iter().for_each(|vehicle| {
             vehicle.shifts.iter().for_each(|shift| {
-                index.add(&shift.start.location);
+                if let Some(location) = &shift.start.location {
+                    index.add(location);
+                }
 
-                if let Some(end) = &shift.end {
-                    index.add(&end.location);
+                if let Some(location) = shift.end.as_ref().and_then(|end| end.location.as_ref()) {
+                    index.add(location);
                 }
 
                 if let Some(breaks) = &shift.breaks {
@@ -57,7 +67,17 @@ impl CoordIndex {
 
     pub fn add(&mut self, location: &Location) {
         if self.direct_index.get(location).is_none() {
-            let value = self.direct_index.len();
+            let value = match location {
+                Location::Coordinate { .. } => {
+                    self.coordinates += 1;
+                    self.coordinates - 1
+                }
+                Location::Reference { index } => {
+                    self.has_references = true;
+                    *index
+                }
+            };
+
             self.direct_index.insert(location.clone(), value);
             self.reverse_index.insert(value, location.clone());
         }
@@ -71,6 +91,16 @@ impl CoordIndex {
         self.reverse_index.get(index).cloned()
     }
 
+    /// Returns whether geocoordinates and index references are used.
+    pub fn get_used_types(&self) -> (bool, bool) {
+        (self.coordinates > 0, self.has_references)
+    }
+
+    /// Returns max routing matrix index used by locations.
+    pub fn max_index(&self) -> Option<usize> {
+        self.direct_index.values().max().cloned()
+    }
+
     pub fn unique(&self) -> Vec<Location> {
         let mut sorted_pairs: Vec<_> = self.reverse_index.iter().collect();
         sorted_pairs.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Less));
@@ -82,14 +112,29 @@ impl Eq for Location {}
 
 impl PartialEq for Location {
     fn eq(&self, other: &Self) -> bool {
-        self.lat == other.lat && self.lng == other.lng
+        match (self, other) {
+            (Location::Coordinate { lat: l_lat, lng: l_lng }, Location::Coordinate { lat: r_lat, lng: r_lng }) => {
+                l_lat == r_lat && l_lng == r_lng
+            }
+            (Location::Reference { index: left }, Location::Reference { index: right }) => left == right,
+            _ => false,
+        }
     }
 }
 
 impl Hash for Location {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        write_hash(self.lat, state);
-        write_hash(self.lng, state);
+        match self {
+            Location::Coordinate { lat, lng } => {
+                state.write_u8(0);
+                write_hash(*lat, state);
+                write_hash(*lng, state);
+            }
+            Location::Reference { index } => {
+                state.write_u8(1);
+                state.write_usize(*index);
+            }
+        }
     }
 }
 
@@ -5,19 +5,49 @@
 extern crate serde_json;
 use serde::{Deserialize, Serialize};
 
-/// A location type represented by latitude and longitude.
+/// A location type represented by geocoordinate or by index in routing matrix.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct Location {
-    /// Latitude.
-    pub lat: f64,
-    /// Longitude.
-    pub lng: f64,
+#[serde(untagged)]
+pub enum Location {
+    /// A location represented by latitude and longitude.
+    Coordinate {
+        /// Latitude.
+        lat: f64,
+        /// Longitude.
+        lng: f64,
+    },
+    /// A location represented by index in routing matrix.
+    Reference {
+        /// An index in routing matrix.
+        index: usize,
+    },
 }
 
 impl Location {
-    /// Creates new `[Location]`.
+    /// Creates new `[Location]` from latitude and longitude.
     pub fn new(lat: f64, lng: f64) -> Self {
-        Self { lat, lng }
+        Self::Coordinate { lat, lng }
+    }
+
+    /// Creates new `[Location]` which refers to routing matrix index.
+    pub fn new_reference(index: usize) -> Self {
+        Self::Reference { index }
+    }
+
+    /// Checks whether location is a reference to routing matrix index.
+    pub fn is_reference(&self) -> bool {
+        match self {
+            Self::Coordinate { .. } => false,
+            Self::Reference { .. } => true,
+        }
+    }
+
+    /// Returns latitude and longitude or `None` if location is not a geocoordinate.
+    pub fn to_lat_lng(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::Coordinate { lat, lng } => Some((*lat, *lng)),
+            Self::Reference { .. } => None,
+        }
     }
 }
 
@@ -30,6 +60,10 @@ const SKILLS_CONSTRAINT_CODE: i32 = 6;
 const LOCKING_CONSTRAINT_CODE: i32 = 7;
 const REACHABLE_CONSTRAINT_CODE: i32 = 8;
 const PRIORITY_CONSTRAINT_CODE: i32 = 9;
+const RIDE_TIME_CONSTRAINT_CODE: i32 = 10;
+const TOUR_SIZE_CONSTRAINT_CODE: i32 = 11;
+const COMPATIBILITY_CONSTRAINT_CODE: i32 = 12;
+const GROUP_CONSTRAINT_CODE: i32 = 13;
 
 pub mod coord_index;
 
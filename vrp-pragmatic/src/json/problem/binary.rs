@@ -0,0 +1,146 @@
+//! Specifies compact binary encoding of routing matrix.
+//!
+//! All numbers are stored in little-endian byte order. The layout is:
+//! - magic bytes `VRPM` followed by format version (`u8`) and flags (`u8`)
+//! - profile name as length-prefixed (`u32`) utf8 string
+//! - optional timestamp (present when flags has `HAS_TIMESTAMP` bit) as length-prefixed string
+//! - amount of locations (`u64`): each collection has squared amount of elements
+//! - travel times, distances and, optionally (flags has `HAS_ERROR_CODES` bit), error codes as `i64` values
+
+#[cfg(test)]
+#[path = "../../../tests/unit/json/problem/binary_test.rs"]
+mod binary_test;
+
+use crate::json::problem::{FormatError, Matrix};
+use std::convert::TryFrom;
+use std::io::{BufRead, BufReader, BufWriter, Error, ErrorKind, Read, Write};
+
+const MAGIC: &[u8; 4] = b"VRPM";
+const VERSION: u8 = 1;
+const HAS_TIMESTAMP: u8 = 0b01;
+const HAS_ERROR_CODES: u8 = 0b10;
+
+/// Checks whether data available in reader starts with binary matrix header.
+pub fn is_binary_matrix<R: Read>(reader: &mut BufReader<R>) -> bool {
+    matches!(reader.fill_buf(), Ok(buffer) if buffer.starts_with(MAGIC))
+}
+
+/// Deserializes routing matrix in binary format from [`BufReader`].
+pub fn deserialize_binary_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<FormatError>> {
+    read_matrix(reader).map_err(|err| {
+        vec![FormatError::new(
+            "E0001".to_string(),
+            "cannot deserialize matrix".to_string(),
+            format!("check input binary matrix: '{}'", err),
+        )]
+    })
+}
+
+/// Serializes routing matrix in binary format to [`BufWriter`].
+pub fn serialize_binary_matrix<W: Write>(writer: BufWriter<W>, matrix: &Matrix) -> Result<(), Error> {
+    let mut writer = writer;
+
+    let size = matrix.travel_times.len();
+    if matrix.distances.len() != size || matrix.error_codes.iter().any(|codes| codes.len() != size) {
+        return Err(Error::new(ErrorKind::InvalidInput, "matrix collections have different length"));
+    }
+
+    let locations = (size as f64).sqrt().round() as usize;
+    if locations * locations != size {
+        return Err(Error::new(ErrorKind::InvalidInput, "matrix is not square"));
+    }
+
+    let flags = if matrix.timestamp.is_some() { HAS_TIMESTAMP } else { 0 }
+        | if matrix.error_codes.is_some() { HAS_ERROR_CODES } else { 0 };
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION, flags])?;
+
+    write_string(&mut writer, matrix.profile.as_str())?;
+    if let Some(timestamp) = &matrix.timestamp {
+        write_string(&mut writer, timestamp.as_str())?;
+    }
+
+    writer.write_all(&(locations as u64).to_le_bytes())?;
+    write_values(&mut writer, &matrix.travel_times)?;
+    write_values(&mut writer, &matrix.distances)?;
+    if let Some(error_codes) = &matrix.error_codes {
+        write_values(&mut writer, error_codes)?;
+    }
+
+    writer.flush()
+}
+
+fn read_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Error> {
+    let mut reader = reader;
+
+    let mut header = [0_u8; 6];
+    reader.read_exact(&mut header)?;
+
+    if &header[0..4] != MAGIC {
+        return Err(Error::new(ErrorKind::InvalidData, "unknown header"));
+    }
+
+    if header[4] != VERSION {
+        return Err(Error::new(ErrorKind::InvalidData, format!("unsupported version: {}", header[4])));
+    }
+
+    let flags = header[5];
+
+    let profile = read_string(&mut reader)?;
+    let timestamp = if flags & HAS_TIMESTAMP != 0 { Some(read_string(&mut reader)?) } else { None };
+
+    let locations = read_u64(&mut reader)?;
+    let size = locations
+        .checked_mul(locations)
+        .and_then(|size| usize::try_from(size).ok())
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("too many locations: {}", locations)))?;
+    let travel_times = read_values(&mut reader, size)?;
+    let distances = read_values(&mut reader, size)?;
+    let error_codes = if flags & HAS_ERROR_CODES != 0 { Some(read_values(&mut reader, size)?) } else { None };
+
+    Ok(Matrix { profile, timestamp, travel_times, distances, error_codes })
+}
+
+fn write_string<W: Write>(writer: &mut W, value: &str) -> Result<(), Error> {
+    writer.write_all(&(value.len() as u32).to_le_bytes())?;
+    writer.write_all(value.as_bytes())
+}
+
+fn write_values<W: Write>(writer: &mut W, values: &[i64]) -> Result<(), Error> {
+    values.iter().try_for_each(|value| writer.write_all(&value.to_le_bytes()))
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<String, Error> {
+    let mut length = [0_u8; 4];
+    reader.read_exact(&mut length)?;
+
+    // NOTE length comes from untrusted input, so buffer is not allocated upfront
+    let length = u64::from(u32::from_le_bytes(length));
+    let mut buffer = vec![];
+    reader.by_ref().take(length).read_to_end(&mut buffer)?;
+
+    if buffer.len() as u64 != length {
+        return Err(Error::new(ErrorKind::UnexpectedEof, "string is truncated"));
+    }
+
+    String::from_utf8(buffer).map_err(|err| Error::new(ErrorKind::InvalidData, err))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64, Error> {
+    let mut buffer = [0_u8; 8];
+    reader.read_exact(&mut buffer)?;
+
+    Ok(u64::from_le_bytes(buffer))
+}
+
+fn read_values<R: Read>(reader: &mut R, size: usize) -> Result<Vec<i64>, Error> {
+    (0..size)
+        .map(|_| {
+            let mut buffer = [0_u8; 8];
+            reader.read_exact(&mut buffer)?;
+
+            Ok(i64::from_le_bytes(buffer))
+        })
+        .collect()
+}
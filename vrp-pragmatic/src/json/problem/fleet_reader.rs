@@ -1,3 +1,4 @@
+use crate::constraints::{TourSizeLimitFunc, TourSizeLimits};
 use crate::extensions::{create_typed_actor_groups, MultiDimensionalCapacity};
 use crate::json::coord_index::CoordIndex;
 use crate::json::problem::reader::{add_skills, ApiProblem, ProblemProperties};
@@ -62,28 +63,33 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
             fixed: vehicle.costs.fixed.unwrap_or(0.),
             per_distance: vehicle.costs.distance,
             per_driving_time: vehicle.costs.time,
-            per_waiting_time: vehicle.costs.time,
-            per_service_time: vehicle.costs.time,
+            per_waiting_time: vehicle.costs.waiting.unwrap_or(vehicle.costs.time),
+            per_service_time: vehicle.costs.service.unwrap_or(vehicle.costs.time),
+            overtime: vehicle
+                .costs
+                .overtime
+                .as_ref()
+                .map(|overtime| OvertimeCosts { threshold: overtime.threshold, per_time: overtime.time }),
         };
 
         let profile = *profiles.get(&vehicle.profile).unwrap() as Profile;
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
-                let location = coord_index.get_by_loc(&shift.start.location).unwrap();
+                let location = shift.start.location.as_ref().map(|location| coord_index.get_by_loc(location).unwrap());
                 let time = parse_time(&shift.start.time);
                 (location, time)
             };
 
             let end = shift.end.as_ref().map_or(None, |end| {
-                let location = coord_index.get_by_loc(&end.location).unwrap();
+                let location = end.location.as_ref().map(|location| coord_index.get_by_loc(location).unwrap());
                 let time = parse_time(&end.time);
                 Some((location, time))
             });
 
             let details = vec![VehicleDetail {
-                start: Some(start.0),
-                end: end.map_or(None, |end| Some(end.0)),
+                start: start.0,
+                end: end.map_or(None, |end| end.0),
                 time: Some(TimeWindow::new(start.1, end.map_or(std::f64::MAX, |end| end.1))),
             }];
 
@@ -105,21 +111,61 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
         }
     });
 
-    let drivers = vec![Arc::new(Driver {
-        costs: Costs {
-            fixed: 0.0,
-            per_distance: 0.0,
-            per_driving_time: 0.0,
-            per_waiting_time: 0.0,
-            per_service_time: 0.0,
-        },
-        dimens: Default::default(),
-        details: vec![],
-    })];
+    let drivers = read_drivers(api_problem);
 
     Fleet::new(drivers, vehicles, Box::new(|actors| create_typed_actor_groups(actors)))
 }
 
+fn read_drivers(api_problem: &ApiProblem) -> Vec<Arc<Driver>> {
+    match api_problem.fleet.drivers.as_ref() {
+        Some(drivers) if !drivers.is_empty() => drivers
+            .iter()
+            .map(|driver| {
+                let mut dimens: Dimensions = Default::default();
+                dimens.set_id(&driver.id);
+                add_skills(&mut dimens, &driver.skills);
+
+                let details = if driver.shifts.is_empty() {
+                    vec![DriverDetail { time: None }]
+                } else {
+                    driver
+                        .shifts
+                        .iter()
+                        .map(|shift| DriverDetail {
+                            time: Some(TimeWindow::new(parse_time(&shift.time[0]), parse_time(&shift.time[1]))),
+                        })
+                        .collect()
+                };
+
+                Arc::new(Driver {
+                    costs: Costs {
+                        fixed: driver.costs.fixed.unwrap_or(0.),
+                        per_distance: driver.costs.distance,
+                        per_driving_time: driver.costs.time,
+                        per_waiting_time: driver.costs.time,
+                        per_service_time: driver.costs.time,
+                        overtime: None,
+                    },
+                    dimens,
+                    details,
+                })
+            })
+            .collect(),
+        _ => vec![Arc::new(Driver {
+            costs: Costs {
+                fixed: 0.0,
+                per_distance: 0.0,
+                per_driving_time: 0.0,
+                per_waiting_time: 0.0,
+                per_service_time: 0.0,
+                overtime: None,
+            },
+            dimens: Default::default(),
+            details: vec![],
+        })],
+    }
+}
+
 pub fn read_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     let limits = api_problem.fleet.vehicles.iter().filter(|vehicle| vehicle.limits.is_some()).fold(
         HashMap::new(),
@@ -143,6 +189,34 @@ pub fn read_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
+pub fn read_tour_size_limits(api_problem: &ApiProblem) -> Option<TourSizeLimitFunc> {
+    let limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.limits.as_ref().map(|limits| (vehicle, limits)))
+        .filter(|(_, limits)| limits.max_activities.is_some() || limits.max_jobs.is_some() || limits.min_jobs.is_some())
+        .fold(HashMap::new(), |mut acc, (vehicle, limits)| {
+            acc.insert(
+                vehicle.type_id.clone(),
+                TourSizeLimits {
+                    max_activities: limits.max_activities,
+                    max_jobs: limits.max_jobs,
+                    min_jobs: limits.min_jobs,
+                },
+            );
+            acc
+        });
+
+    if limits.is_empty() {
+        None
+    } else {
+        Some(Arc::new(move |actor: &Actor| {
+            limits.get(actor.vehicle.dimens.get_value::<String>("type_id").unwrap()).cloned().unwrap_or_default()
+        }))
+    }
+}
+
 fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name) == None {
@@ -1,14 +1,18 @@
-use crate::extensions::MultiDimensionalCapacity;
+use crate::constraints::JobSkills;
+use crate::extensions::{set_places_lateness, MultiDimensionalCapacity, PlaceLateness};
 use crate::json::coord_index::CoordIndex;
-use crate::json::problem::reader::{add_skills, parse_time_window, ApiProblem, JobIndex, ProblemProperties};
-use crate::json::problem::{JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleReload, VehicleType};
+use crate::json::problem::reader::{parse_time_window, ApiProblem, JobIndex, ProblemProperties};
+use crate::json::problem::{
+    Job as ApiJob, JobPlace, JobSkills as ApiJobSkills, JobTask, RelationType, VehicleBreak, VehicleBreakTime,
+    VehicleReload, VehicleType,
+};
 use crate::json::Location;
 use crate::utils::VariableJobPermutation;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use vrp_core::construction::constraints::{Demand, DemandDimension};
+use vrp_core::construction::constraints::{Demand, DemandDimension, GroupDimension};
 use vrp_core::models::common::{Dimensions, Duration, IdDimension, TimeOffset, TimeSpan, TimeWindow, ValueDimension};
-use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
+use vrp_core::models::problem::{Actor, Fleet, Job, JobIndexOptions, Jobs, Multi, Place, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
 
 // TODO configure sample size
@@ -28,7 +32,7 @@ pub fn read_jobs_with_extra_locks(
     jobs.extend(conditional_jobs);
     locks.extend(conditional_locks);
 
-    (Jobs::new(fleet, jobs, transport), locks)
+    (Jobs::new_with_options(fleet, jobs, transport, get_job_index_options(api_problem)), locks)
 }
 
 pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
@@ -116,10 +120,19 @@ fn read_required_jobs(
             _ => panic!("Invalid activity type."),
         };
 
-        let places =
-            task.places.iter().map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times))).collect();
+        let places = task.places.iter().map(|p| (Some(p.location.clone()), p.duration, parse_place_times(p))).collect();
 
-        get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index);
+        set_places_lateness(
+            &mut single.dimens,
+            task.places
+                .iter()
+                .map(|p| p.lateness.as_ref().map(|lateness| PlaceLateness { max: lateness.max, cost: lateness.cost }))
+                .collect(),
+        );
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -152,9 +165,9 @@ fn read_required_jobs(
         assert!(singles.len() > 0);
 
         let problem_job = if singles.len() > 1 {
-            get_multi_job(&job.id, &job.priority, &job.skills, singles, job.pickups.as_ref().map_or(0, |p| p.len()))
+            get_multi_job(job, singles, job.pickups.as_ref().map_or(0, |p| p.len()))
         } else {
-            get_single_job(&job.id, singles.into_iter().next().unwrap(), &job.priority, &job.skills)
+            get_single_job(job, singles.into_iter().next().unwrap())
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -332,27 +345,29 @@ fn get_single_with_extras(
     single
 }
 
-fn get_single_job(id: &String, single: Single, priority: &Option<i32>, skills: &Option<Vec<String>>) -> Job {
+fn get_single_job(job: &ApiJob, single: Single) -> Job {
     let mut single = single;
-    single.dimens.set_id(id.as_str());
+    single.dimens.set_id(job.id.as_str());
 
-    add_priority(&mut single.dimens, priority);
-    add_skills(&mut single.dimens, skills);
+    add_priority(&mut single.dimens, &job.priority);
+    add_job_skills(&mut single.dimens, &job.skills);
+    add_compatibility(&mut single.dimens, &job.compatibility);
+    add_group(&mut single.dimens, &job.group);
 
     Job::Single(Arc::new(single))
 }
 
-fn get_multi_job(
-    id: &String,
-    priority: &Option<i32>,
-    skills: &Option<Vec<String>>,
-    singles: Vec<Single>,
-    deliveries_start_index: usize,
-) -> Job {
+fn get_multi_job(job: &ApiJob, singles: Vec<Single>, deliveries_start_index: usize) -> Job {
     let mut dimens: Dimensions = Default::default();
-    dimens.set_id(id.as_str());
-    add_priority(&mut dimens, priority);
-    add_skills(&mut dimens, skills);
+    dimens.set_id(job.id.as_str());
+    add_priority(&mut dimens, &job.priority);
+    add_job_skills(&mut dimens, &job.skills);
+    add_compatibility(&mut dimens, &job.compatibility);
+    add_group(&mut dimens, &job.group);
+
+    if let Some(max_ride_time) = job.max_ride_time {
+        dimens.set_value("max_ride_time", max_ride_time);
+    }
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -389,12 +404,64 @@ fn add_priority(dimens: &mut Dimensions, priority: &Option<i32>) {
     }
 }
 
+fn add_job_skills(dimens: &mut Dimensions, skills: &Option<ApiJobSkills>) {
+    let to_set = |skills: &Option<Vec<String>>| skills.as_ref().map(|skills| skills.iter().cloned().collect());
+
+    let skills = match skills {
+        Some(ApiJobSkills::List(all_of)) => {
+            JobSkills { all_of: Some(all_of.iter().cloned().collect::<HashSet<_>>()), ..JobSkills::default() }
+        }
+        Some(ApiJobSkills::Sets(sets)) => {
+            JobSkills { all_of: to_set(&sets.all_of), one_of: to_set(&sets.one_of), none_of: to_set(&sets.none_of) }
+        }
+        None => return,
+    };
+
+    dimens.set_value("skills", skills);
+}
+
+fn add_compatibility(dimens: &mut Dimensions, compatibility: &Option<String>) {
+    if let Some(compatibility) = compatibility {
+        dimens.set_value("compatibility", compatibility.clone());
+    }
+}
+
+fn add_group(dimens: &mut Dimensions, group: &Option<String>) {
+    if let Some(group) = group {
+        dimens.set_group(group);
+    }
+}
+
 fn empty() -> MultiDimensionalCapacity {
     MultiDimensionalCapacity::default()
 }
 
+/// Parses place time windows extending them by max lateness, if soft time windows are used.
+fn parse_place_times(place: &JobPlace) -> Vec<TimeSpan> {
+    let max_lateness = place.lateness.as_ref().map_or(0., |lateness| lateness.max);
+
+    parse_times(&place.times)
+        .into_iter()
+        .map(|time| match time {
+            TimeSpan::Window(tw) => TimeSpan::Window(TimeWindow::new(tw.start, tw.end + max_lateness)),
+            TimeSpan::Offset(_) => time,
+        })
+        .collect()
+}
+
 fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
     times.as_ref().map_or(vec![TimeSpan::Window(TimeWindow::max())], |tws| {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
     })
 }
+
+fn get_job_index_options(api_problem: &ApiProblem) -> JobIndexOptions {
+    let default = JobIndexOptions::default();
+
+    api_problem.config.as_ref().and_then(|config| config.neighbourhood.as_ref()).map_or(default.clone(), |config| {
+        JobIndexOptions {
+            max_neighbours: config.max_neighbours.or(default.max_neighbours),
+            is_lazy: config.is_lazy.unwrap_or(default.is_lazy),
+        }
+    })
+}
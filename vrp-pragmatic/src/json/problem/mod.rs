@@ -4,6 +4,9 @@
 mod model;
 pub use self::model::*;
 
+mod binary;
+pub use self::binary::*;
+
 mod reader;
 pub use self::reader::FormatError;
 pub use self::reader::PragmaticProblem;
@@ -4,12 +4,13 @@ mod deserializer_test;
 
 extern crate serde_json;
 
-use crate::json::problem::FormatError;
+use crate::json::problem::{deserialize_binary_matrix, is_binary_matrix, FormatError};
 use crate::json::Location;
 use serde::{Deserialize, Serialize};
 use serde_json::Error;
 use std::io::{BufReader, Read};
 use std::io::{BufWriter, Write};
+use vrp_solver::config::Config as SolverConfig;
 
 // region Plan
 
@@ -41,6 +42,15 @@ pub struct Relation {
     pub shift_index: Option<usize>,
 }
 
+/// Specifies soft time windows of job place: arrival after time window end is allowed, but penalized.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPlaceLateness {
+    /// Maximum allowed lateness in seconds.
+    pub max: f64,
+    /// A cost per second of lateness.
+    pub cost: f64,
+}
+
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct JobPlace {
@@ -51,6 +61,9 @@ pub struct JobPlace {
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
+    /// Makes time windows soft allowing late arrival. If omitted, time windows are hard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<JobPlaceLateness>,
 }
 
 /// Specifies a job task.
@@ -66,11 +79,37 @@ pub struct JobTask {
     pub tag: Option<String>,
 }
 
+/// Specifies job skills as set operations over vehicle and driver skills.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSkillSets {
+    /// A list of skills which all have to be present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_of: Option<Vec<String>>,
+    /// A list of skills from which at least one has to be present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_of: Option<Vec<String>>,
+    /// A list of skills which none has to be present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub none_of: Option<Vec<String>>,
+}
+
+/// Job skills variant.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(untagged)]
+pub enum JobSkills {
+    /// A list of skills which all have to be present.
+    List(Vec<String>),
+    /// Skills defined by set operations.
+    Sets(JobSkillSets),
+}
+
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
 /// which follows these rules:
 /// * all of them should be completed or none of them.
 /// * all pickups must be completed before any of deliveries.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Job {
     /// A job id.
     pub id: String,
@@ -95,9 +134,22 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
 
-    /// A set of skills required to serve a job.
+    /// Skills required to serve a job.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub skills: Option<Vec<String>>,
+    pub skills: Option<JobSkills>,
+
+    /// A max time in seconds between departure from pickup and arrival to delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ride_time: Option<f64>,
+
+    /// A compatibility tag: jobs with different tags cannot be served in the same tour
+    /// or, if vehicle has reloads, between the same reloads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility: Option<String>,
+
+    /// A job group: jobs of the same group are served in the same tour or not served at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
 }
 
 /// A plan specifies work which has to be done.
@@ -114,6 +166,15 @@ pub struct Plan {
 
 // region Fleet
 
+/// Specifies vehicle overtime costs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleOvertimeCosts {
+    /// A tour duration in seconds after which overtime starts.
+    pub threshold: f64,
+    /// An extra cost per time unit of overtime.
+    pub time: f64,
+}
+
 /// Specifies vehicle costs.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct VehicleCosts {
@@ -124,6 +185,15 @@ pub struct VehicleCosts {
     pub distance: f64,
     /// Cost per time unit.
     pub time: f64,
+    /// Cost per waiting time unit. Default is time cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<f64>,
+    /// Cost per service time unit. Default is time cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<f64>,
+    /// Overtime costs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<VehicleOvertimeCosts>,
 }
 
 /// Specifies vehicle place.
@@ -131,8 +201,9 @@ pub struct VehicleCosts {
 pub struct VehiclePlace {
     /// Vehicle start or end time.
     pub time: String,
-    /// Vehicle location.
-    pub location: Location,
+    /// Vehicle location. When omitted, vehicle starts at the first job or ends at the last one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
 }
 
 /// Specifies vehicle shift.
@@ -184,6 +255,18 @@ pub struct VehicleLimits {
     /// Max time per shift/tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shift_time: Option<f64>,
+
+    /// Max amount of job activities per shift/tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_activities: Option<usize>,
+
+    /// Max amount of jobs per shift/tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jobs: Option<usize>,
+
+    /// Min amount of jobs per shift/tour, if vehicle is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_jobs: Option<usize>,
 }
 
 /// Vehicle break time variant.
@@ -241,6 +324,54 @@ pub struct VehicleType {
     pub limits: Option<VehicleLimits>,
 }
 
+/// Specifies driver costs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverCosts {
+    /// Fixed is cost of driver usage per tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<f64>,
+    /// Cost per distance unit.
+    pub distance: f64,
+    /// Cost per time unit.
+    pub time: f64,
+}
+
+/// Specifies driver shift.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverShift {
+    /// Driver working time window with time specified in RFC3339 format.
+    pub time: Vec<String>,
+}
+
+/// Specifies a driver.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Driver {
+    /// Driver id.
+    pub id: String,
+
+    /// Driver costs.
+    pub costs: DriverCosts,
+
+    /// Driver shifts. If empty, driver has no working time restrictions.
+    pub shifts: Vec<DriverShift>,
+
+    /// Driver skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+}
+
+/// Specifies a metric used to approximate distances when no routing matrix is specified.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DistanceMetric {
+    /// A great-circle distance.
+    Haversine,
+    /// A sum of absolute differences of projected coordinates.
+    Manhattan,
+    /// A straight line distance between projected coordinates.
+    Euclidean,
+}
+
 /// Specifies routing profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Profile {
@@ -249,6 +380,15 @@ pub struct Profile {
     /// Profile type.
     #[serde(rename(deserialize = "type", serialize = "type"))]
     pub profile_type: String,
+    /// An average speed in meters per second used by approximated routing. Default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+    /// A ratio of actual road distance to the one given by metric used by approximated routing. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuity: Option<f64>,
+    /// A distance metric used by approximated routing. Default is haversine.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<DistanceMetric>,
 }
 
 /// Specifies fleet.
@@ -258,15 +398,38 @@ pub struct Fleet {
     pub vehicles: Vec<VehicleType>,
     /// Routing profiles.
     pub profiles: Vec<Profile>,
+    /// Drivers. If specified, each tour is assigned to one of the drivers which can drive only one
+    /// vehicle at a time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<Vec<Driver>>,
 }
 
 // endregion
 
 // region Configuration
 
-/// Specifies extra configuration (reserved for future).
+/// Specifies extra configuration.
 #[derive(Clone, Deserialize, Debug, Serialize)]
-pub struct Config {}
+pub struct Config {
+    /// Job neighbourhood settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neighbourhood: Option<NeighbourhoodConfig>,
+    /// Solver configuration: population, mutation, acceptance and termination settings.
+    #[serde(flatten)]
+    pub solver: SolverConfig,
+}
+
+/// Specifies job neighbourhood settings: unspecified parameters have default values.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NeighbourhoodConfig {
+    /// Max amount of nearest neighbours kept per job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_neighbours: Option<usize>,
+    /// If true, neighbours of a job are calculated on first access.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_lazy: Option<bool>,
+}
 
 // endregion
 
@@ -428,6 +591,7 @@ pub struct Matrix {
     pub profile: String,
 
     /// A date in RFC3999 for which routing info is applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<String>,
 
     /// Travel distances (used to be in seconds).
@@ -454,8 +618,13 @@ pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Vec
     })
 }
 
-/// Deserializes routing matrix in json format from [`BufReader`].
+/// Deserializes routing matrix in json or binary format from [`BufReader`].
 pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<FormatError>> {
+    let mut reader = reader;
+    if is_binary_matrix(&mut reader) {
+        return deserialize_binary_matrix(reader);
+    }
+
     serde_json::from_reader(reader).map_err(|err| {
         vec![FormatError::new(
             "E0001".to_string(),
@@ -469,3 +638,8 @@ pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<F
 pub fn serialize_problem<W: Write>(writer: BufWriter<W>, problem: &Problem) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, problem)
 }
+
+/// Serializes [`matrix`] in json into [`writer`].
+pub fn serialize_matrix<W: Write>(writer: BufWriter<W>, matrix: &Matrix) -> Result<(), Error> {
+    serde_json::to_writer_pretty(writer, matrix)
+}
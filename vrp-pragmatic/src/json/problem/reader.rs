@@ -11,15 +11,15 @@ mod fleet_reader;
 #[path = "./objective_reader.rs"]
 mod objective_reader;
 
-use self::fleet_reader::{create_transport_costs, read_fleet, read_limits};
+use self::fleet_reader::{create_transport_costs, read_fleet, read_limits, read_tour_size_limits};
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
 use self::objective_reader::create_objective;
 use crate::constraints::*;
 use crate::extensions::{MultiDimensionalCapacity, OnlyVehicleActivityCost};
 use crate::json::coord_index::CoordIndex;
-use crate::json::problem::{deserialize_matrix, deserialize_problem, Matrix};
+use crate::json::problem::{deserialize_matrix, deserialize_problem, DistanceMetric as ApiDistanceMetric, Matrix};
 use crate::json::*;
-use crate::utils::get_approx_transportation;
+use crate::utils::get_approx_transportation_with_routing;
 use crate::validation::ValidationContext;
 use crate::{get_locations, parse_time};
 use std::collections::{HashMap, HashSet};
@@ -29,12 +29,15 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::models::common::{Dimensions, TimeWindow, ValueDimension};
-use vrp_core::models::problem::{ActivityCost, Fleet, Job, TransportCost};
+use vrp_core::models::problem::{ActivityCost, ApproxRouting, DistanceMetric, Fleet, Job, TransportCost};
 use vrp_core::models::{Extras, Lock, Problem};
 
 pub type ApiProblem = crate::json::problem::Problem;
 pub type JobIndex = HashMap<String, Job>;
 
+const DEFAULT_APPROX_SPEED: f64 = 10.;
+const DEFAULT_APPROX_CIRCUITY: f64 = 1.;
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>>;
@@ -101,6 +104,10 @@ pub struct ProblemProperties {
     has_unreachable_locations: bool,
     has_reload: bool,
     has_priorities: bool,
+    has_max_ride_time: bool,
+    has_compatibility: bool,
+    has_groups: bool,
+    has_lateness: bool,
 }
 
 /// A format error.
@@ -135,28 +142,51 @@ impl std::fmt::Display for FormatError {
 }
 
 fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
-    let locations = get_locations(&problem);
-    let (durations, distances) = get_approx_transportation(&locations, 10.);
+    ValidationContext::new(&problem, None).validate()?;
 
-    let durations = durations.into_iter().map(|d| d.round() as i64).collect::<Vec<_>>();
-    let distances = distances.into_iter().map(|d| d.round() as i64).collect::<Vec<_>>();
+    let locations = get_locations(&problem);
 
     let matrices = problem
         .fleet
         .profiles
         .iter()
-        .map(move |profile| Matrix {
-            profile: profile.name.clone(),
-            timestamp: None,
-            travel_times: durations.clone(),
-            distances: distances.clone(),
-            error_codes: None,
+        .map(|profile| {
+            let (durations, distances) =
+                get_approx_transportation_with_routing(&locations, &get_approx_routing(profile)).map_err(|err| {
+                    vec![FormatError::new(
+                        "E0002".to_string(),
+                        "cannot create transport costs".to_string(),
+                        format!("Check matrix routing data: '{}'", err),
+                    )]
+                })?;
+
+            Ok(Matrix {
+                profile: profile.name.clone(),
+                timestamp: None,
+                travel_times: durations.into_iter().map(|d| d.round() as i64).collect(),
+                distances: distances.into_iter().map(|d| d.round() as i64).collect(),
+                error_codes: None,
+            })
         })
-        .collect();
+        .collect::<Result<Vec<_>, Vec<FormatError>>>()?;
 
     map_to_problem(problem, matrices)
 }
 
+fn get_approx_routing(profile: &crate::json::problem::Profile) -> ApproxRouting {
+    let metric = match profile.metric {
+        Some(ApiDistanceMetric::Manhattan) => DistanceMetric::Manhattan,
+        Some(ApiDistanceMetric::Euclidean) => DistanceMetric::Euclidean,
+        Some(ApiDistanceMetric::Haversine) | None => DistanceMetric::Haversine,
+    };
+
+    ApproxRouting::new(
+        profile.speed.unwrap_or(DEFAULT_APPROX_SPEED),
+        profile.circuity.unwrap_or(DEFAULT_APPROX_CIRCUITY),
+        metric,
+    )
+}
+
 fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
     ValidationContext::new(&api_problem, Some(&matrices)).validate()?;
 
@@ -178,9 +208,17 @@ fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Prob
         read_jobs_with_extra_locks(&api_problem, &problem_props, &coord_index, &fleet, &transport, &mut job_index);
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect();
     let limits = read_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
-    let extras = Arc::new(create_extras(&problem_props, coord_index));
-    let mut constraint =
-        create_constraint_pipeline(&fleet, activity.clone(), transport.clone(), &problem_props, &locks, limits);
+    let tour_size_limits = read_tour_size_limits(&api_problem);
+    let extras = Arc::new(create_extras(&api_problem, &problem_props, coord_index));
+    let mut constraint = create_constraint_pipeline(
+        &fleet,
+        activity.clone(),
+        transport.clone(),
+        &problem_props,
+        &locks,
+        limits,
+        tour_size_limits,
+    );
 
     let objective = Arc::new(create_objective(&api_problem, &mut constraint, &problem_props));
 
@@ -203,6 +241,7 @@ fn create_constraint_pipeline(
     props: &ProblemProperties,
     locks: &Vec<Arc<Lock>>,
     limits: TravelLimitFunc,
+    tour_size_limits: Option<TourSizeLimitFunc>,
 ) -> ConstraintPipeline {
     let mut constraint = ConstraintPipeline::default();
     constraint.add_module(Box::new(TransportConstraintModule::new(
@@ -236,6 +275,26 @@ fn create_constraint_pipeline(
         constraint.add_module(Box::new(ReachableModule::new(transport.clone(), REACHABLE_CONSTRAINT_CODE)));
     }
 
+    if props.has_max_ride_time {
+        constraint.add_module(Box::new(RideTimeModule::new(transport.clone(), RIDE_TIME_CONSTRAINT_CODE)));
+    }
+
+    if props.has_compatibility {
+        constraint.add_module(Box::new(CompatibilityModule::new(COMPATIBILITY_CONSTRAINT_CODE)));
+    }
+
+    if props.has_groups {
+        constraint.add_module(Box::new(GroupModule::new(GROUP_CONSTRAINT_CODE)));
+    }
+
+    if props.has_lateness {
+        constraint.add_module(Box::new(LatenessModule::new(activity.clone(), transport.clone())));
+    }
+
+    if let Some(tour_size_limits) = tour_size_limits {
+        constraint.add_module(Box::new(TourSizeModule::new(tour_size_limits, TOUR_SIZE_CONSTRAINT_CODE)));
+    }
+
     constraint
 }
 
@@ -262,13 +321,17 @@ fn add_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemPrope
     });
 }
 
-fn create_extras(props: &ProblemProperties, coord_index: CoordIndex) -> Extras {
+fn create_extras(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: CoordIndex) -> Extras {
     let mut extras = Extras::default();
     extras.insert(
         "capacity_type".to_string(),
-        Box::new((if props.has_multi_dimen_capacity { "multi" } else { "single" }).to_string()),
+        Arc::new((if props.has_multi_dimen_capacity { "multi" } else { "single" }).to_string()),
     );
-    extras.insert("coord_index".to_owned(), Box::new(coord_index));
+    extras.insert("coord_index".to_owned(), Arc::new(coord_index));
+
+    if let Some(config) = &api_problem.config {
+        extras.insert("solver_config".to_owned(), Arc::new(config.solver.clone()));
+    }
 
     extras
 }
@@ -303,6 +366,23 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> P
 
     let has_priorities = api_problem.plan.jobs.iter().filter_map(|job| job.priority).any(|priority| priority > 1);
 
+    let has_max_ride_time = api_problem.plan.jobs.iter().any(|job| job.max_ride_time.is_some());
+
+    let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
+
+    let has_groups = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
+
+    let has_lateness = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups.iter().chain(job.deliveries.iter()).chain(job.replacements.iter()).chain(job.services.iter())
+        })
+        .flat_map(|tasks| tasks.iter())
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.lateness.is_some());
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -310,6 +390,10 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> P
         has_unreachable_locations,
         has_reload,
         has_priorities,
+        has_max_ride_time,
+        has_compatibility,
+        has_groups,
+        has_lateness,
     }
 }
 
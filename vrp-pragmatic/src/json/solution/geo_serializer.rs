@@ -1,5 +1,6 @@
 use super::Solution;
 use crate::json::solution::{Stop, Tour};
+use serde::ser::Error as SerError;
 use serde::Serialize;
 use serde_json::Error;
 use std::collections::HashMap;
@@ -45,8 +46,17 @@ fn get_marker_symbol(stop: &Stop) -> String {
     .to_string()
 }
 
-fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) -> Feature {
-    Feature {
+fn get_coordinates(stop: &Stop) -> Result<(f64, f64), Error> {
+    let (lat, lng) = stop
+        .location
+        .to_lat_lng()
+        .ok_or_else(|| Error::custom("geojson can be created only when locations are specified by geocoordinates"))?;
+
+    Ok((lng, lat))
+}
+
+fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) -> Result<Feature, Error> {
+    Ok(Feature {
         properties: slice_to_map(&[
             ("marker-color", color),
             ("marker-size", "medium"),
@@ -55,12 +65,12 @@ fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) ->
             ("stop_idx", stop_idx.to_string().as_str()),
             ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
         ]),
-        geometry: Geometry::Point { coordinates: (stop.location.lng, stop.location.lat) },
-    }
+        geometry: Geometry::Point { coordinates: get_coordinates(stop)? },
+    })
 }
 
-fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Feature {
-    Feature {
+fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Result<Feature, Error> {
+    Ok(Feature {
         properties: slice_to_map(&[
             ("vehicle_id", tour.vehicle_id.as_str()),
             ("tour_idx", tour_idx.to_string().as_str()),
@@ -71,9 +81,9 @@ fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Feature {
             ("stroke", color),
         ]),
         geometry: Geometry::LineString {
-            coordinates: tour.stops.iter().map(|stop| (stop.location.lng, stop.location.lat)).collect(),
+            coordinates: tour.stops.iter().map(get_coordinates).collect::<Result<_, _>>()?,
         },
-    }
+    })
 }
 
 /// Serializes solution into geo json format.
@@ -90,10 +100,9 @@ pub fn serialize_solution_as_geojson<W: Write>(writer: BufWriter<W>, solution: &
         .enumerate()
         .map(|(tour_idx, tour)| get_tour_line(tour_idx, tour, get_color(tour_idx).as_str()));
 
-    serde_json::to_writer_pretty(
-        writer,
-        &FeatureCollection { features: stop_markers.into_iter().chain(stop_lines.into_iter()).collect() },
-    )
+    let features = stop_markers.chain(stop_lines).collect::<Result<Vec<_>, _>>()?;
+
+    serde_json::to_writer_pretty(writer, &FeatureCollection { features })
 }
 
 fn get_color(idx: usize) -> String {
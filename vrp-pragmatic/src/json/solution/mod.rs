@@ -1,4 +1,4 @@
-//! Specifies logic to create a "pragmatic" solution and write it into json format.
+//! Specifies logic to create a "pragmatic" solution, write it into json format or read it back.
 
 mod model;
 pub use self::model::*;
@@ -8,6 +8,9 @@ pub use self::geo_serializer::serialize_solution_as_geojson;
 
 mod extensions;
 
+mod reader;
+pub use self::reader::read_init_solution;
+
 mod writer;
 pub use self::writer::create_solution;
 pub use self::writer::PragmaticSolution;
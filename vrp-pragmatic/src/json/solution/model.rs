@@ -1,10 +1,10 @@
 use crate::json::Location;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Error;
-use std::io::{BufWriter, Write};
+use std::io::{BufReader, BufWriter, Read, Write};
 
 /// Timing statistic.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Timing {
     /// Driving time.
     pub driving: i32,
@@ -13,12 +13,12 @@ pub struct Timing {
     /// Waiting time.
     pub waiting: i32,
     /// Break time.
-    #[serde(rename(serialize = "break"))]
+    #[serde(rename = "break")]
     pub break_time: i32,
 }
 
 /// Represents statistic.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Statistic {
     /// Total cost.
     pub cost: f64,
@@ -31,7 +31,7 @@ pub struct Statistic {
 }
 
 /// Represents a schedule.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Schedule {
     /// Arrival time specified in RFC3339 format.
     pub arrival: String,
@@ -40,7 +40,7 @@ pub struct Schedule {
 }
 
 /// Represents time interval.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Interval {
     /// Start time specified in RFC3339 format.
     pub start: String,
@@ -49,13 +49,13 @@ pub struct Interval {
 }
 
 /// An activity is unit of work performed at some place.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Activity {
     /// Job id.
     pub job_id: String,
     /// Activity type.
-    #[serde(rename(serialize = "type"))]
+    #[serde(rename = "type")]
     pub activity_type: String,
     /// Location.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,12 +65,15 @@ pub struct Activity {
     pub time: Option<Interval>,
     /// Job tag.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename(serialize = "tag"))]
+    #[serde(rename = "tag")]
     pub job_tag: Option<String>,
+    /// Lateness in seconds: specified only when activity is started after end of its soft time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<i32>,
 }
 
 /// A stop is a place where vehicle is supposed to be parked.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Stop {
     /// Stop location.
     pub location: Location,
@@ -85,13 +88,16 @@ pub struct Stop {
 }
 
 /// A tour is list of stops with their activities performed by specific vehicle.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Tour {
     /// Vehicle id.
     pub vehicle_id: String,
     /// Vehicle type id.
     pub type_id: String,
+    /// Driver id, specified only when drivers are defined in the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_id: Option<String>,
     /// Shift index.
     pub shift_index: usize,
     /// List of stops.
@@ -101,7 +107,7 @@ pub struct Tour {
 }
 
 /// Unassigned job reason.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct UnassignedJobReason {
     /// A reason code.
     pub code: i32,
@@ -110,7 +116,7 @@ pub struct UnassignedJobReason {
 }
 
 /// Unassigned job.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UnassignedJob {
     /// Job id.
@@ -120,7 +126,7 @@ pub struct UnassignedJob {
 }
 
 /// Defines iteration model.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Iteration {
     /// Iteration number.
     pub number: i32,
@@ -131,18 +137,18 @@ pub struct Iteration {
     /// Amount of tours
     pub tours: usize,
     /// Amount of unassigned jobs.
-    pub unassinged: usize,
+    pub unassigned: usize,
 }
 
 /// Contains extra information.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Extras {
     /// Stores information about iteration performance.
     pub performance: Vec<Iteration>,
 }
 
 /// A VRP solution.
-#[derive(Clone, Serialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Solution {
     /// Total statistic.
@@ -160,3 +166,8 @@ pub struct Solution {
 pub fn serialize_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, solution)
 }
+
+/// Deserializes solution from json format.
+pub fn deserialize_solution<R: Read>(reader: BufReader<R>) -> Result<Solution, Error> {
+    serde_json::from_reader(reader)
+}
@@ -0,0 +1,211 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/json/solution/reader_test.rs"]
+mod reader_test;
+
+use crate::json::coord_index::CoordIndex;
+use crate::json::solution::{deserialize_solution, Activity, Stop, Tour};
+use crate::parse_time;
+use std::collections::HashSet;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{create_end_activity, create_start_activity};
+use vrp_core::models::common::{IdDimension, Schedule, TimeWindow, ValueDimension};
+use vrp_core::models::problem::{Actor, Job, Single};
+use vrp_core::models::solution::{Place, Registry, Route, Tour as CoreTour};
+use vrp_core::models::{Problem, Solution};
+
+type ApiSolution = crate::json::solution::model::Solution;
+type CoreActivity = vrp_core::models::solution::Activity;
+
+/// Reads solution in pragmatic format and converts it to core solution.
+/// Jobs which are not present in any tour are considered as unassigned as well as multi jobs
+/// which are present only partially.
+pub fn read_init_solution<R: Read>(reader: BufReader<R>, problem: Arc<Problem>) -> Result<Solution, String> {
+    let api_solution = deserialize_solution(reader).map_err(|err| format!("cannot deserialize solution: {}", err))?;
+
+    map_to_solution(&api_solution, problem)
+}
+
+fn map_to_solution(api_solution: &ApiSolution, problem: Arc<Problem>) -> Result<Solution, String> {
+    let coord_index = problem
+        .extras
+        .get("coord_index")
+        .and_then(|s| s.downcast_ref::<CoordIndex>())
+        .ok_or_else(|| "cannot get coord index".to_string())?;
+
+    let mut registry = Registry::new(&problem.fleet);
+    let mut used: HashSet<Job> = HashSet::default();
+
+    let mut routes = api_solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let actor = registry
+                .available()
+                .find(|actor| is_same_vehicle(actor, &tour.vehicle_id, tour.shift_index) && is_same_driver(actor, tour))
+                .ok_or_else(|| {
+                    format!("cannot find available vehicle '{}' for shift {}", tour.vehicle_id, tour.shift_index)
+                })?;
+            registry.use_actor(&actor);
+
+            create_route(problem.as_ref(), coord_index, actor, tour, &mut used)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    routes.iter_mut().for_each(|route| remove_partial_multi_jobs(&mut route.tour));
+
+    let assigned = routes.iter().flat_map(|route| route.tour.jobs()).collect::<HashSet<_>>();
+    let unassigned = problem.jobs.all().filter(|job| !assigned.contains(job)).map(|job| (job, 0)).collect();
+
+    Ok(Solution { registry, routes, unassigned, extras: problem.extras.clone() })
+}
+
+fn create_route(
+    problem: &Problem,
+    coord_index: &CoordIndex,
+    actor: Arc<Actor>,
+    tour: &Tour,
+    used: &mut HashSet<Job>,
+) -> Result<Route, String> {
+    let mut core_tour = CoreTour::default();
+    core_tour.set_start(create_start_activity(&actor));
+    create_end_activity(&actor).map(|end| core_tour.set_end(end));
+
+    let start_time = actor.detail.time.start;
+
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| !is_terminal(activity))
+        .try_for_each(|(stop, activity)| -> Result<(), String> {
+            let location = activity.location.as_ref().unwrap_or(&stop.location);
+            let location = coord_index
+                .get_by_loc(location)
+                .ok_or_else(|| format!("unknown location for activity of job '{}'", activity.job_id))?;
+
+            let single = find_single(problem, tour, activity, location, used).ok_or_else(|| {
+                format!("cannot match activity '{}' of job '{}'", activity.activity_type, activity.job_id)
+            })?;
+            used.insert(Job::Single(single.clone()));
+
+            let schedule = get_schedule(stop, activity);
+            let place = single
+                .places
+                .iter()
+                .find(|place| place.location.iter().all(|&l| l == location))
+                .ok_or_else(|| format!("unexpected location for activity of job '{}'", activity.job_id))?;
+            let time = place
+                .times
+                .iter()
+                .map(|span| span.to_time_window(start_time))
+                .find(|tw| tw.intersects(&TimeWindow::new(schedule.arrival, schedule.departure)))
+                .or_else(|| place.times.first().map(|span| span.to_time_window(start_time)))
+                .unwrap_or_else(TimeWindow::max);
+
+            core_tour.insert_last(Box::new(CoreActivity {
+                place: Place { location, duration: place.duration, time },
+                schedule,
+                job: Some(single),
+            }));
+
+            Ok(())
+        })?;
+
+    // NOTE keep terminal schedules as they are specified in the solution
+    tour.stops.iter().flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity))).for_each(
+        |(stop, activity)| {
+            let index = match activity.activity_type.as_str() {
+                "departure" => Some(0),
+                "arrival" => Some(core_tour.total() - 1),
+                _ => None,
+            };
+
+            if let Some(terminal) = index.and_then(|index| core_tour.get_mut(index)) {
+                terminal.schedule = get_schedule(stop, activity);
+            }
+        },
+    );
+
+    Ok(Route { actor, tour: core_tour })
+}
+
+/// Removes multi jobs which have not all their sub jobs in the tour, so they are treated as unassigned.
+fn remove_partial_multi_jobs(tour: &mut CoreTour) {
+    let partial = tour
+        .jobs()
+        .filter(|job| match job {
+            Job::Multi(multi) => tour.all_activities().filter(|a| a.has_same_job(job)).count() < multi.jobs.len(),
+            Job::Single(_) => false,
+        })
+        .collect::<Vec<_>>();
+
+    partial.iter().for_each(|job| {
+        tour.remove(job);
+    });
+}
+
+/// Finds not yet used single job which matches given activity.
+fn find_single(
+    problem: &Problem,
+    tour: &Tour,
+    activity: &Activity,
+    location: usize,
+    used: &HashSet<Job>,
+) -> Option<Arc<Single>> {
+    let is_not_used = |single: &Arc<Single>| !used.contains(&Job::Single(single.clone()));
+    let is_same_location =
+        |single: &Arc<Single>| single.places.iter().any(|place| place.location.iter().all(|&l| l == location));
+    let is_same_tag = |single: &Arc<Single>| single.dimens.get_value::<String>("tag") == activity.job_tag.as_ref();
+
+    match activity.activity_type.as_str() {
+        "break" | "reload" => problem
+            .jobs
+            .all()
+            .filter_map(|job| job.as_single().cloned())
+            .filter(|single| {
+                single.dimens.get_value::<String>("type") == Some(&activity.activity_type)
+                    && single.dimens.get_value::<String>("vehicle_id") == Some(&tour.vehicle_id)
+                    && single.dimens.get_value::<usize>("shift_index") == Some(&tour.shift_index)
+            })
+            .find(|single| is_not_used(single) && is_same_location(single) && is_same_tag(single)),
+        _ => problem.jobs.all().find(|job| job.dimens().get_id() == Some(&activity.job_id)).and_then(|job| match job {
+            Job::Single(single) => Some(single).filter(|single| is_not_used(single) && is_same_location(single)),
+            Job::Multi(multi) => multi
+                .jobs
+                .iter()
+                .filter(|single| single.dimens.get_value::<String>("type") == Some(&activity.activity_type))
+                .find(|single| is_not_used(single) && is_same_location(single) && is_same_tag(single))
+                .cloned(),
+        }),
+    }
+}
+
+fn get_schedule(stop: &Stop, activity: &Activity) -> Schedule {
+    let (arrival, departure) = activity
+        .time
+        .as_ref()
+        .map_or_else(|| (&stop.time.arrival, &stop.time.departure), |interval| (&interval.start, &interval.end));
+
+    Schedule::new(parse_time(arrival), parse_time(departure))
+}
+
+fn is_terminal(activity: &Activity) -> bool {
+    activity.activity_type == "departure" || activity.activity_type == "arrival"
+}
+
+fn is_same_driver(actor: &Arc<Actor>, tour: &Tour) -> bool {
+    let is_in_shift = match tour.stops.first() {
+        Some(stop) => {
+            let departure = parse_time(&stop.time.departure);
+            actor.detail.time.start <= departure && departure <= actor.detail.time.end
+        }
+        None => true,
+    };
+
+    actor.driver.dimens.get_id() == tour.driver_id.as_ref() && is_in_shift
+}
+
+fn is_same_vehicle(actor: &Arc<Actor>, vehicle_id: &String, shift_index: usize) -> bool {
+    actor.vehicle.dimens.get_id() == Some(vehicle_id)
+        && actor.vehicle.dimens.get_value::<usize>("shift_index") == Some(&shift_index)
+}
@@ -2,21 +2,22 @@
 #[path = "../../../tests/unit/json/solution/writer_test.rs"]
 mod writer_test;
 
-use crate::extensions::MultiDimensionalCapacity;
+use crate::extensions::{get_activity_lateness, MultiDimensionalCapacity};
 use crate::format_time;
 use crate::json::coord_index::CoordIndex;
 use crate::json::solution::model::Timing;
 use crate::json::solution::{
-    serialize_solution, serialize_solution_as_geojson, Activity, Extras, Interval, Statistic, Stop, Tour,
+    serialize_solution, serialize_solution_as_geojson, Activity, Extras, Interval, Iteration, Statistic, Stop, Tour,
     UnassignedJob, UnassignedJobReason,
 };
 use crate::json::*;
 use std::io::{BufWriter, Write};
-use vrp_core::construction::constraints::{route_intervals, Demand, DemandDimension};
+use vrp_core::construction::constraints::{get_overtime_cost, route_intervals, Demand, DemandDimension};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, Multi};
 use vrp_core::models::solution::{Route, TourActivity};
 use vrp_core::models::{Problem, Solution};
+use vrp_core::refinement::Progress;
 
 type ApiSolution = crate::json::solution::model::Solution;
 type ApiSchedule = crate::json::solution::model::Schedule;
@@ -68,7 +69,7 @@ impl Leg {
 
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution) -> ApiSolution {
-    let coord_index = solution
+    let coord_index = problem
         .extras
         .get("coord_index")
         .and_then(|s| s.downcast_ref::<CoordIndex>())
@@ -94,6 +95,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
     let mut tour = Tour {
         vehicle_id: vehicle.dimens.get_id().unwrap().clone(),
         type_id: vehicle.dimens.get_value::<String>("type_id").unwrap().to_string(),
+        driver_id: actor.driver.dimens.get_id().cloned(),
         shift_index: *vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
         stops: vec![],
         statistic: Statistic::default(),
@@ -126,9 +128,13 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 activities: vec![Activity {
                     job_id: "departure".to_string(),
                     activity_type: "departure".to_string(),
-                    location: None,
-                    time: None,
+                    location: Some(coord_index.get_by_idx(&start.place.location).unwrap()),
+                    time: Some(Interval {
+                        start: format_time(start.schedule.departure),
+                        end: format_time(start.schedule.departure),
+                    }),
                     job_tag: None,
+                    lateness: None,
                 }],
             });
             (start_idx + 1, start)
@@ -169,10 +175,13 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 let waiting = start - act.schedule.arrival;
                 let serving = problem.activity.duration(route.actor.as_ref(), act, act.schedule.arrival);
                 let departure = start + serving;
+                let (lateness, lateness_cost) = get_activity_lateness(act, act.schedule.arrival).unwrap_or((0., 0.));
+                let lateness = Some(lateness.round() as i32).filter(|lateness| *lateness > 0);
 
                 // total cost and distance
                 let cost = leg.statistic.cost
                     + problem.activity.cost(actor, act, act.schedule.arrival)
+                    + lateness_cost
                     + problem.transport.cost(actor, prev_location, act.place.location, prev_departure);
                 let distance = leg.statistic.distance
                     + problem.transport.distance(vehicle.profile, prev_location, act.place.location, prev_departure)
@@ -201,6 +210,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     location: Some(coord_index.get_by_idx(&act.place.location).unwrap()),
                     time: Some(Interval { start: format_time(arrival), end: format_time(departure) }),
                     job_tag,
+                    lateness,
                 });
 
                 Leg {
@@ -236,7 +246,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
             activity.time = None;
         });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    let duration = route.tour.end().unwrap().schedule.arrival - route.tour.start().unwrap().schedule.departure;
+    leg.statistic.cost += vehicle.costs.fixed + actor.driver.costs.fixed + get_overtime_cost(actor, duration);
 
     tour.vehicle_id = vehicle.dimens.get_id().unwrap().clone();
     tour.type_id = vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
@@ -266,7 +277,7 @@ fn calculate_load(
 }
 
 fn create_unassigned(solution: &Solution) -> Vec<UnassignedJob> {
-    solution.unassigned.iter().fold(vec![], |mut acc, unassigned| {
+    let mut unassigned = solution.unassigned.iter().fold(vec![], |mut acc, unassigned| {
         let reason = match *unassigned.1 {
             TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
             CAPACITY_CONSTRAINT_CODE => (3, "does not fit into any vehicle due to capacity"),
@@ -276,6 +287,10 @@ fn create_unassigned(solution: &Solution) -> Vec<UnassignedJob> {
             REACHABLE_CONSTRAINT_CODE => (100, "location unreachable"),
             BREAK_CONSTRAINT_CODE => (101, "break is not assignable"),
             PRIORITY_CONSTRAINT_CODE => (103, "cannot be served due to priority"),
+            RIDE_TIME_CONSTRAINT_CODE => (104, "cannot be served due to max ride time constraint"),
+            TOUR_SIZE_CONSTRAINT_CODE => (105, "cannot be assigned due to tour size constraint of vehicle"),
+            COMPATIBILITY_CONSTRAINT_CODE => (106, "cannot be assigned due to compatibility constraint"),
+            GROUP_CONSTRAINT_CODE => (107, "cannot be assigned due to group constraint"),
             _ => (0, "unknown"),
         };
         let dimens = match unassigned.0 {
@@ -291,7 +306,12 @@ fn create_unassigned(solution: &Solution) -> Vec<UnassignedJob> {
         });
 
         acc
-    })
+    });
+
+    // NOTE keep order stable as unassigned jobs are stored in hash map
+    unassigned.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    unassigned
 }
 
 fn get_activity_type(activity: &TourActivity) -> Option<&String> {
@@ -329,9 +349,16 @@ fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
 }
 
 fn create_extras(solution: &Solution) -> Option<Extras> {
-    if solution.extras.get("iterations").is_some() {
-        unimplemented!()
-    }
-
-    None
+    solution.extras.get("iterations").and_then(|s| s.downcast_ref::<Vec<Progress>>()).map(|iterations| Extras {
+        performance: iterations
+            .iter()
+            .map(|progress| Iteration {
+                number: progress.generation as i32,
+                cost: progress.cost,
+                timestamp: progress.elapsed,
+                tours: progress.tours,
+                unassigned: progress.unassigned,
+            })
+            .collect(),
+    })
 }
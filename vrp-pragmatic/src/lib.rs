@@ -7,10 +7,6 @@
 #[macro_use]
 pub mod helpers;
 
-#[cfg(test)]
-#[path = "../tests/checker/mod.rs"]
-pub mod checker;
-
 #[cfg(test)]
 #[path = "../tests/generator/mod.rs"]
 pub mod generator;
@@ -28,8 +24,12 @@ mod extensions;
 mod utils;
 mod validation;
 
+pub mod checker;
 pub mod json;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod routing;
+
 use crate::json::coord_index::CoordIndex;
 use crate::json::problem::{deserialize_problem, PragmaticProblem, Problem};
 use crate::json::solution::PragmaticSolution;
@@ -39,10 +39,12 @@ use std::io::{BufReader, BufWriter};
 use std::os::raw::c_char;
 use std::panic::catch_unwind;
 use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::models::Solution as CoreSolution;
-use vrp_solver::SolverBuilder;
+use vrp_solver::config::Config as SolverConfig;
+use vrp_solver::{Progress, SolverBuilder};
 
 use crate::json::Location;
 use std::io::Read;
@@ -52,6 +54,11 @@ pub fn get_locations(problem: &Problem) -> Vec<Location> {
     CoordIndex::new(&problem).unique()
 }
 
+/// Returns solver configuration specified in problem's `config`, if any.
+pub fn get_solver_config(problem: &CoreProblem) -> Option<SolverConfig> {
+    problem.extras.get("solver_config").and_then(|config| config.downcast_ref::<SolverConfig>()).cloned()
+}
+
 /// Returns serialized into json list of unique locations from serialized `problem` in order used
 /// by routing matrix.
 pub fn get_locations_serialized<R: Read>(problem: BufReader<R>) -> Result<String, String> {
@@ -122,6 +129,70 @@ extern "C" fn solve(
     matrices_len: *const i32,
     success: Callback,
     failure: Callback,
+) {
+    solve_problem(problem, matrices, matrices_len, None, None, success, failure)
+}
+
+/// Specifies progress callback which receives generation, best cost, amount of tours and unassigned jobs.
+type ProgressCallback = extern "C" fn(usize, f64, usize, usize);
+
+/// Creates a cancellation handle which can be passed to `solve_with_progress` and should be
+/// released using `release_cancellation`.
+#[no_mangle]
+extern "C" fn create_cancellation() -> *const AtomicBool {
+    Arc::into_raw(Arc::new(AtomicBool::new(false)))
+}
+
+/// Requests cancellation: solver stops as soon as possible and returns the best known solution.
+#[no_mangle]
+extern "C" fn cancel(cancellation: *const AtomicBool) {
+    if !cancellation.is_null() {
+        unsafe { (*cancellation).store(true, Ordering::Relaxed) }
+    }
+}
+
+/// Releases cancellation handle created by `create_cancellation`.
+#[no_mangle]
+extern "C" fn release_cancellation(cancellation: *const AtomicBool) {
+    if !cancellation.is_null() {
+        unsafe { Arc::from_raw(cancellation) };
+    }
+}
+
+/// Solves problem reporting progress to the callback. Both cancellation handle and progress
+/// callback are optional and can be null.
+#[no_mangle]
+extern "C" fn solve_with_progress(
+    problem: *const c_char,
+    matrices: *const *const c_char,
+    matrices_len: *const i32,
+    cancellation: *const AtomicBool,
+    progress: Option<ProgressCallback>,
+    success: Callback,
+    failure: Callback,
+) {
+    let cancellation = if cancellation.is_null() {
+        None
+    } else {
+        // NOTE handle is still owned by caller, so increase reference count
+        let cancellation = unsafe { Arc::from_raw(cancellation) };
+        let shared = cancellation.clone();
+        std::mem::forget(cancellation);
+
+        Some(shared)
+    };
+
+    solve_problem(problem, matrices, matrices_len, cancellation, progress, success, failure)
+}
+
+fn solve_problem(
+    problem: *const c_char,
+    matrices: *const *const c_char,
+    matrices_len: *const i32,
+    cancellation: Option<Arc<AtomicBool>>,
+    progress: Option<ProgressCallback>,
+    success: Callback,
+    failure: Callback,
 ) {
     let result = catch_unwind(|| {
         let problem = to_string(problem);
@@ -130,7 +201,15 @@ extern "C" fn solve(
 
         let problem = Arc::new((problem, matrices).read_pragmatic().ok().unwrap());
 
-        let (solution, _, _) = SolverBuilder::default().build().solve(problem.clone()).unwrap();
+        let (solution, _, _) = SolverBuilder::default()
+            .with_config(get_solver_config(problem.as_ref()))
+            .with_cancellation(cancellation.clone())
+            .with_progress(progress.map::<vrp_solver::ProgressCallback, _>(|progress| {
+                Box::new(move |p: &Progress| progress(p.generation, p.cost, p.tours, p.unassigned))
+            }))
+            .build()
+            .solve(problem.clone())
+            .unwrap();
 
         solution_to_string(problem.as_ref(), &solution)
     });
@@ -156,8 +235,70 @@ mod wasm {
     use super::*;
     use crate::json::problem::Matrix;
 
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_name = Function)]
+        type JsFunction;
+
+        #[wasm_bindgen(method, js_name = call)]
+        fn call1(this: &JsFunction, context: &JsValue, arg: &JsValue) -> JsValue;
+    }
+
+    /// Wraps js progress callback.
+    struct JsProgress(JsFunction);
+
+    // NOTE wasm is single threaded, so callback is never called from another thread
+    unsafe impl Send for JsProgress {}
+    unsafe impl Sync for JsProgress {}
+
     #[wasm_bindgen]
     pub fn web_solve(problem: &JsValue, matrices: &JsValue) -> Result<JsValue, JsValue> {
+        solve_with_builder(problem, matrices, &mut SolverBuilder::default())
+    }
+
+    /// Solves problem reporting progress to js callback which receives an object with generation,
+    /// cost, tours and unassigned fields. When callback returns true, solving is cancelled and
+    /// the best known solution is returned.
+    #[wasm_bindgen]
+    pub fn web_solve_with_progress(
+        problem: &JsValue,
+        matrices: &JsValue,
+        progress: &JsValue,
+    ) -> Result<JsValue, JsValue> {
+        if !progress.is_function() {
+            return Err(JsValue::from_str("Progress callback is not a function"));
+        }
+
+        let cancellation = Arc::new(AtomicBool::new(false));
+        let callback = JsProgress(progress.clone().unchecked_into::<JsFunction>());
+        let is_cancelled = cancellation.clone();
+
+        solve_with_builder(
+            problem,
+            matrices,
+            SolverBuilder::default().with_cancellation(Some(cancellation)).with_progress(Some(Box::new(
+                move |p: &Progress| {
+                    let progress = JsValue::from_serde(&serde_json::json!({
+                        "generation": p.generation,
+                        "cost": p.cost,
+                        "tours": p.tours,
+                        "unassigned": p.unassigned,
+                    }))
+                    .unwrap_or(JsValue::NULL);
+
+                    if callback.0.call1(&JsValue::NULL, &progress).is_truthy() {
+                        is_cancelled.store(true, Ordering::Relaxed);
+                    }
+                },
+            ))),
+        )
+    }
+
+    fn solve_with_builder(
+        problem: &JsValue,
+        matrices: &JsValue,
+        builder: &mut SolverBuilder,
+    ) -> Result<JsValue, JsValue> {
         let problem: Problem = problem
             .into_serde()
             .map_err(|err| JsValue::from_str(format!("Cannot read problem: '{}'", err).as_str()))?;
@@ -176,7 +317,8 @@ mod wasm {
             )?,
         );
 
-        let (solution, _, _) = SolverBuilder::default()
+        let (solution, _, _) = builder
+            .with_config(get_solver_config(problem.as_ref()))
             .build()
             .solve(problem.clone())
             .ok_or_else(|| JsValue::from_str("Cannot solve problem"))?;
@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../tests/unit/routing/cache_test.rs"]
+mod cache_test;
+
+use super::RoutingProvider;
+use crate::json::problem::{deserialize_matrix, serialize_binary_matrix, Matrix, Profile};
+use crate::json::Location;
+use std::fs::{create_dir_all, rename, File};
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+/// A routing provider which keeps matrices returned by inner provider on disk. Matrices are stored
+/// in binary format using file name derived from hash of routing source, profile type and locations.
+/// Failure to write cache is not fatal: it is reported to logger and the matrix is still returned.
+pub struct CachedRoutingProvider<P: RoutingProvider> {
+    inner: P,
+    directory: PathBuf,
+    logger: Box<dyn Fn(String)>,
+}
+
+impl<P: RoutingProvider> CachedRoutingProvider<P> {
+    /// Creates a new instance of `CachedRoutingProvider` which logs into stderr.
+    pub fn new(inner: P, directory: PathBuf) -> Self {
+        Self::new_with_logger(inner, directory, Box::new(|msg| eprintln!("{}", msg)))
+    }
+
+    /// Creates a new instance of `CachedRoutingProvider` with custom logger.
+    pub fn new_with_logger(inner: P, directory: PathBuf, logger: Box<dyn Fn(String)>) -> Self {
+        Self { inner, directory, logger }
+    }
+
+    fn get_path(&self, profile: &Profile, locations: &[Location]) -> PathBuf {
+        let mut hash = FnvHash::default();
+
+        hash.write(self.inner.source().as_bytes());
+        hash.write(profile.profile_type.as_bytes());
+        locations.iter().for_each(|location| match location {
+            Location::Coordinate { lat, lng } => {
+                hash.write(&lat.to_bits().to_le_bytes());
+                hash.write(&lng.to_bits().to_le_bytes());
+            }
+            Location::Reference { index } => hash.write(&(*index as u64).to_le_bytes()),
+        });
+
+        self.directory.join(format!("{:016x}.matrix", hash.0))
+    }
+
+    fn read_cached(&self, path: &PathBuf) -> Option<Matrix> {
+        let file = File::open(path).ok()?;
+
+        deserialize_matrix(BufReader::new(file)).ok()
+    }
+
+    fn write_cached(&self, path: &PathBuf, matrix: &Matrix) -> Result<(), String> {
+        create_dir_all(&self.directory).map_err(|err| err.to_string())?;
+
+        // NOTE write into temporary file first to avoid reading partially written matrix
+        let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        let file = File::create(&temp_path).map_err(|err| err.to_string())?;
+        serialize_binary_matrix(BufWriter::new(file), matrix).map_err(|err| err.to_string())?;
+
+        rename(temp_path, path).map_err(|err| err.to_string())
+    }
+}
+
+impl<P: RoutingProvider> RoutingProvider for CachedRoutingProvider<P> {
+    fn source(&self) -> String {
+        self.inner.source()
+    }
+
+    fn get_matrix(&self, profile: &Profile, locations: &[Location]) -> Result<Matrix, String> {
+        let path = self.get_path(profile, locations);
+
+        if let Some(mut matrix) = self.read_cached(&path) {
+            matrix.profile = profile.name.clone();
+            return Ok(matrix);
+        }
+
+        let matrix = self.inner.get_matrix(profile, locations)?;
+
+        if let Err(err) = self.write_cached(&path, &matrix) {
+            (self.logger)(format!("cannot write routing cache '{}': '{}'", path.display(), err));
+        }
+
+        Ok(matrix)
+    }
+}
+
+/// A FNV-1a hash which, unlike default hasher, is stable between program runs and versions.
+struct FnvHash(u64);
+
+impl Default for FnvHash {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl FnvHash {
+    fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|byte| {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        });
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+#[path = "../../tests/unit/routing/http_test.rs"]
+mod http_test;
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// A timeout for connecting to routing service.
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// A timeout for sending request and reading response from routing service.
+const READ_TIMEOUT_SECS: u64 = 300;
+
+/// Sends http request with optional json body and returns response body on success.
+/// NOTE only plain http is supported: routing services are expected to be deployed nearby.
+pub(crate) fn send_request(method: &str, url: &str, body: Option<&str>) -> Result<String, String> {
+    let (authority, path) = parse_url(url)?;
+
+    let mut stream = connect(authority.as_str())
+        .map_err(|err| format!("cannot connect to routing service '{}': '{}'", authority, err))?;
+    stream.set_read_timeout(Some(Duration::from_secs(READ_TIMEOUT_SECS))).map_err(|err| err.to_string())?;
+    stream.set_write_timeout(Some(Duration::from_secs(READ_TIMEOUT_SECS))).map_err(|err| err.to_string())?;
+
+    let host = authority.trim_end_matches(":80");
+    let request = match body {
+        Some(body) => format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nAccept: application/json\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            method,
+            path,
+            host,
+            body.len(),
+            body
+        ),
+        None => format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nAccept: application/json\r\nConnection: close\r\n\r\n",
+            method, path, host
+        ),
+    };
+
+    stream.write_all(request.as_bytes()).map_err(|err| format!("cannot send request: '{}'", err))?;
+
+    let mut response = vec![];
+    stream.read_to_end(&mut response).map_err(|err| format!("cannot read response: '{}'", err))?;
+
+    parse_response(response.as_slice())
+}
+
+/// Connects to the first reachable address of given authority using connect timeout.
+fn connect(authority: &str) -> std::io::Result<TcpStream> {
+    let timeout = Duration::from_secs(CONNECT_TIMEOUT_SECS);
+
+    let mut last_error = std::io::Error::new(std::io::ErrorKind::NotFound, "cannot resolve address");
+    for address in authority.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_error = err,
+        }
+    }
+
+    Err(last_error)
+}
+
+/// Splits url into authority with port and path with query.
+fn parse_url(url: &str) -> Result<(String, String), String> {
+    let url = url.strip_prefix("http://").ok_or_else(|| format!("only http scheme is supported: '{}'", url))?;
+
+    let (authority, path) = match url.find('/') {
+        Some(idx) => (&url[..idx], &url[idx..]),
+        None => (url, "/"),
+    };
+
+    if authority.is_empty() {
+        return Err("url has no host".to_string());
+    }
+
+    let authority = if authority.contains(':') { authority.to_string() } else { format!("{}:80", authority) };
+
+    Ok((authority, path.to_string()))
+}
+
+/// Parses raw http response and returns its body if status code is successful.
+fn parse_response(response: &[u8]) -> Result<String, String> {
+    let separator = b"\r\n\r\n";
+    let head_end = response
+        .windows(separator.len())
+        .position(|window| window == separator)
+        .ok_or_else(|| "malformed http response".to_string())?;
+
+    let head = String::from_utf8_lossy(&response[..head_end]).to_string();
+    let body = &response[head_end + separator.len()..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| "malformed http status line".to_string())?;
+
+    let headers = lines
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            Some((parts.next()?.trim().to_lowercase(), parts.next()?.trim().to_lowercase()))
+        })
+        .collect::<Vec<_>>();
+    let get_header = |name: &str| headers.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+
+    let body = if matches!(get_header("transfer-encoding"), Some(value) if value.contains("chunked")) {
+        decode_chunked(body)?
+    } else if let Some(length) = get_header("content-length").and_then(|value| value.parse::<usize>().ok()) {
+        body[..length.min(body.len())].to_vec()
+    } else {
+        body.to_vec()
+    };
+
+    let body = String::from_utf8(body).map_err(|err| format!("response is not utf8: '{}'", err))?;
+
+    if (200..300).contains(&status) {
+        Ok(body)
+    } else {
+        Err(format!("routing service returned status {}: '{}'", status, body))
+    }
+}
+
+/// Decodes body sent using chunked transfer encoding.
+fn decode_chunked(body: &[u8]) -> Result<Vec<u8>, String> {
+    let mut result = vec![];
+    let mut rest = body;
+
+    loop {
+        let line_end = rest
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or_else(|| "malformed chunked response".to_string())?;
+        let size = String::from_utf8_lossy(&rest[..line_end]);
+        let size = size.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| "malformed chunk size".to_string())?;
+
+        rest = &rest[line_end + 2..];
+
+        if size == 0 {
+            return Ok(result);
+        }
+
+        if rest.len() < size {
+            return Err("truncated chunked response".to_string());
+        }
+
+        result.extend_from_slice(&rest[..size]);
+        rest = rest.get(size + 2..).unwrap_or_default();
+    }
+}
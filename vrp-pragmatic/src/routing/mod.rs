@@ -0,0 +1,31 @@
+//! Contains routing providers which are used to get routing matrices from external services
+//! instead of passing them explicitly.
+
+mod cache;
+pub use self::cache::CachedRoutingProvider;
+
+mod http;
+
+mod table;
+pub use self::table::*;
+
+use crate::get_locations;
+use crate::json::problem::{Matrix, Problem, Profile};
+use crate::json::Location;
+
+/// Provides routing matrices used to create transport costs.
+pub trait RoutingProvider {
+    /// Returns a name of routing source, e.g. service url.
+    fn source(&self) -> String;
+
+    /// Returns a routing matrix for given profile. Matrix has square shape and
+    /// follows order of given locations.
+    fn get_matrix(&self, profile: &Profile, locations: &[Location]) -> Result<Matrix, String>;
+}
+
+/// Gets routing matrices for all problem's profiles using given provider.
+pub fn get_routing_matrices(problem: &Problem, provider: &dyn RoutingProvider) -> Result<Vec<Matrix>, String> {
+    let locations = get_locations(problem);
+
+    problem.fleet.profiles.iter().map(|profile| provider.get_matrix(profile, locations.as_slice())).collect()
+}
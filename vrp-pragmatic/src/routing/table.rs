@@ -0,0 +1,228 @@
+#[cfg(test)]
+#[path = "../../tests/unit/routing/table_test.rs"]
+mod table_test;
+
+use super::http::send_request;
+use super::RoutingProvider;
+use crate::json::problem::{Matrix, Profile};
+use crate::json::Location;
+use serde::Deserialize;
+use serde_json::json;
+
+/// A default maximum amount of locations used as sources or destinations in one request.
+pub const DEFAULT_TABLE_CHUNK_SIZE: usize = 50;
+
+/// Specifies api of routing service.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TableApi {
+    /// OSRM table service: `GET /table/v1/{profile}/{coordinates}`.
+    Osrm,
+    /// Valhalla matrix service: `POST /sources_to_targets`.
+    Valhalla,
+}
+
+/// A routing provider which fetches matrices from OSRM or Valhalla like table endpoint.
+/// Large location sets are split into chunks, so each request has limited amount of
+/// sources and destinations.
+pub struct TableRoutingProvider {
+    url: String,
+    api: TableApi,
+    chunk_size: usize,
+}
+
+impl TableRoutingProvider {
+    /// Creates a new instance of `TableRoutingProvider` using service base url.
+    pub fn new(url: &str, api: TableApi) -> Self {
+        Self { url: url.trim_end_matches('/').to_string(), api, chunk_size: DEFAULT_TABLE_CHUNK_SIZE }
+    }
+
+    /// Creates a new instance of `TableRoutingProvider` with custom chunk size which should be
+    /// greater than zero.
+    pub fn new_with_chunk_size(url: &str, api: TableApi, chunk_size: usize) -> Result<Self, String> {
+        if chunk_size == 0 {
+            return Err("table chunk size should be greater than zero".to_string());
+        }
+
+        Ok(Self { chunk_size, ..Self::new(url, api) })
+    }
+
+    fn get_table(
+        &self,
+        profile: &Profile,
+        sources: &[(f64, f64)],
+        destinations: &[(f64, f64)],
+    ) -> Result<Table, String> {
+        let table = match self.api {
+            TableApi::Osrm => self.get_osrm_table(profile, sources, destinations)?,
+            TableApi::Valhalla => self.get_valhalla_table(profile, sources, destinations)?,
+        };
+
+        let is_valid_size = |values: &Vec<Vec<Option<f64>>>| {
+            values.len() == sources.len() && values.iter().all(|row| row.len() == destinations.len())
+        };
+
+        if is_valid_size(&table.durations) && is_valid_size(&table.distances) {
+            Ok(table)
+        } else {
+            Err("routing service returned table of unexpected size".to_string())
+        }
+    }
+
+    fn get_osrm_table(
+        &self,
+        profile: &Profile,
+        sources: &[(f64, f64)],
+        destinations: &[(f64, f64)],
+    ) -> Result<Table, String> {
+        let coordinates = sources
+            .iter()
+            .chain(destinations.iter())
+            .map(|(lat, lng)| format!("{},{}", lng, lat))
+            .collect::<Vec<_>>()
+            .join(";");
+        let join_range = |range: std::ops::Range<usize>| range.map(|idx| idx.to_string()).collect::<Vec<_>>().join(";");
+
+        let url = format!(
+            "{}/table/v1/{}/{}?sources={}&destinations={}&annotations=duration,distance",
+            self.url,
+            profile.profile_type,
+            coordinates,
+            join_range(0..sources.len()),
+            join_range(sources.len()..sources.len() + destinations.len())
+        );
+
+        let response = send_request("GET", url.as_str(), None)?;
+        let response: OsrmResponse =
+            serde_json::from_str(response.as_str()).map_err(|err| format!("cannot parse osrm response: '{}'", err))?;
+
+        if response.code != "Ok" {
+            return Err(format!(
+                "osrm returned error code '{}': '{}'",
+                response.code,
+                response.message.unwrap_or_default()
+            ));
+        }
+
+        Ok(Table {
+            durations: response.durations.ok_or_else(|| "osrm response has no durations".to_string())?,
+            distances: response.distances.ok_or_else(|| "osrm response has no distances".to_string())?,
+        })
+    }
+
+    fn get_valhalla_table(
+        &self,
+        profile: &Profile,
+        sources: &[(f64, f64)],
+        destinations: &[(f64, f64)],
+    ) -> Result<Table, String> {
+        let to_points = |points: &[(f64, f64)]| {
+            points.iter().map(|(lat, lng)| json!({ "lat": lat, "lon": lng })).collect::<Vec<_>>()
+        };
+
+        let body = json!({
+            "sources": to_points(sources),
+            "targets": to_points(destinations),
+            "costing": profile.profile_type,
+            "units": "kilometers",
+        })
+        .to_string();
+
+        let url = format!("{}/sources_to_targets", self.url);
+        let response = send_request("POST", url.as_str(), Some(body.as_str()))?;
+        let response: ValhallaResponse = serde_json::from_str(response.as_str())
+            .map_err(|err| format!("cannot parse valhalla response: '{}'", err))?;
+
+        let (durations, distances) = response
+            .sources_to_targets
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|cell| (cell.time, cell.distance.map(|distance| distance * 1000.)))
+                    .unzip::<_, _, Vec<_>, Vec<_>>()
+            })
+            .unzip();
+
+        Ok(Table { durations, distances })
+    }
+}
+
+impl RoutingProvider for TableRoutingProvider {
+    fn source(&self) -> String {
+        format!("{:?}:{}", self.api, self.url)
+    }
+
+    fn get_matrix(&self, profile: &Profile, locations: &[Location]) -> Result<Matrix, String> {
+        let coordinates = locations
+            .iter()
+            .map(|location| location.to_lat_lng())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| "routing service requires locations specified by coordinates".to_string())?;
+        let size = coordinates.len();
+
+        let mut travel_times = vec![0; size * size];
+        let mut distances = vec![0; size * size];
+        let mut error_codes = vec![0; size * size];
+
+        for (source_chunk, sources) in coordinates.chunks(self.chunk_size).enumerate() {
+            for (destination_chunk, destinations) in coordinates.chunks(self.chunk_size).enumerate() {
+                let table = self.get_table(profile, sources, destinations)?;
+
+                for (row, (durations, row_distances)) in table.durations.iter().zip(table.distances.iter()).enumerate()
+                {
+                    for (column, (duration, distance)) in durations.iter().zip(row_distances.iter()).enumerate() {
+                        let from = source_chunk * self.chunk_size + row;
+                        let to = destination_chunk * self.chunk_size + column;
+                        let idx = from * size + to;
+
+                        match (duration, distance) {
+                            (Some(duration), Some(distance)) => {
+                                travel_times[idx] = duration.round() as i64;
+                                distances[idx] = distance.round() as i64;
+                            }
+                            _ => {
+                                travel_times[idx] = -1;
+                                distances[idx] = -1;
+                                error_codes[idx] = 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let has_errors = error_codes.iter().any(|code| *code > 0);
+
+        Ok(Matrix {
+            profile: profile.name.clone(),
+            timestamp: None,
+            travel_times,
+            distances,
+            error_codes: if has_errors { Some(error_codes) } else { None },
+        })
+    }
+}
+
+/// Keeps durations and distances between sources and destinations, unreachable pairs are None.
+struct Table {
+    durations: Vec<Vec<Option<f64>>>,
+    distances: Vec<Vec<Option<f64>>>,
+}
+
+#[derive(Deserialize)]
+struct OsrmResponse {
+    code: String,
+    message: Option<String>,
+    durations: Option<Vec<Vec<Option<f64>>>>,
+    distances: Option<Vec<Vec<Option<f64>>>>,
+}
+
+#[derive(Deserialize)]
+struct ValhallaResponse {
+    sources_to_targets: Vec<Vec<ValhallaCell>>,
+}
+
+#[derive(Deserialize)]
+struct ValhallaCell {
+    time: Option<f64>,
+    distance: Option<f64>,
+}
@@ -3,57 +3,39 @@
 mod approx_transportation_test;
 
 use crate::json::Location;
-
-/// Gets approximated durations and distances.
-pub fn get_approx_transportation(locations: &Vec<Location>, speed: f64) -> (Vec<f64>, Vec<f64>) {
-    locations
+use vrp_core::models::problem::{ApproxRouting, DistanceMetric};
+
+/// Mean earth radius in meters used to project geocoordinates on plane.
+const EARTH_RADIUS: f64 = 6371000.;
+
+/// Gets approximated durations and distances using given routing settings. For planar metrics,
+/// geocoordinates are projected on plane, so distances are returned in meters for all metrics.
+/// Returns error if some of locations is not a geocoordinate.
+pub fn get_approx_transportation_with_routing(
+    locations: &Vec<Location>,
+    routing: &ApproxRouting,
+) -> Result<(Vec<f64>, Vec<f64>), String> {
+    let coordinates = locations
         .iter()
-        .flat_map(|l1| {
-            locations.iter().map(move |l2| {
-                let distance = get_distance(l1, l2);
-                let duration = distance / speed;
-                (duration, distance)
-            })
-        })
-        .collect::<Vec<_>>()
-        .into_iter()
-        .unzip()
-}
-
-/// Gets distance between two points using haversine formula.
-fn get_distance(p1: &Location, p2: &Location) -> f64 {
-    let d_lat = degree_rad(p1.lat - p2.lat);
-    let d_lng = degree_rad(p1.lng - p2.lng);
-
-    let lat1 = degree_rad(p1.lat);
-    let lat2 = degree_rad(p2.lat);
+        .map(|location| location.to_lat_lng())
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| "approximated routing requires locations specified by geocoordinates".to_string())?;
 
-    let a =
-        (d_lat / 2.).sin() * (d_lat / 2.).sin() + (d_lng / 2.).sin() * (d_lng / 2.).sin() * (lat1).cos() * (lat2).cos();
-    let c = 2. * a.sqrt().atan2((1. - a).sqrt());
+    let points = match routing.metric {
+        DistanceMetric::Haversine => coordinates,
+        DistanceMetric::Manhattan | DistanceMetric::Euclidean => project_on_plane(coordinates),
+    };
 
-    let radius = wgs84_earth_radius(d_lat);
-
-    radius * c
-}
-
-/// Converts degrees to radians.
-#[inline(always)]
-fn degree_rad(degrees: f64) -> f64 {
-    std::f64::consts::PI * degrees / 180.
+    Ok(routing.get_matrices(points.as_slice()))
 }
 
-#[inline(always)]
-fn wgs84_earth_radius(lat: f64) -> f64 {
-    // Semi-axes of WGS-84 geoidal reference
-    const WGS84_A: f64 = 6378137.0; // Major semiaxis [m]
-    const WGS84_B: f64 = 6356752.3; // Minor semiaxis [m]
+/// Projects geocoordinates on plane using equirectangular projection centered at mean latitude.
+fn project_on_plane(coordinates: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    let mean_lat = coordinates.iter().map(|(lat, _)| lat).sum::<f64>() / (coordinates.len().max(1) as f64);
+    let scale = mean_lat.to_radians().cos();
 
-    // http://en.wikipedia.org/wiki/Earth_radius
-    let an = WGS84_A * WGS84_A * lat.cos();
-    let bn = WGS84_B * WGS84_B * lat.sin();
-    let ad = WGS84_A * lat.cos();
-    let bd = WGS84_B * lat.sin();
-
-    ((an * an + bn * bn) / (ad * ad + bd * bd)).sqrt()
+    coordinates
+        .into_iter()
+        .map(|(lat, lng)| (EARTH_RADIUS * lat.to_radians(), EARTH_RADIUS * lng.to_radians() * scale))
+        .collect()
 }
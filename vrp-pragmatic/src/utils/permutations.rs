@@ -1,8 +1,10 @@
 extern crate rand;
 
+use rand::rngs::StdRng;
 use rand::seq::IteratorRandom;
 use std::collections::HashSet;
 use vrp_core::models::problem::JobPermutation;
+use vrp_core::utils::Random;
 
 #[cfg(test)]
 #[path = "../../tests/unit/utils/permutations_test.rs"]
@@ -22,8 +24,8 @@ impl VariableJobPermutation {
 }
 
 impl JobPermutation for VariableJobPermutation {
-    fn get(&self) -> Vec<Vec<usize>> {
-        get_split_permutations(self.size, self.split_start_index, self.sample_size)
+    fn get(&self, random: &dyn Random) -> Vec<Vec<usize>> {
+        get_split_permutations(self.size, self.split_start_index, self.sample_size, &mut random.get_rng())
     }
 
     fn validate(&self, permutation: &[usize]) -> bool {
@@ -66,26 +68,29 @@ impl Iterator for Permutations {
     }
 }
 
-fn generate_sample_permutations(start: usize, end: usize, sample_size: usize) -> Vec<Vec<usize>> {
-    let mut rng = rand::thread_rng();
-
+fn generate_sample_permutations(start: usize, end: usize, sample_size: usize, rng: &mut StdRng) -> Vec<Vec<usize>> {
     get_permutations(start, end)
-        .choose_multiple(&mut rng, sample_size)
+        .choose_multiple(rng, sample_size)
         .iter()
         .map(|permutation| permutation.iter().map(|i| *i).collect::<Vec<usize>>())
         .collect()
 }
 
-fn get_split_permutations(size: usize, split_start_index: usize, sample_size: usize) -> Vec<Vec<usize>> {
+fn get_split_permutations(
+    size: usize,
+    split_start_index: usize,
+    sample_size: usize,
+    rng: &mut StdRng,
+) -> Vec<Vec<usize>> {
     // TODO make it memory efficient somehow
 
     match split_start_index {
-        x if x == 0 || x == size => generate_sample_permutations(0, size - 1, sample_size),
+        x if x == 0 || x == size => generate_sample_permutations(0, size - 1, sample_size, rng),
         _ => {
             assert!(size > split_start_index);
 
-            let first = generate_sample_permutations(0, split_start_index - 1, sample_size);
-            let second = generate_sample_permutations(split_start_index, size - 1, sample_size);
+            let first = generate_sample_permutations(0, split_start_index - 1, sample_size, rng);
+            let second = generate_sample_permutations(split_start_index, size - 1, sample_size, rng);
 
             first
                 .iter()
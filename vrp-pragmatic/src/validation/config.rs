@@ -0,0 +1,39 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/config_test.rs"]
+mod config_test;
+
+use super::*;
+use vrp_solver::config::validate_config as validate_solver_config;
+
+/// Checks that solver configuration has valid parameters.
+fn check_e1700_invalid_solver_config(ctx: &ValidationContext) -> Result<(), FormatError> {
+    ctx.problem.config.as_ref().map_or(Ok(()), |config| {
+        validate_solver_config(&config.solver).map_err(|err| {
+            FormatError::new(
+                "E1700".to_string(),
+                "invalid solver configuration".to_string(),
+                format!("fix solver configuration: {}", err),
+            )
+        })
+    })
+}
+
+/// Checks that job neighbourhood configuration has valid parameters.
+fn check_e1701_invalid_neighbourhood_config(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let neighbourhood = ctx.problem.config.as_ref().and_then(|config| config.neighbourhood.as_ref());
+
+    if neighbourhood.iter().any(|neighbourhood| neighbourhood.max_neighbours == Some(0)) {
+        Err(FormatError::new(
+            "E1701".to_string(),
+            "invalid neighbourhood configuration".to_string(),
+            "specify max neighbours greater than zero".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates solver and job neighbourhood configuration.
+pub fn validate_config(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
+    combine_error_results(&[check_e1700_invalid_solver_config(ctx), check_e1701_invalid_neighbourhood_config(ctx)])
+}
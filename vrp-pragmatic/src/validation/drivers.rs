@@ -0,0 +1,43 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/drivers_test.rs"]
+mod drivers_test;
+
+use super::*;
+
+/// Checks that fleet has no drivers with duplicate ids.
+fn check_e1400_no_drivers_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
+    get_duplicates(ctx.drivers().map(|driver| &driver.id)).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1400".to_string(),
+            "duplicated driver ids".to_string(),
+            format!("remove duplicated driver ids: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that driver shift time is correct.
+fn check_e1401_driver_shift_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let driver_ids = ctx
+        .drivers()
+        .filter(|driver| {
+            let tws = driver.shifts.iter().map(|shift| shift.time.clone()).collect::<Vec<_>>();
+            !tws.is_empty() && !check_raw_time_windows(&tws, false)
+        })
+        .map(|driver| driver.id.to_string())
+        .collect::<Vec<_>>();
+
+    if driver_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1401".to_string(),
+            "invalid time in driver shift".to_string(),
+            format!("ensure that driver shift times conform time window rules, driver ids: {}", driver_ids.join(", ")),
+        ))
+    }
+}
+
+/// Validates drivers from the fleet.
+pub fn validate_drivers(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
+    combine_error_results(&[check_e1400_no_drivers_with_duplicate_ids(ctx), check_e1401_driver_shift_time(ctx)])
+}
@@ -4,6 +4,7 @@ mod jobs_test;
 
 use super::*;
 use crate::extensions::MultiDimensionalCapacity;
+use std::collections::HashSet;
 
 /// Checks that plan has no jobs with duplicate ids.
 fn check_e1100_no_jobs_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -140,6 +141,99 @@ fn check_e1105_empty_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
     }
 }
 
+/// Checks that soft time windows of job places have valid lateness parameters.
+fn check_e1106_correct_lateness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.lateness.as_ref())
+                .any(|lateness| lateness.max < 0. || lateness.cost < 0.)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1106".to_string(),
+            "invalid lateness in job places".to_string(),
+            format!("ensure that lateness max and cost are not negative, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that max ride time is used only with pickup and delivery jobs and it is not negative.
+/// A job with multiple pickups and multiple deliveries is rejected as pickup and delivery pairs
+/// are not defined for it.
+fn check_e1107_correct_max_ride_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            let get_size = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+            let (pickups, deliveries) = (get_size(&job.pickups), get_size(&job.deliveries));
+
+            matches!(job.max_ride_time, Some(max_ride_time)
+                if max_ride_time < 0. || pickups == 0 || deliveries == 0 || (pickups > 1 && deliveries > 1))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1107".to_string(),
+            "invalid max ride time in jobs".to_string(),
+            format!(
+                "ensure that max ride time is not negative and specified only for jobs with pickups and deliveries \
+                 where either pickup or delivery is single, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job skills defined by set operations are not empty and not contradictory.
+fn check_e1108_correct_skills(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| match &job.skills {
+            Some(JobSkills::Sets(sets)) => {
+                let lists = [&sets.all_of, &sets.one_of, &sets.none_of];
+                let is_empty = lists.iter().all(|list| list.is_none())
+                    || lists.iter().any(|list| matches!(list, Some(list) if list.is_empty()));
+
+                // NOTE oneOf skill can be forbidden by noneOf while other oneOf skills are still allowed
+                let forbidden = sets.none_of.iter().flatten().collect::<HashSet<_>>();
+                let is_contradictory = sets.all_of.iter().flatten().any(|skill| forbidden.contains(skill))
+                    || matches!(&sets.one_of, Some(one_of) if one_of.iter().all(|skill| forbidden.contains(skill)));
+
+                is_empty || is_contradictory
+            }
+            _ => false,
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid skills in jobs".to_string(),
+            format!(
+                "ensure that allOf, oneOf and noneOf skills are not empty, noneOf has no skills from allOf and \
+                 does not forbid all oneOf skills, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -149,5 +243,8 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1103_time_window_correctness(ctx),
         check_e1104_no_reserved_ids(ctx),
         check_e1105_empty_jobs(ctx),
+        check_e1106_correct_lateness(ctx),
+        check_e1107_correct_max_ride_time(ctx),
+        check_e1108_correct_skills(ctx),
     ])
 }
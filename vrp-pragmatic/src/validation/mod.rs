@@ -21,12 +21,18 @@ use self::objectives::validate_objectives;
 mod vehicles;
 use self::vehicles::validate_vehicles;
 
+mod drivers;
+use self::drivers::validate_drivers;
+
 mod relations;
 use self::relations::validate_relations;
 
 mod routing;
 use self::routing::validate_profiles;
 
+mod config;
+use self::config::validate_config;
+
 impl<'a> ValidationContext<'a> {
     /// Creates an instance of `ValidationContext`.
     pub fn new(problem: &'a Problem, matrices: Option<&'a Vec<Matrix>>) -> Self {
@@ -43,9 +49,11 @@ impl<'a> ValidationContext<'a> {
             .err()
             .into_iter()
             .chain(validate_vehicles(&self).err().into_iter())
+            .chain(validate_drivers(&self).err().into_iter())
             .chain(validate_objectives(&self).err().into_iter())
             .chain(validate_profiles(&self).err().into_iter())
             .chain(validate_relations(&self).err().into_iter())
+            .chain(validate_config(&self).err().into_iter())
             .flatten()
             .collect::<Vec<_>>();
 
@@ -66,6 +74,11 @@ impl<'a> ValidationContext<'a> {
         self.problem.fleet.vehicles.iter()
     }
 
+    /// Gets list of drivers from the problem.
+    fn drivers(&self) -> impl Iterator<Item = &Driver> {
+        self.problem.fleet.drivers.iter().flat_map(|drivers| drivers.iter())
+    }
+
     /// Gets a flat list of job tasks from the job.
     fn tasks(&self, job: &'a Job) -> Vec<&'a JobTask> {
         job.pickups
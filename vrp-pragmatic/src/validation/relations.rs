@@ -139,6 +139,48 @@ fn check_e1204_job_assigned_to_multiple_vehicles(relations: &Vec<Relation>) -> R
     }
 }
 
+/// Checks that relation jobs have the same compatibility when vehicle shift has no reloads.
+fn check_e1205_incompatible_jobs(
+    ctx: &ValidationContext,
+    relations: &Vec<Relation>,
+    vehicle_map: &HashMap<String, &VehicleType>,
+) -> Result<(), FormatError> {
+    let job_ids = relations
+        .iter()
+        .filter(|relation| {
+            let shift = vehicle_map
+                .get(&relation.vehicle_id)
+                .and_then(|v_type| v_type.shifts.get(relation.shift_index.unwrap_or(0)));
+
+            !matches!(shift.and_then(|shift| shift.reloads.as_ref()), Some(reloads) if !reloads.is_empty())
+        })
+        .filter(|relation| {
+            let compatibilities = relation
+                .jobs
+                .iter()
+                .filter_map(|job_id| ctx.job_index.get(job_id))
+                .filter_map(|job| job.compatibility.as_ref())
+                .collect::<HashSet<_>>();
+
+            compatibilities.len() > 1
+        })
+        .flat_map(|relation| relation.jobs.iter().filter(|job_id| !is_reserved_job_id(job_id)).cloned())
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1205".to_string(),
+            "relation has jobs with different compatibility".to_string(),
+            format!(
+                "remove incompatible jobs from relation or add reloads to vehicle shift, ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates relations in the plan.
 pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let vehicle_map = ctx
@@ -154,6 +196,7 @@ pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError
             check_e1202_empty_job_list(relations),
             check_e1203_no_multiple_places_times(ctx, relations),
             check_e1204_job_assigned_to_multiple_vehicles(relations),
+            check_e1205_incompatible_jobs(ctx, relations, &vehicle_map),
         ])
     } else {
         Ok(())
@@ -3,6 +3,7 @@
 mod routing_test;
 
 use super::*;
+use crate::json::coord_index::CoordIndex;
 
 /// Checks that no duplicated profile names specified.
 fn check_e1500_duplicated_profiles(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -28,7 +29,96 @@ fn check_e1501_empty_profiles(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
-/// Validates profiles from the fleet.
+/// Checks that geocoordinates and index references are not mixed.
+fn check_e1502_mixed_location_types(coord_index: &CoordIndex) -> Result<(), FormatError> {
+    match coord_index.get_used_types() {
+        (true, true) => Err(FormatError::new(
+            "E1502".to_string(),
+            "mixing different location types".to_string(),
+            "use either geocoordinates or routing matrix indices for all locations".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that routing matrix is provided when index references are used.
+fn check_e1503_no_matrix_with_index_locations(
+    ctx: &ValidationContext,
+    coord_index: &CoordIndex,
+) -> Result<(), FormatError> {
+    match (coord_index.get_used_types(), ctx.matrices) {
+        ((_, true), None) => Err(FormatError::new(
+            "E1503".to_string(),
+            "routing matrix is not specified for index locations".to_string(),
+            "specify routing matrix or use geocoordinates for locations".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that routing matrix has enough locations.
+fn check_e1504_matrix_is_too_small(ctx: &ValidationContext, coord_index: &CoordIndex) -> Result<(), FormatError> {
+    let max_index = coord_index.max_index();
+    let profiles = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .filter(|matrix| {
+            let size = (matrix.travel_times.len().min(matrix.distances.len()) as f64).sqrt() as usize;
+            max_index.iter().any(|&max_index| max_index >= size)
+        })
+        .map(|matrix| matrix.profile.clone())
+        .collect::<Vec<_>>();
+
+    if profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1504".to_string(),
+            "amount of locations does not match matrix dimension".to_string(),
+            format!(
+                "check matrix size for profiles '{}': it should have at least {} locations",
+                profiles.join(", "),
+                max_index.unwrap_or_default() + 1
+            ),
+        ))
+    }
+}
+
+/// Checks that approximated routing parameters are valid.
+fn check_e1505_invalid_approximation_parameters(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.speed.iter().any(|&speed| speed <= 0.) || profile.circuity.iter().any(|&circuity| circuity < 1.)
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1505".to_string(),
+            "invalid approximation parameters in profile".to_string(),
+            format!("check speed is positive and circuity is not less than one for profiles: '{}'", names.join(", ")),
+        ))
+    }
+}
+
+/// Validates profiles from the fleet and used locations.
 pub fn validate_profiles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
-    combine_error_results(&[check_e1500_duplicated_profiles(ctx), check_e1501_empty_profiles(ctx)])
+    let coord_index = CoordIndex::new(ctx.problem);
+
+    combine_error_results(&[
+        check_e1500_duplicated_profiles(ctx),
+        check_e1501_empty_profiles(ctx),
+        check_e1502_mixed_location_types(&coord_index),
+        check_e1503_no_matrix_with_index_locations(ctx, &coord_index),
+        check_e1504_matrix_is_too_small(ctx, &coord_index),
+        check_e1505_invalid_approximation_parameters(ctx),
+    ])
 }
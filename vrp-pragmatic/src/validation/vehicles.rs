@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/vehicles_test.rs"]
+mod vehicles_test;
+
 use super::*;
 use crate::validation::common::get_time_windows;
 use std::ops::Deref;
@@ -129,6 +133,62 @@ fn check_e1304_vehicle_reload_time_is_correct(ctx: &ValidationContext) -> Result
     }
 }
 
+/// Checks that vehicle time costs are not negative.
+fn check_e1305_vehicle_time_costs_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let costs = &vehicle.costs;
+            matches!(costs.waiting, Some(value) if value < 0.)
+                || matches!(costs.service, Some(value) if value < 0.)
+                || matches!(&costs.overtime, Some(overtime) if overtime.threshold < 0. || overtime.time < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1305".to_string(),
+            "invalid vehicle time costs".to_string(),
+            format!(
+                "ensure that waiting, service and overtime costs are not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle tour size limits are correct.
+fn check_e1306_vehicle_tour_size_limits_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| match &vehicle.limits {
+            Some(limits) => {
+                let max_jobs = limits.max_jobs.or(limits.max_activities);
+
+                matches!((limits.min_jobs, max_jobs), (Some(min_jobs), Some(max_jobs)) if min_jobs > max_jobs)
+            }
+            None => false,
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1306".to_string(),
+            "invalid vehicle tour size limits".to_string(),
+            format!(
+                "ensure that min jobs is not bigger than max jobs or max activities, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleShift, Option<TimeWindow>) -> bool>,
@@ -174,5 +234,7 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1302_vehicle_shift_time(ctx),
         check_e1303_vehicle_breaks_time_is_correct(ctx),
         check_e1304_vehicle_reload_time_is_correct(ctx),
+        check_e1305_vehicle_time_costs_are_correct(ctx),
+        check_e1306_vehicle_tour_size_limits_are_correct(ctx),
     ])
 }
@@ -24,6 +24,7 @@ fn can_assign_break_between_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -92,6 +93,7 @@ fn can_assign_break_between_jobs() {
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -14,7 +14,10 @@ fn can_assign_break_using_second_location() {
             vehicles: vec![VehicleType {
                 costs: create_default_vehicle_costs(),
                 shifts: vec![VehicleShift {
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![30., 0.].to_loc() }),
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: Some(vec![30., 0.].to_loc()),
+                    }),
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
                         duration: 2.0,
@@ -25,6 +28,7 @@ fn can_assign_break_using_second_location() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -93,6 +97,7 @@ fn can_assign_break_using_second_location() {
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -23,6 +23,7 @@ fn can_assign_interval_break_between_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -70,6 +71,7 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
+                                lateness: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -80,6 +82,7 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
+                                lateness: None,
                             }
                         ],
                     },
@@ -106,6 +109,7 @@ fn can_assign_interval_break_between_jobs() {
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -128,8 +132,11 @@ fn can_assign_interval_break_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![30., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: Some(vec![30., 0.].to_loc()),
+                    }),
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeOffset(vec![8., 12.]),
                         duration: 2.0,
@@ -146,6 +153,7 @@ fn can_assign_interval_break_with_reload() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -193,6 +201,7 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
+                                lateness: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -203,6 +212,7 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
+                                lateness: None,
                             }
                         ],
                     },
@@ -253,6 +263,7 @@ fn can_assign_interval_break_with_reload() {
                     duration: 69,
                     times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -30,6 +30,7 @@ fn can_use_two_breaks() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -93,6 +94,7 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
+                                lateness: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -103,6 +105,7 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
+                                lateness: None,
                             }
                         ],
                     },
@@ -121,6 +124,7 @@ fn can_use_two_breaks() {
                     duration: 204,
                     times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -27,6 +27,7 @@ fn get_solution(relation_type: RelationType, jobs: Vec<String>) -> Solution {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -108,6 +109,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -188,6 +190,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
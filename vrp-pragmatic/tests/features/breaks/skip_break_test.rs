@@ -14,10 +14,10 @@ fn can_skip_break_when_vehicle_not_used() {
             vehicles: vec![
                 VehicleType {
                     shifts: vec![VehicleShift {
-                        start: VehiclePlace { time: format_time(0.), location: vec![100., 0.].to_loc() },
+                        start: VehiclePlace { time: format_time(0.), location: Some(vec![100., 0.].to_loc()) },
                         end: Some(VehiclePlace {
                             time: format_time(1000.).to_string(),
-                            location: vec![100., 0.].to_loc(),
+                            location: Some(vec![100., 0.].to_loc()),
                         }),
                         breaks: Some(vec![VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
@@ -31,6 +31,7 @@ fn can_skip_break_when_vehicle_not_used() {
                 create_default_vehicle("vehicle_without_break"),
             ],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -91,6 +92,7 @@ fn can_skip_break_when_vehicle_not_used() {
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -118,6 +120,7 @@ fn can_skip_break_when_jobs_completed() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -170,6 +173,7 @@ fn can_skip_break_when_jobs_completed() {
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![UnassignedJob {
                 job_id: "my_vehicle_1_break".to_string(),
@@ -210,6 +214,7 @@ fn can_skip_second_break_when_jobs_completed() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -278,6 +283,7 @@ fn can_skip_second_break_when_jobs_completed() {
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -0,0 +1,126 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+use std::collections::{HashMap, HashSet};
+
+fn create_job_with_compatibility(id: &str, location: Vec<f64>, compatibility: &str) -> Job {
+    Job { compatibility: Some(compatibility.to_string()), ..create_delivery_job(id, location) }
+}
+
+fn create_test_jobs() -> Vec<Job> {
+    vec![
+        create_job_with_compatibility("job1", vec![1., 0.], "food"),
+        create_job_with_compatibility("job2", vec![2., 0.], "chemicals"),
+        create_job_with_compatibility("job3", vec![3., 0.], "food"),
+        create_job_with_compatibility("job4", vec![4., 0.], "chemicals"),
+    ]
+}
+
+/// Returns compatibility tags of jobs served between reloads.
+fn get_interval_compatibilities(problem: &Problem, tour: &Tour) -> Vec<HashSet<String>> {
+    let compatibilities = problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.compatibility.as_ref().map(|compatibility| (job.id.clone(), compatibility.clone())))
+        .collect::<HashMap<_, _>>();
+
+    tour.stops.iter().flat_map(|stop| stop.activities.iter()).fold(vec![HashSet::new()], |mut acc, activity| {
+        if activity.activity_type == "reload" {
+            acc.push(HashSet::new());
+        } else if let Some(compatibility) = compatibilities.get(&activity.job_id) {
+            acc.last_mut().unwrap().insert(compatibility.clone());
+        }
+
+        acc
+    })
+}
+
+#[test]
+fn can_split_incompatible_jobs_between_vehicles() {
+    let problem = Problem {
+        plan: Plan { jobs: create_test_jobs(), relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.unassigned.is_empty());
+    solution.tours.iter().for_each(|tour| {
+        let compatibilities = get_interval_compatibilities(&problem, tour);
+        assert_eq!(compatibilities.len(), 1);
+        assert_eq!(compatibilities.first().unwrap().len(), 1);
+    });
+}
+
+#[test]
+fn can_leave_incompatible_jobs_unassigned() {
+    let problem = Problem {
+        plan: Plan { jobs: create_test_jobs(), relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.len(), 2);
+    solution.unassigned.iter().for_each(|job| {
+        assert_eq!(
+            job.reasons,
+            vec![UnassignedJobReason {
+                code: 106,
+                description: "cannot be assigned due to compatibility constraint".to_string()
+            }]
+        );
+    });
+}
+
+#[test]
+fn can_serve_incompatible_jobs_between_reloads() {
+    let problem = Problem {
+        plan: Plan { jobs: create_test_jobs(), relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        times: None,
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.0,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_empty());
+    get_interval_compatibilities(&problem, solution.tours.first().unwrap())
+        .iter()
+        .for_each(|compatibilities| assert!(compatibilities.len() <= 1));
+}
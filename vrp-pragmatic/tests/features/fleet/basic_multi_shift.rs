@@ -17,15 +17,18 @@ fn can_use_multiple_times_from_vehicle_and_job() {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                        end: Some(VehiclePlace { time: format_time(99.).to_string(), location: vec![0., 0.].to_loc() }),
+                        start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                        end: Some(VehiclePlace {
+                            time: format_time(99.).to_string(),
+                            location: Some(vec![0., 0.].to_loc()),
+                        }),
                         ..create_default_vehicle_shift()
                     },
                     VehicleShift {
-                        start: VehiclePlace { time: format_time(100.), location: vec![0., 0.].to_loc() },
+                        start: VehiclePlace { time: format_time(100.), location: Some(vec![0., 0.].to_loc()) },
                         end: Some(VehiclePlace {
                             time: format_time(200.).to_string(),
-                            location: vec![0., 0.].to_loc(),
+                            location: Some(vec![0., 0.].to_loc()),
                         }),
                         ..create_default_vehicle_shift()
                     },
@@ -34,6 +37,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -87,6 +91,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_1".to_string(),
@@ -124,6 +129,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 }
             ],
             unassigned: vec![],
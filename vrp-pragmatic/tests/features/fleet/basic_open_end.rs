@@ -12,6 +12,7 @@ fn can_use_vehicle_with_open_end() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -56,6 +57,7 @@ fn can_use_vehicle_with_open_end() {
                     duration: 2,
                     times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -0,0 +1,106 @@
+use crate::format_time;
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+
+#[test]
+fn can_use_vehicle_with_open_start() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: VehiclePlace { time: format_time(0.), location: None },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 16.,
+                distance: 2,
+                duration: 4,
+                times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                driver_id: None,
+                shift_index: 0,
+                stops: vec![
+                    Stop {
+                        location: vec![2., 0.].to_loc(),
+                        time: Schedule {
+                            arrival: "1970-01-01T00:00:00Z".to_string(),
+                            departure: "1970-01-01T00:00:01Z".to_string(),
+                        },
+                        distance: 0,
+                        load: vec![1],
+                        activities: vec![
+                            Activity {
+                                job_id: "departure".to_string(),
+                                activity_type: "departure".to_string(),
+                                location: Some(vec![2., 0.].to_loc()),
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:00Z".to_string(),
+                                    end: "1970-01-01T00:00:00Z".to_string(),
+                                }),
+                                job_tag: None,
+                                lateness: None,
+                            },
+                            Activity {
+                                job_id: "job2".to_string(),
+                                activity_type: "delivery".to_string(),
+                                location: Some(vec![2., 0.].to_loc()),
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:00Z".to_string(),
+                                    end: "1970-01-01T00:00:01Z".to_string(),
+                                }),
+                                job_tag: None,
+                                lateness: None,
+                            },
+                        ],
+                    },
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:04Z", "1970-01-01T00:00:04Z"),
+                        2
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 16.,
+                    distance: 2,
+                    duration: 4,
+                    times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
@@ -1,4 +1,7 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod basic_open_start;
 mod multi_dimens;
+mod multi_driver;
+mod time_costs;
 mod unreachable_jobs;
@@ -19,6 +19,7 @@ fn can_use_two_dimensions() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -71,6 +72,7 @@ fn can_use_two_dimensions() {
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -89,6 +91,7 @@ fn can_unassign_due_to_dimension_mismatch() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -0,0 +1,117 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+
+fn create_problem_with_drivers(jobs: Vec<Job>, vehicle_ids: Vec<&str>, drivers: Vec<Driver>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { vehicle_ids: to_strings(vehicle_ids), ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+            drivers: Some(drivers),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_driver_skills() {
+    let problem = create_problem_with_drivers(
+        vec![create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique_skill".to_string()])],
+        vec!["my_vehicle_1"],
+        vec![
+            create_default_driver("driver_without_skill"),
+            Driver { skills: Some(vec!["unique_skill".to_string()]), ..create_default_driver("driver_with_skill") },
+        ],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 15.,
+                distance: 2,
+                duration: 3,
+                times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                driver_id: Some("driver_with_skill".to_string()),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                        2
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 15.,
+                    distance: 2,
+                    duration: 3,
+                    times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
+
+#[test]
+fn can_use_one_driver_with_one_vehicle_at_a_time() {
+    let problem = create_problem_with_drivers(
+        vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+        vec!["my_vehicle_1", "my_vehicle_2"],
+        vec![create_default_driver("driver1")],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().driver_id, Some("driver1".to_string()));
+}
+
+#[test]
+fn can_respect_driver_shift_time() {
+    let problem = create_problem_with_drivers(
+        vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![10., 0.])],
+        vec!["my_vehicle_1"],
+        vec![Driver {
+            shifts: vec![DriverShift { time: to_strings(vec!["1970-01-01T00:00:00Z", "1970-01-01T00:00:10Z"]) }],
+            ..create_default_driver("driver1")
+        }],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.first().unwrap().job_id, "job2");
+}
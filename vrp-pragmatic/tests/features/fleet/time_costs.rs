@@ -0,0 +1,69 @@
+use crate::helpers::*;
+use crate::json::problem::Objective::*;
+use crate::json::problem::*;
+
+fn solve_with_costs(costs: VehicleCosts) -> f64 {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { costs, ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    solve_with_metaheuristic(problem, Some(vec![matrix])).statistic.cost
+}
+
+#[test]
+fn can_use_separate_service_time_cost() {
+    let cost = solve_with_costs(VehicleCosts { service: Some(3.), ..create_default_vehicle_costs() });
+
+    assert_eq!(cost - solve_with_costs(create_default_vehicle_costs()), 2.);
+}
+
+#[test]
+fn can_add_overtime_cost_to_solution_cost() {
+    let cost = solve_with_costs(VehicleCosts {
+        overtime: Some(VehicleOvertimeCosts { threshold: 10., time: 2. }),
+        ..create_default_vehicle_costs()
+    });
+
+    assert_eq!(cost, 51. + 22.);
+}
+
+#[test]
+fn can_prefer_vehicle_without_overtime() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![-10., 0.])],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                costs: VehicleCosts {
+                    fixed: Some(1.),
+                    overtime: Some(VehicleOvertimeCosts { threshold: 30., time: 10. }),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs { goal: None }],
+            secondary: Some(vec![MinimizeCost { goal: None, tolerance: None }]),
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(solution.statistic.cost, 2. * (1. + 20. + 21.));
+}
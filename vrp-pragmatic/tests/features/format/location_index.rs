@@ -0,0 +1,79 @@
+use crate::format_time;
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::serialize_solution_as_geojson;
+use crate::json::Location;
+use std::io::BufWriter;
+
+fn create_job_with_index(id: &str, index: usize) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace { location: Location::new_reference(index), ..create_job_place(vec![0., 0.]) }],
+            demand: Some(vec![1]),
+            tag: None,
+        }]),
+        ..create_job(id)
+    }
+}
+
+#[test]
+fn can_use_location_index() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_index("job1", 0), create_job_with_index("job2", 1)],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: VehiclePlace { time: format_time(0.), location: Some(Location::new_reference(2)) },
+                    end: None,
+                    breaks: None,
+                    reloads: None,
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix(vec![0, 5, 1, 5, 0, 10, 1, 10, 0]);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 6);
+    assert_eq!(
+        solution.tours.first().unwrap().stops.iter().map(|stop| stop.location.clone()).collect::<Vec<_>>(),
+        vec![Location::new_reference(2), Location::new_reference(0), Location::new_reference(1)]
+    );
+    assert!(serialize_solution_as_geojson(BufWriter::new(vec![]), &solution).is_err());
+}
+
+#[test]
+fn can_detect_too_small_matrix_for_location_index() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_job_with_index("job1", 3)], relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: VehiclePlace { time: format_time(0.), location: Some(Location::new_reference(0)) },
+                    end: None,
+                    breaks: None,
+                    reloads: None,
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix(vec![0, 1, 1, 0]);
+
+    let result = (problem, vec![matrix]).read_pragmatic();
+
+    assert_eq!(result.err().map(|errors| errors.first().unwrap().code.clone()), Some("E1504".to_string()));
+}
@@ -0,0 +1 @@
+mod location_index;
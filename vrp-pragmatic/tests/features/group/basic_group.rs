@@ -0,0 +1,75 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+
+fn create_job_with_group(id: &str, location: Vec<f64>, group: &str) -> Job {
+    Job { group: Some(group.to_string()), ..create_delivery_job(id, location) }
+}
+
+fn create_test_fleet() -> Fleet {
+    Fleet {
+        vehicles: vec![VehicleType {
+            vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+            ..create_vehicle_with_capacity("my_vehicle", vec![2])
+        }],
+        profiles: create_default_profiles(),
+        drivers: None,
+    }
+}
+
+fn get_job_ids(tour: &Tour) -> Vec<String> {
+    tour.stops.iter().flat_map(|stop| stop.activities.iter()).map(|activity| activity.job_id.clone()).collect()
+}
+
+#[test]
+fn can_serve_group_in_one_tour() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_group("job1", vec![1., 0.], "g1"),
+                create_job_with_group("job2", vec![10., 0.], "g1"),
+                create_delivery_job("job3", vec![2., 0.]),
+                create_delivery_job("job4", vec![11., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: create_test_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+
+    assert!(solution.unassigned.is_empty());
+    let tour = solution.tours.iter().find(|tour| get_job_ids(tour).contains(&"job1".to_string())).unwrap();
+    assert!(get_job_ids(tour).contains(&"job2".to_string()));
+    assert_eq!(solve_and_check(problem, Some(vec![matrix])), Ok(()));
+}
+
+#[test]
+fn can_leave_whole_group_unassigned() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_group("job1", vec![1., 0.], "g1"),
+                create_job_with_group("job2", vec![2., 0.], "g1"),
+                create_job_with_group("job3", vec![3., 0.], "g1"),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: create_test_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(get_job_ids(solution.tours.first().unwrap()).contains(&"job4".to_string()));
+    assert_eq!(
+        solution.unassigned.iter().map(|job| job.job_id.clone()).collect::<Vec<_>>(),
+        vec!["job1".to_string(), "job2".to_string(), "job3".to_string()]
+    );
+    assert_eq!(solve_and_check(problem, Some(vec![matrix])), Ok(()));
+}
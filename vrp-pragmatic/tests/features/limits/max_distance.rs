@@ -8,10 +8,17 @@ fn can_limit_by_max_distance() {
         plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    shift_time: None,
+                    max_activities: None,
+                    max_jobs: None,
+                    min_jobs: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -1,2 +1,3 @@
 mod max_distance;
 mod shift_time;
+mod tour_size;
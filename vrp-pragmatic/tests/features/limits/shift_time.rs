@@ -8,10 +8,17 @@ fn can_limit_one_job_by_shift_time() {
         plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: None, shift_time: Some(99.) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: Some(99.),
+                    max_activities: None,
+                    max_jobs: None,
+                    min_jobs: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -62,10 +69,17 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: None, shift_time: Some(40.) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: Some(40.),
+                    max_activities: None,
+                    max_jobs: None,
+                    min_jobs: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -134,6 +148,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     duration: 36,
                     times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![
                 UnassignedJob {
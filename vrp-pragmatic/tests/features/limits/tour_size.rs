@@ -0,0 +1,154 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+
+fn create_vehicle_with_tour_size_limits(
+    vehicle_ids: Vec<&str>,
+    max_activities: Option<usize>,
+    max_jobs: Option<usize>,
+    min_jobs: Option<usize>,
+) -> VehicleType {
+    VehicleType {
+        vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+        limits: Some(VehicleLimits { max_distance: None, shift_time: None, max_activities, max_jobs, min_jobs }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn get_tour_size_reasons() -> Vec<UnassignedJobReason> {
+    vec![UnassignedJobReason {
+        code: 105,
+        description: "cannot be assigned due to tour size constraint of vehicle".to_string(),
+    }]
+}
+
+fn get_activity_count(tour: &Tour) -> usize {
+    tour.stops.iter().flat_map(|stop| stop.activities.iter()).filter(|a| a.job_id.starts_with("job")).count()
+}
+
+#[test]
+fn can_limit_by_max_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_tour_size_limits(vec!["my_vehicle_1"], None, Some(2), None)],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_activity_count(solution.tours.first().unwrap()), 2);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.first().unwrap().reasons, get_tour_size_reasons());
+}
+
+#[test]
+fn can_limit_by_max_activities() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.]),
+                create_delivery_job("job2", vec![3., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_tour_size_limits(vec!["my_vehicle_1"], Some(2), None, None)],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(get_activity_count(solution.tours.first().unwrap()) <= 2);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.first().unwrap().reasons, get_tour_size_reasons());
+}
+
+#[test]
+fn can_skip_tour_with_less_than_min_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_tour_size_limits(
+                vec!["my_vehicle_1", "my_vehicle_2"],
+                None,
+                Some(2),
+                Some(2),
+            )],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_activity_count(solution.tours.first().unwrap()), 2);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.first().unwrap().reasons, get_tour_size_reasons());
+}
+
+#[test]
+fn can_serve_jobs_of_tour_with_less_than_min_jobs_by_other_tour() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+                create_delivery_job("job5", vec![5., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_tour_size_limits(vec!["my_vehicle_1", "my_vehicle_2"], None, Some(3), Some(3)),
+                VehicleType {
+                    costs: VehicleCosts { fixed: Some(1.), ..create_default_vehicle_costs() },
+                    ..create_vehicle_with_capacity("other_vehicle", vec![2])
+                },
+            ],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.iter().map(get_activity_count).sum::<usize>(), 5);
+    assert!(solution
+        .tours
+        .iter()
+        .filter(|tour| tour.type_id == "my_vehicle")
+        .all(|tour| get_activity_count(tour) == 3));
+}
@@ -1,7 +1,10 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation and combination.
 
 mod breaks;
+mod compatibility;
 mod fleet;
+mod format;
+mod group;
 mod limits;
 mod multjob;
 mod pickdev;
@@ -9,5 +12,6 @@ mod priorities;
 mod relations;
 mod reload;
 mod skills;
+mod solver;
 mod timing;
 mod work_balance;
@@ -19,6 +19,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -98,6 +99,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -119,6 +121,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -190,6 +193,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     duration: 15,
                     times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -17,12 +17,16 @@ fn can_assign_service_job() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![4., 0.].to_loc() }),
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: Some(vec![4., 0.].to_loc()),
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -91,6 +95,7 @@ fn can_assign_service_job() {
                     duration: 7,
                     times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -23,6 +23,7 @@ fn can_handle_limited_capacity() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -121,6 +122,7 @@ fn can_handle_limited_capacity() {
                     duration: 42,
                     times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -16,6 +16,7 @@ fn can_use_only_deliveries_as_static_demand() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -70,6 +71,7 @@ fn can_use_only_deliveries_as_static_demand() {
                     duration: 11,
                     times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -87,12 +89,16 @@ fn can_use_only_pickups_as_static_demand() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![10., 0.].to_loc() }),
+                    end: Some(VehiclePlace {
+                        time: format_time(1000.).to_string(),
+                        location: Some(vec![10., 0.].to_loc()),
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -155,6 +161,7 @@ fn can_use_only_pickups_as_static_demand() {
                     duration: 13,
                     times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -16,6 +16,7 @@ fn can_unassign_multi_job_due_to_capacity() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -6,7 +6,11 @@ use crate::json::solution::*;
 fn can_use_one_pickup_delivery_job_with_one_vehicle() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.])], relations: None },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -66,6 +70,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     duration: 6,
                     times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -0,0 +1,72 @@
+use crate::checker::{check_ride_time, CheckerContext};
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+
+fn create_pickup_delivery_job_with_ride_time(
+    id: &str,
+    pickup: Vec<f64>,
+    delivery: Vec<f64>,
+    max_ride_time: Option<f64>,
+) -> Job {
+    Job { max_ride_time, ..create_pickup_delivery_job(id, pickup, delivery) }
+}
+
+#[test]
+fn can_deliver_job_directly_when_ride_time_is_limited() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job_with_ride_time("job1", vec![1., 0.], vec![3., 0.], Some(2.)),
+                create_pickup_delivery_job_with_ride_time("job2", vec![2., 0.], vec![4., 0.], None),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(check_ride_time(&CheckerContext::new(problem, Some(vec![matrix]), solution)), Ok(()));
+}
+
+#[test]
+fn can_skip_job_with_too_small_ride_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job_with_ride_time("job1", vec![1., 0.], vec![10., 0.], Some(5.)),
+                create_pickup_delivery_job_with_ride_time("job2", vec![2., 0.], vec![3., 0.], Some(5.)),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.unassigned,
+        vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 104,
+                description: "cannot be served due to max ride time constraint".to_string()
+            }]
+        }]
+    );
+}
@@ -13,7 +13,11 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
             ],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -88,7 +92,8 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     distance: 8,
                     duration: 12,
                     times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
-                }
+                },
+                driver_id: None
             }],
             unassigned: vec![],
             extras: None,
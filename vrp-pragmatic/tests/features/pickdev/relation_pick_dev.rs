@@ -23,6 +23,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -99,6 +100,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     duration: 54,
                     times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
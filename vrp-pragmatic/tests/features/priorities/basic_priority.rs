@@ -19,6 +19,7 @@ fn can_follow_priorities() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -87,6 +88,7 @@ fn can_follow_priorities() {
                     duration: 23,
                     times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
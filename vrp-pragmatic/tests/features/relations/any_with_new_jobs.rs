@@ -25,6 +25,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -85,6 +86,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                     duration: 6,
                     times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
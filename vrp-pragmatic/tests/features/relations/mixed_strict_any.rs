@@ -30,7 +30,11 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 },
             ]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -130,6 +134,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     duration: 25,
                     times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -30,7 +30,11 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 },
             ]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -130,6 +134,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     duration: 29,
                     times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -186,6 +191,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -255,6 +261,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -308,6 +315,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 }
             ],
             unassigned: vec![],
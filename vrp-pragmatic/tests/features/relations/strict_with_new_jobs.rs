@@ -40,6 +40,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -125,6 +126,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         duration: 27,
                         times: Timing { driving: 22, serving: 5, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -194,6 +196,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         duration: 25,
                         times: Timing { driving: 20, serving: 5, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 }
             ],
             unassigned: vec![],
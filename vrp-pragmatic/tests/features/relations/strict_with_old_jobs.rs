@@ -38,6 +38,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -115,6 +116,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         duration: 24,
                         times: Timing { driving: 20, serving: 4, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -176,6 +178,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         duration: 18,
                         times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 }
             ],
             unassigned: vec![],
@@ -20,8 +20,11 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: Some(vec![0., 0.].to_loc()),
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -34,6 +37,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -113,6 +117,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
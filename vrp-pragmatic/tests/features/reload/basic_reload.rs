@@ -34,8 +34,11 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: Some(vec![0., 0.].to_loc()),
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -48,6 +51,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -116,6 +120,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned,
             extras: None,
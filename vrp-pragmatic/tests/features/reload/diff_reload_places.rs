@@ -19,8 +19,8 @@ fn can_use_reloads_with_different_locations() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(1000.), location: vec![32., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                    end: Some(VehiclePlace { time: format_time(1000.), location: Some(vec![32., 0.].to_loc()) }),
                     breaks: None,
                     reloads: Some(vec![
                         VehicleReload {
@@ -41,6 +41,7 @@ fn can_use_reloads_with_different_locations() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -143,6 +144,7 @@ fn can_use_reloads_with_different_locations() {
                     duration: 47,
                     times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
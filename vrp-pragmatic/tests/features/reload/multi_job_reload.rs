@@ -1,5 +1,5 @@
-use crate::checker::solve_and_check;
 use crate::format_time;
+use crate::helpers::solve_and_check;
 use crate::helpers::*;
 use crate::json::problem::*;
 use crate::json::solution::*;
@@ -24,8 +24,11 @@ fn can_serve_multi_job_and_delivery_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![10., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: Some(vec![10., 0.].to_loc()),
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -38,6 +41,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -141,6 +145,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     duration: 22,
                     times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -165,21 +170,18 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts {
+                    fixed: Some(20.0),
+                    distance: 0.002,
+                    time: 0.003,
+                    waiting: None,
+                    service: None,
+                    overtime: None,
+                },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
-                        VehicleReload {
-                            times: None,
-                            location: Location { lat: 0.0, lng: 0.0 },
-                            duration: 2620.0,
-                            tag: None,
-                        },
-                        VehicleReload {
-                            times: None,
-                            location: Location { lat: 0.0, lng: 0.0 },
-                            duration: 2874.0,
-                            tag: None,
-                        },
+                        VehicleReload { times: None, location: Location::new(0.0, 0.0), duration: 2620.0, tag: None },
+                        VehicleReload { times: None, location: Location::new(0.0, 0.0), duration: 2874.0, tag: None },
                     ]),
                     ..create_default_vehicle_shift()
                 }],
@@ -187,6 +189,7 @@ fn can_properly_handle_load_without_capacity_violation() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
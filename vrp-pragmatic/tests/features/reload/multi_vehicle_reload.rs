@@ -14,8 +14,11 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: Some(vec![0., 0.].to_loc()),
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -28,6 +31,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -96,6 +100,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -19,8 +19,11 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
-                    end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![6., 0.].to_loc() }),
+                    start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
+                    end: Some(VehiclePlace {
+                        time: format_time(100.).to_string(),
+                        location: Some(vec![6., 0.].to_loc()),
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -33,6 +36,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -117,6 +121,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     duration: 12,
                     times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![UnassignedJob {
                 job_id: "d3".to_string(),
@@ -21,6 +21,7 @@ fn can_wait_for_job_start() {
                 },
             ],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -73,6 +74,7 @@ fn can_wait_for_job_start() {
                     duration: 19,
                     times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
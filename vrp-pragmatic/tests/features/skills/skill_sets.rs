@@ -0,0 +1,70 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+
+fn to_skills(skills: Option<Vec<&str>>) -> Option<Vec<String>> {
+    skills.map(|skills| skills.into_iter().map(|skill| skill.to_string()).collect())
+}
+
+fn create_vehicle_with_skills(id: &str, location: f64, skills: Vec<&str>) -> VehicleType {
+    VehicleType {
+        type_id: id.to_string(),
+        vehicle_ids: vec![format!("{}_1", id)],
+        shifts: vec![create_default_vehicle_shift_with_locations((location, 0.), (location, 0.))],
+        skills: to_skills(Some(skills)),
+        ..create_default_vehicle_type()
+    }
+}
+
+parameterized_test! {can_use_skill_sets, (all_of, one_of, none_of, expected), {
+    can_use_skill_sets_impl(all_of, one_of, none_of, expected);
+}}
+
+can_use_skill_sets! {
+    case01: (Some(vec!["s1", "s2"]), None, None, Some("vehicle_ab_1")),
+    case02: (None, Some(vec!["s2", "s3"]), None, Some("vehicle_ab_1")),
+    case03: (None, Some(vec!["s3"]), None, Some("vehicle_c_1")),
+    case04: (None, None, Some(vec!["s1"]), Some("vehicle_c_1")),
+    case05: (Some(vec!["s1"]), None, Some(vec!["s2"]), Some("vehicle_a_1")),
+    case06: (Some(vec!["s1"]), Some(vec!["s3"]), Some(vec!["s2"]), None),
+}
+
+fn can_use_skill_sets_impl(
+    all_of: Option<Vec<&str>>,
+    one_of: Option<Vec<&str>>,
+    none_of: Option<Vec<&str>>,
+    expected: Option<&str>,
+) {
+    let skills = JobSkills::Sets(JobSkillSets {
+        all_of: to_skills(all_of),
+        one_of: to_skills(one_of),
+        none_of: to_skills(none_of),
+    });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { skills: Some(skills), ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_skills("vehicle_a", 0., vec!["s1"]),
+                create_vehicle_with_skills("vehicle_ab", 10., vec!["s1", "s2"]),
+                create_vehicle_with_skills("vehicle_c", 20., vec!["s3"]),
+            ],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if let Some(vehicle_id) = expected {
+        assert_eq!(solution.tours.len(), 1);
+        assert_eq!(solution.tours.first().unwrap().vehicle_id, vehicle_id);
+        assert!(solution.unassigned.is_empty());
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.len(), 1);
+    }
+}
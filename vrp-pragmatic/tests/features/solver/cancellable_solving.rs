@@ -0,0 +1,58 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use vrp_solver::{Progress, SolverBuilder};
+
+parameterized_test! {can_cancel_solving_using_progress_callback, islands, {
+    can_cancel_solving_using_progress_callback_impl(islands);
+}}
+
+can_cancel_solving_using_progress_callback! {
+    case01: None,
+    case02: Some((2, 5)),
+}
+
+fn can_cancel_solving_using_progress_callback_impl(islands: Option<(usize, usize)>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=8).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    let reports = Arc::new(Mutex::new(vec![]));
+
+    let solution = {
+        let is_cancelled = is_cancelled.clone();
+        let reports = reports.clone();
+        solve_with_solver_builder(
+            problem,
+            Some(vec![matrix]),
+            SolverBuilder::default()
+                .with_max_generations(Some(1000))
+                .with_islands(islands)
+                .with_cancellation(Some(is_cancelled.clone()))
+                .with_progress(Some(Box::new(move |progress: &Progress| {
+                    reports.lock().unwrap().push((progress.generation, progress.tours, progress.unassigned));
+                    if progress.generation >= 5 {
+                        is_cancelled.store(true, Ordering::Relaxed);
+                    }
+                }))),
+        )
+    };
+
+    let reports = reports.lock().unwrap();
+    assert!(is_cancelled.load(Ordering::Relaxed));
+    assert!(reports.last().unwrap().0 < 20);
+    assert_eq!(reports.last().unwrap().1, solution.tours.len());
+    assert_eq!(reports.last().unwrap().2, solution.unassigned.len());
+    assert!(solution.unassigned.is_empty());
+}
@@ -0,0 +1,88 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+
+fn create_test_problem(config: &str) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (1..=8).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        config: Some(serde_json::from_str(config).unwrap()),
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_solve_problem_with_config, config, {
+    can_solve_problem_with_config_impl(config);
+}}
+
+can_solve_problem_with_config! {
+    case01: r#"{ "termination": { "maxGenerations": 10 } }"#,
+    case02: r#"{
+        "population": { "size": 2, "selection": "best" },
+        "acceptance": { "type": "annealing", "initialTemperature": 0.1 },
+        "termination": { "maxGenerations": 10 }
+    }"#,
+    case03: r#"{
+        "mutation": {
+            "ruins": [
+                {
+                    "name": "strings",
+                    "weight": 10,
+                    "methods": [
+                        { "type": "adjusted-string", "probability": 1, "lmax": 10, "cavg": 5, "alpha": 0.01 },
+                        { "type": "random-route", "probability": 0.1, "min": 1, "max": 2, "threshold": 0.2 }
+                    ]
+                },
+                {
+                    "weight": 5,
+                    "methods": [
+                        { "type": "neighbour", "probability": 1, "min": 1, "max": 4, "threshold": 0.5 },
+                        { "type": "random-job", "probability": 0.5, "min": 1, "max": 2, "threshold": 0.2 },
+                        { "type": "worst-job", "probability": 0.5, "threshold": 8, "skip": 2, "min": 1, "max": 2 }
+                    ]
+                }
+            ],
+            "recreates": [
+                { "type": "cheapest", "weight": 10 },
+                { "type": "regret", "weight": 5, "start": 2, "end": 3 },
+                { "type": "blinks", "weight": 5 },
+                { "type": "gaps", "weight": 1, "min": 2 },
+                { "type": "nearest-neighbor", "weight": 1 }
+            ],
+            "adaptive": { "segmentSize": 5 }
+        },
+        "acceptance": { "type": "record", "deviation": 0.05 },
+        "termination": { "maxGenerations": 10 }
+    }"#,
+    case04: r#"{
+        "mutation": { "localSearch": "after-recreate" },
+        "acceptance": { "type": "threshold" },
+        "termination": { "maxGenerations": 10 },
+        "islands": { "amount": 2, "migrationInterval": 5 }
+    }"#,
+}
+
+fn can_solve_problem_with_config_impl(config: &str) {
+    let problem = create_test_problem(config);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_problem_config(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.iter().map(|tour| tour.stops.len() - 2).sum::<usize>(), 8);
+}
+
+#[test]
+fn can_keep_config_when_problem_is_serialized() {
+    let problem = create_test_problem(r#"{ "acceptance": { "type": "greedy" }, "termination": { "maxTime": 1 } }"#);
+
+    let json = serde_json::to_string(&problem).unwrap();
+
+    assert!(json.contains(r#""config":{"acceptance":{"type":"greedy"},"termination":{"maxTime":1.0}}"#));
+}
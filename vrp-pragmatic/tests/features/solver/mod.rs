@@ -0,0 +1,4 @@
+mod cancellable_solving;
+mod configurable_solving;
+mod performance_solving;
+mod reproducible_solving;
@@ -0,0 +1,53 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::create_solution;
+use std::sync::Arc;
+use vrp_solver::SolverBuilder;
+
+parameterized_test! {can_write_performance_into_extras, (telemetry, expected), {
+    can_write_performance_into_extras_impl(telemetry, expected);
+}}
+
+can_write_performance_into_extras! {
+    case01: (None, None),
+    case02: (Some(5), Some(vec![5, 10, 15, 20])),
+    case03: (Some(10), Some(vec![10, 20])),
+    case04: (Some(7), Some(vec![7, 14, 20])),
+}
+
+fn can_write_performance_into_extras_impl(telemetry: Option<usize>, expected: Option<Vec<i32>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=4).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().ok().unwrap());
+
+    let (solution, _, _) = SolverBuilder::default()
+        .with_max_generations(Some(20))
+        .with_telemetry(telemetry)
+        .build()
+        .solve(problem.clone())
+        .unwrap();
+
+    assert!(solution.extras.contains_key("coord_index"));
+    let solution = create_solution(problem.as_ref(), &solution);
+
+    let performance = solution.extras.map(|extras| extras.performance);
+    assert_eq!(
+        performance.as_ref().map(|performance| performance.iter().map(|iteration| iteration.number).collect()),
+        expected
+    );
+    if let Some(performance) = performance {
+        assert!(performance.iter().all(|iteration| iteration.tours == 1 && iteration.unassigned == 0));
+    }
+}
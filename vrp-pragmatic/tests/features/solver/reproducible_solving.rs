@@ -0,0 +1,47 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use vrp_solver::SolverBuilder;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (1..=8).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_get_same_solution_with_same_seed, islands, {
+    can_get_same_solution_with_same_seed_impl(islands);
+}}
+
+can_get_same_solution_with_same_seed! {
+    case01: None,
+    case02: Some((2, 5)),
+    case03: Some((4, 1)),
+}
+
+fn can_get_same_solution_with_same_seed_impl(islands: Option<(usize, usize)>) {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solutions = (0..2)
+        .map(|_| {
+            solve_with_solver_builder(
+                problem.clone(),
+                Some(vec![matrix.clone()]),
+                SolverBuilder::default().with_max_generations(Some(20)).with_seed(Some(42)).with_islands(islands),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert!(solutions[0].unassigned.is_empty());
+    assert_eq!(solutions[0].tours.iter().map(|tour| tour.stops.len() - 2).sum::<usize>(), 8);
+    assert_eq!(solutions[0], solutions[1]);
+}
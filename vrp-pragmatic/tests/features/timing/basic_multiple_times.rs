@@ -15,7 +15,11 @@ fn can_use_multiple_times() {
             ],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -99,6 +103,7 @@ fn can_use_multiple_times() {
                     duration: 130,
                     times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
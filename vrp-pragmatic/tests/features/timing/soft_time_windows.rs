@@ -0,0 +1,111 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+
+fn create_delivery_job_with_lateness(id: &str, location: Vec<f64>, end: i32, max: f64, cost: f64) -> Job {
+    let mut job = create_delivery_job_with_times(id, location, vec![(0, end)], 0.);
+    job.deliveries.as_mut().unwrap()[0].places[0].lateness = Some(JobPlaceLateness { max, cost });
+
+    job
+}
+
+fn create_problem_with_lateness_cost(cost: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 10)], 0.),
+                create_delivery_job_with_lateness("job2", vec![20., 0.], 5, 20., cost),
+                create_delivery_job_with_lateness("job3", vec![30., 0.], 5, 10., cost),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_lateness(solution: &Solution, job_id: &str) -> Option<i32> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .find(|activity| activity.job_id == job_id)
+        .unwrap()
+        .lateness
+}
+
+#[test]
+fn can_serve_job_late_within_soft_time_window() {
+    let problem = create_problem_with_lateness_cost(3.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_lateness(&solution, "job1"), None);
+    assert_eq!(get_lateness(&solution, "job2"), Some(15));
+    assert_eq!(
+        solution.unassigned,
+        vec![UnassignedJob {
+            job_id: "job3".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 2,
+                description: "cannot be visited within time window".to_string()
+            }]
+        }]
+    );
+}
+
+#[test]
+fn can_add_lateness_cost_to_solution_cost() {
+    let get_cost = |lateness_cost: f64| {
+        let problem = create_problem_with_lateness_cost(lateness_cost);
+        let matrix = create_matrix_from_problem(&problem);
+
+        solve_with_metaheuristic(problem, Some(vec![matrix])).statistic.cost
+    };
+
+    assert_eq!(get_cost(3.) - get_cost(0.), 45.);
+}
+
+#[test]
+fn can_avoid_lateness_caused_by_other_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_lateness("job1", vec![10., 0.], 10, 100., 10.),
+                create_delivery_job_with_duration("job2", vec![5., 0.], 5.),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(get_lateness(&solution, "job1"), None);
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .map(|a| a.job_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["departure", "job1", "job2"]
+    );
+}
@@ -15,7 +15,11 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
             ],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -91,6 +95,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     duration: 80,
                     times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![UnassignedJob {
                 job_id: "job5".to_string(),
@@ -21,6 +21,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -90,6 +91,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         duration: 140,
                         times: Timing { driving: 100, serving: 30, waiting: 10, break_time: 0 },
                     },
+                    driver_id: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -135,6 +137,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         duration: 60,
                         times: Timing { driving: 40, serving: 20, waiting: 0, break_time: 0 },
                     },
+                    driver_id: None,
                 },
             ],
             unassigned: vec![],
@@ -57,6 +57,7 @@ fn can_balance_activities_with_tolerance_and_threshold_impl(
                 },
             ],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         objectives: Some(Objectives {
             primary: vec![BalanceActivities { threshold, tolerance }],
@@ -8,7 +8,7 @@ use crate::{format_time, parse_time};
 pub const START_DAY: &str = "2020-07-04T00:00:00Z";
 
 pub const DEFAULT_BOUNDING_BOX: (Location, Location) =
-    (Location { lat: 52.4240, lng: 13.2148 }, Location { lat: 52.5937, lng: 13.5970 });
+    (Location::Coordinate { lat: 52.4240, lng: 13.2148 }, Location::Coordinate { lat: 52.5937, lng: 13.5970 });
 
 pub fn default_time_plus_offset(offset: i32) -> String {
     format_time(parse_time(&START_DAY.to_string()) + from_hours(offset).as_secs_f64())
@@ -36,7 +36,7 @@ pub fn default_delivery_prototype() -> impl Strategy<Value = Job> {
     delivery_job_prototype(
         job_task_prototype(default_job_place_prototype(), generate_simple_demand(1..5), generate_no_tags()),
         generate_no_priority(),
-        generate_no_skills(),
+        generate_no_job_skills(),
     )
 }
 
@@ -44,7 +44,7 @@ pub fn default_pickup_prototype() -> impl Strategy<Value = Job> {
     pickup_job_prototype(
         job_task_prototype(default_job_place_prototype(), generate_simple_demand(1..5), generate_no_tags()),
         generate_no_priority(),
-        generate_no_skills(),
+        generate_no_job_skills(),
     )
 }
 
@@ -54,7 +54,7 @@ pub fn default_pickup_delivery_prototype() -> impl Strategy<Value = Job> {
         default_job_place_prototype(),
         generate_simple_demand(1..4),
         generate_no_priority(),
-        generate_no_skills(),
+        generate_no_job_skills(),
     )
 }
 
@@ -64,16 +64,16 @@ pub fn default_job_prototype() -> impl Strategy<Value = Job> {
 
 pub fn default_costs_prototype() -> impl Strategy<Value = VehicleCosts> {
     from_costs(vec![
-        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003 },
-        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005 },
+        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003, waiting: None, service: None, overtime: None },
+        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005, waiting: None, service: None, overtime: None },
     ])
 }
 
 pub fn default_vehicle_places_prototype() -> impl Strategy<Value = (VehiclePlace, Option<VehiclePlace>)> {
     generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
         Just((
-            VehiclePlace { time: default_time_plus_offset(9), location: location.clone() },
-            Some(VehiclePlace { time: default_time_plus_offset(18), location }),
+            VehiclePlace { time: default_time_plus_offset(9), location: Some(location.clone()) },
+            Some(VehiclePlace { time: default_time_plus_offset(18), location: Some(location) }),
         ))
     })
 }
@@ -87,7 +87,13 @@ pub fn default_breaks_prototype() -> impl Strategy<Value = Option<Vec<VehicleBre
 }
 
 pub fn default_profiles() -> impl Strategy<Value = Vec<Profile>> {
-    Just(vec![Profile { name: "car".to_string(), profile_type: "car".to_string() }])
+    Just(vec![Profile {
+        name: "car".to_string(),
+        profile_type: "car".to_string(),
+        speed: None,
+        circuity: None,
+        metric: None,
+    }])
 }
 
 pub fn default_vehicle_shifts() -> impl Strategy<Value = Vec<VehicleShift>> {
@@ -8,7 +8,7 @@ use uuid::Uuid;
 pub fn delivery_job_prototype(
     task_proto: impl Strategy<Value = JobTask>,
     priority_proto: impl Strategy<Value = Option<i32>>,
-    skills_proto: impl Strategy<Value = Option<Vec<String>>>,
+    skills_proto: impl Strategy<Value = Option<JobSkills>>,
 ) -> impl Strategy<Value = Job> {
     job_prototype(
         generate_no_job_tasks(),
@@ -24,7 +24,7 @@ pub fn delivery_job_prototype(
 pub fn pickup_job_prototype(
     task_proto: impl Strategy<Value = JobTask>,
     priority_proto: impl Strategy<Value = Option<i32>>,
-    skills_proto: impl Strategy<Value = Option<Vec<String>>>,
+    skills_proto: impl Strategy<Value = Option<JobSkills>>,
 ) -> impl Strategy<Value = Job> {
     job_prototype(
         task_proto.prop_map(|p| Some(vec![p])),
@@ -42,7 +42,7 @@ prop_compose! {
         delivery_place: impl Strategy<Value = JobPlace>,
         demand_proto: impl Strategy<Value = Option<Vec<i32>>>,
         priority_proto: impl Strategy<Value = Option<i32>>,
-        skills_proto: impl Strategy<Value = Option<Vec<String>>>
+        skills_proto: impl Strategy<Value = Option<JobSkills>>
     )
     (
      pickup in pickup_place,
@@ -62,11 +62,38 @@ prop_compose! {
             replacements: None,
             services: None,
             priority,
-            skills,
+            skills, max_ride_time: None, compatibility: None, group: None,
         }
     }
 }
 
+prop_compose! {
+    /// Generates job skills defined by set operations.
+    pub fn generate_job_skills(
+        all_of_proto: impl Strategy<Value = Option<Vec<String>>>,
+        one_of_proto: impl Strategy<Value = Option<Vec<String>>>,
+        none_of_proto: impl Strategy<Value = Option<Vec<String>>>,
+    )
+    (
+     all_of in all_of_proto,
+     one_of in one_of_proto,
+     none_of in none_of_proto
+    ) -> Option<JobSkills> {
+        if all_of.is_none() && one_of.is_none() && none_of.is_none() {
+            None
+        } else {
+            Some(JobSkills::Sets(JobSkillSets { all_of, one_of, none_of }))
+        }
+    }
+}
+
+prop_compose! {
+    /// Generates no job skills.
+    pub fn generate_no_job_skills()(_ in ".*") -> Option<JobSkills> {
+        None
+    }
+}
+
 /// Generates jobs.
 pub fn generate_jobs(job_proto: impl Strategy<Value = Job>, range: Range<usize>) -> impl Strategy<Value = Vec<Job>> {
     prop::collection::vec(job_proto, range)
@@ -84,7 +111,7 @@ prop_compose! {
         replacements_proto: impl Strategy<Value = Option<Vec<JobTask>>>,
         services_proto: impl Strategy<Value = Option<Vec<JobTask>>>,
         priority_proto: impl Strategy<Value = Option<i32>>,
-        skills_proto: impl Strategy<Value = Option<Vec<String>>>,
+        skills_proto: impl Strategy<Value = Option<JobSkills>>,
     )
     (
      pickups in pickups_proto,
@@ -101,7 +128,7 @@ prop_compose! {
             replacements,
             services,
             priority,
-            skills,
+            skills, max_ride_time: None, compatibility: None, group: None,
         }
     }
 }
@@ -132,7 +159,7 @@ prop_compose! {
      duration in durations,
      times in time_windows
     ) -> JobPlace {
-      JobPlace { times, location, duration}
+      JobPlace { times, location, duration, lateness: None}
     }
 }
 
@@ -122,7 +122,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles }
+        Fleet { vehicles, profiles, drivers: None }
     }
 }
 
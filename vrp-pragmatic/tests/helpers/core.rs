@@ -6,8 +6,14 @@ use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 use vrp_core::models::solution::*;
 
-const DEFAULT_VEHICLE_COSTS: Costs =
-    Costs { fixed: 100.0, per_distance: 1.0, per_driving_time: 1.0, per_waiting_time: 1.0, per_service_time: 1.0 };
+const DEFAULT_VEHICLE_COSTS: Costs = Costs {
+    fixed: 100.0,
+    per_distance: 1.0,
+    per_driving_time: 1.0,
+    per_waiting_time: 1.0,
+    per_service_time: 1.0,
+    overtime: None,
+};
 pub const DEFAULT_JOB_LOCATION: Location = 0;
 pub const DEFAULT_JOB_DURATION: Duration = 0.0;
 pub const DEFAULT_JOB_TIME_SPAN: TimeSpan = TimeSpan::Window(TimeWindow { start: 0., end: 1000. });
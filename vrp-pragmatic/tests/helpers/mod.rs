@@ -26,6 +26,9 @@ pub use self::solver::*;
 pub mod problem;
 pub use self::problem::*;
 
+pub mod routing;
+pub use self::routing::*;
+
 pub mod solution;
 pub use self::solution::*;
 use crate::json::Location;
@@ -6,7 +6,7 @@ use vrp_core::models::common::{Distance, Duration, Location, Timestamp};
 use vrp_core::models::problem::TransportCost;
 
 pub fn create_job_place(location: Vec<f64>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1. }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., lateness: None }
 }
 
 pub fn create_task(location: Vec<f64>) -> JobTask {
@@ -22,6 +22,9 @@ pub fn create_job(id: &str) -> Job {
         services: None,
         priority: None,
         skills: None,
+        max_ride_time: None,
+        compatibility: None,
+        group: None,
     }
 }
 
@@ -34,7 +37,7 @@ pub fn create_delivery_job_with_priority(id: &str, location: Vec<f64>, priority:
 }
 
 pub fn create_delivery_job_with_skills(id: &str, location: Vec<f64>, skills: Vec<String>) -> Job {
-    Job { skills: Some(skills), ..create_delivery_job(id, location) }
+    Job { skills: Some(JobSkills::List(skills)), ..create_delivery_job(id, location) }
 }
 
 pub fn create_delivery_job_with_demand(id: &str, location: Vec<f64>, demand: Vec<i32>) -> Job {
@@ -149,7 +152,7 @@ pub fn create_default_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
+        start: VehiclePlace { time: format_time(0.), location: Some(vec![0., 0.].to_loc()) },
         end: None,
         breaks: None,
         reloads: None,
@@ -158,15 +161,15 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: VehiclePlace { time: format_time(0.), location: vec![start.0, start.1].to_loc() },
-        end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![end.0, end.1].to_loc() }),
+        start: VehiclePlace { time: format_time(0.), location: Some(vec![start.0, start.1].to_loc()) },
+        end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: Some(vec![end.0, end.1].to_loc()) }),
         breaks: None,
         reloads: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., waiting: None, service: None, overtime: None }
 }
 
 pub fn create_default_vehicle_type() -> VehicleType {
@@ -190,14 +193,29 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
     }
 }
 
+pub fn create_default_driver(id: &str) -> Driver {
+    Driver {
+        id: id.to_string(),
+        costs: DriverCosts { fixed: None, distance: 0., time: 0. },
+        shifts: vec![],
+        skills: None,
+    }
+}
+
 pub fn create_default_profiles() -> Vec<Profile> {
-    vec![Profile { name: "car".to_string(), profile_type: "car".to_string() }]
+    vec![Profile {
+        name: "car".to_string(),
+        profile_type: "car".to_string(),
+        speed: None,
+        circuity: None,
+        metric: None,
+    }]
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: Plan { jobs: vec![], relations: None },
-        fleet: Fleet { vehicles: vec![], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], drivers: None },
         objectives: None,
         config: None,
     }
@@ -224,7 +242,11 @@ pub fn create_matrix_from_problem(problem: &Problem) -> Matrix {
         .iter()
         .cloned()
         .flat_map(|a| {
-            unique.iter().map(move |b| ((a.lat - b.lat).powf(2.) + (a.lng - b.lng).powf(2.)).sqrt().round() as i64)
+            let (a_lat, a_lng) = a.to_lat_lng().unwrap();
+            unique.iter().map(move |b| {
+                let (b_lat, b_lng) = b.to_lat_lng().unwrap();
+                ((a_lat - b_lat).powf(2.) + (a_lng - b_lng).powf(2.)).sqrt().round() as i64
+            })
         })
         .collect();
 
@@ -57,6 +57,7 @@ fn create_stop_with_activity_impl(
             location: None,
             time: None,
             job_tag,
+            lateness: None,
         }],
     }
 }
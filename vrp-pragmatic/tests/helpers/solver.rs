@@ -1,3 +1,5 @@
+use crate::checker::*;
+use crate::get_solver_config;
 use crate::json::problem::{Matrix, PragmaticProblem, Problem};
 use crate::json::solution::{create_solution, Solution};
 use std::cmp::Ordering::Less;
@@ -44,6 +46,58 @@ pub fn solve_with_metaheuristic_and_iterations(
     sort_all_data(create_solution(problem.as_ref(), &solution))
 }
 
+/// Runs solver configured by given builder.
+pub fn solve_with_solver_builder(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    builder: &mut SolverBuilder,
+) -> Solution {
+    let problem = get_core_problem(problem, matrices);
+
+    let (solution, _, _) = builder.build().solve(problem.clone()).unwrap();
+
+    sort_all_data(create_solution(problem.as_ref(), &solution))
+}
+
+/// Solves problem with default metaheuristic and creates checker context from its solution.
+pub fn create_checker_context(problem: Problem, matrices: Option<Vec<Matrix>>) -> CheckerContext {
+    let solution = solve_with_metaheuristic_and_iterations(problem.clone(), matrices.clone(), 10);
+
+    CheckerContext::new(problem, matrices, solution)
+}
+
+/// Solves problem and checks results.
+pub fn solve_and_check(problem: Problem, matrices: Option<Vec<Matrix>>) -> Result<(), Vec<String>> {
+    let ctx = create_checker_context(problem, matrices);
+
+    check_vehicle_load(&ctx)?;
+    check_relations(&ctx)?;
+    check_ride_time(&ctx)?;
+    check_groups(&ctx)?;
+    // TODO break is soft constraint and can be violated, how to improve checker?
+    // check_breaks(&ctx)?;
+    check_assignment(&ctx)?;
+    check_time_windows(&ctx)?;
+    check_skills(&ctx)?;
+    check_limits(&ctx)?;
+    check_routing(&ctx)?;
+
+    Ok(())
+}
+
+/// Runs solver configured by problem's config.
+pub fn solve_with_problem_config(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let problem = get_core_problem(problem, matrices);
+
+    let (solution, _, _) = SolverBuilder::default()
+        .with_config(get_solver_config(problem.as_ref()))
+        .build()
+        .solve(problem.clone())
+        .unwrap();
+
+    sort_all_data(create_solution(problem.as_ref(), &solution))
+}
+
 fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
     Arc::new(
         if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
@@ -1,5 +1,5 @@
-use crate::checker::solve_and_check;
 use crate::generator::*;
+use crate::helpers::solve_and_check;
 use crate::json::problem::*;
 
 use crate::json::Location;
@@ -30,7 +30,7 @@ fn job_prototype() -> impl Strategy<Value = Job> {
             generate_no_tags(),
         ),
         generate_no_priority(),
-        generate_no_skills(),
+        generate_no_job_skills(),
     )
 }
 
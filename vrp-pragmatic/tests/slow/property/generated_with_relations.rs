@@ -1,5 +1,5 @@
-use crate::checker::*;
 use crate::generator::*;
+use crate::helpers::solve_and_check;
 use crate::json::problem::*;
 
 use proptest::prelude::*;
@@ -29,7 +29,7 @@ fn relation_job_prototype() -> impl Strategy<Value = Job> {
             generate_no_tags(),
         ),
         generate_no_priority(),
-        generate_no_skills(),
+        generate_no_job_skills(),
     )
 }
 
@@ -1,5 +1,5 @@
-use crate::checker::solve_and_check;
 use crate::generator::*;
+use crate::helpers::solve_and_check;
 use crate::json::problem::*;
 
 use proptest::prelude::*;
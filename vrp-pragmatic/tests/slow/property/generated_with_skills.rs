@@ -0,0 +1,63 @@
+use crate::generator::*;
+use crate::helpers::solve_and_check;
+use crate::json::problem::*;
+
+use proptest::prelude::*;
+
+fn get_skills(skills: Vec<&str>) -> impl Strategy<Value = Option<Vec<String>>> {
+    let skills = skills.into_iter().map(|skill| skill.to_string()).collect::<Vec<_>>();
+    prop_oneof![Just(None), Just(Some(skills))]
+}
+
+fn job_prototype() -> impl Strategy<Value = Job> {
+    delivery_job_prototype(
+        job_task_prototype(default_job_place_prototype(), generate_simple_demand(1..5), generate_no_tags()),
+        generate_no_priority(),
+        generate_job_skills(
+            get_skills(vec!["unique1"]),
+            get_skills(vec!["unique2", "unique3"]),
+            get_skills(vec!["unique4"]),
+        ),
+    )
+}
+
+fn vehicle_type_prototype() -> impl Strategy<Value = VehicleType> {
+    let skills =
+        vec!["unique1", "unique2", "unique3", "unique4"].into_iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    generate_vehicle(
+        2..4,
+        Just("car".to_string()),
+        generate_simple_capacity(30..50),
+        default_costs_prototype(),
+        prop::sample::subsequence(skills, 1..4).prop_map(Some),
+        generate_no_limits(),
+        default_vehicle_shifts(),
+    )
+}
+
+prop_compose! {
+    fn get_problem_with_skills()
+    (
+     plan in generate_plan(generate_jobs(job_prototype(), 1..256)),
+     fleet in generate_fleet(generate_vehicles(vehicle_type_prototype(), 1..4), default_profiles())
+    ) -> Problem {
+        Problem {
+            plan,
+            fleet,
+            objectives: None,
+            config: None,
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+    #[test]
+    #[ignore]
+    fn can_solve_problem_with_skills(problem in get_problem_with_skills()) {
+        let result = solve_and_check(problem, None);
+
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -3,3 +3,4 @@
 mod generated_with_breaks;
 mod generated_with_relations;
 mod generated_with_reload;
+mod generated_with_skills;
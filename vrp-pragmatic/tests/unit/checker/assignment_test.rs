@@ -0,0 +1,275 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {check_vehicles, (known_ids, tours, expected_result), {
+    check_vehicles_impl(known_ids, tours, expected_result);
+}}
+
+check_vehicles! {
+    case_01: (vec!["vehicle_1"], vec![("vehicle_1", 0)], Ok(())),
+    case_02: (vec!["vehicle_1"], vec![("vehicle_2", 0)], Err(())),
+    case_03: (vec!["vehicle_1"], vec![("vehicle_1", 0), ("vehicle_1", 1)], Ok(())),
+    case_04: (vec!["vehicle_1"], vec![("vehicle_1", 0), ("vehicle_1", 0)], Err(())),
+}
+
+fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: known_ids.into_iter().map(|id| id.to_string()).collect(),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: tours
+            .into_iter()
+            .map(|(id, shift_index)| Tour {
+                vehicle_id: id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index,
+                stops: vec![],
+                statistic: Statistic::default(),
+                driver_id: None,
+            })
+            .collect(),
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_vehicles(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {check_drivers, (known_ids, tours, expected_result), {
+    check_drivers_impl(known_ids, tours, expected_result);
+}}
+
+check_drivers! {
+    case_01: (vec![], vec![("v1", None, (0, 10)), ("v2", None, (0, 10))], Ok(())),
+    case_02: (vec!["d1"], vec![("v1", Some("d1"), (0, 10))], Ok(())),
+    case_03: (vec!["d1"], vec![("v1", Some("d2"), (0, 10))], Err("Used driver with unknown id: d2".to_string())),
+    case_04: (vec!["d1"], vec![("v1", None, (0, 10))], Err("Tour of vehicle 'v1' has no driver".to_string())),
+    case_05: (vec!["d1"], vec![("v1", Some("d1"), (0, 10)), ("v2", Some("d1"), (10, 20))], Ok(())),
+    case_06: (
+        vec!["d1"],
+        vec![("v1", Some("d1"), (0, 10)), ("v2", Some("d1"), (5, 20))],
+        Err("Driver 'd1' is used in multiple tours at the same time".to_string())
+    ),
+    case_07: (vec!["d1", "d2"], vec![("v1", Some("d1"), (0, 10)), ("v1", Some("d2"), (10, 20))], Ok(())),
+    case_08: (
+        vec!["d1", "d2"],
+        vec![("v1", Some("d1"), (0, 10)), ("v1", Some("d2"), (5, 20))],
+        Err("Vehicle 'v1' is used in multiple tours at the same time".to_string())
+    ),
+}
+
+fn check_drivers_impl(
+    known_ids: Vec<&str>,
+    tours: Vec<(&str, Option<&str>, (i32, i32))>,
+    expected_result: Result<(), String>,
+) {
+    check_drivers_with_drivers_impl(known_ids.into_iter().map(create_default_driver).collect(), tours, expected_result);
+}
+
+parameterized_test! {check_driver_availability, (tour_time, expected_result), {
+    check_driver_availability_impl(tour_time, expected_result);
+}}
+
+check_driver_availability! {
+    case_01: ((0, 10), Ok(())),
+    case_02: ((5, 10), Ok(())),
+    case_03: ((5, 15), Err("Driver 'd1' is not available for tour of vehicle 'v1'".to_string())),
+}
+
+fn check_driver_availability_impl(tour_time: (i32, i32), expected_result: Result<(), String>) {
+    let driver = Driver {
+        shifts: vec![DriverShift { time: vec![format_time(0.), format_time(10.)] }],
+        ..create_default_driver("d1")
+    };
+
+    check_drivers_with_drivers_impl(vec![driver], vec![("v1", Some("d1"), tour_time)], expected_result);
+}
+
+fn check_drivers_with_drivers_impl(
+    drivers: Vec<Driver>,
+    tours: Vec<(&str, Option<&str>, (i32, i32))>,
+    expected_result: Result<(), String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: if drivers.is_empty() { None } else { Some(drivers) },
+        },
+        ..create_empty_problem()
+    };
+    let create_stop = |time: i32| {
+        let time = format_time(time as f64);
+        create_stop_with_activity("departure", "departure", (0., 0.), 0, (time.as_str(), time.as_str()), 0)
+    };
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: tours
+            .into_iter()
+            .map(|(vehicle_id, driver_id, (start, end))| Tour {
+                vehicle_id: vehicle_id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                driver_id: driver_id.map(|id| id.to_string()),
+                shift_index: 0,
+                stops: vec![create_stop(start), create_stop(end)],
+                statistic: Statistic::default(),
+            })
+            .collect(),
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_drivers(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result, expected_result.map_err(|err| vec![err]));
+}
+
+parameterized_test! {check_jobs, (jobs, tours, unassigned, expected_result), {
+    check_jobs_impl(jobs, tours, unassigned, expected_result);
+}}
+
+check_jobs! {
+    case_01: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![("my_vehicle_1", 0, vec![("job1", "pickup"), ("job1", "delivery")])],
+        vec![],
+        Ok(())
+    ),
+    case_02: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![
+            ("my_vehicle_1", 0, vec![("job1", "pickup")]),
+            ("my_vehicle_2", 0, vec![("job1", "delivery")])
+        ],
+        vec![],
+        Err(vec![
+            "Job served in multiple tours: 'job1'".to_string(),
+            "Not all tasks served for 'job1', expected: 2, assigned: 1".to_string()
+        ])
+    ),
+    case_03: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![("my_vehicle_1", 0, vec![("job1", "pickup")])],
+        vec![],
+        Err(vec!["Not all tasks served for 'job1', expected: 2, assigned: 1".to_string()])
+    ),
+    case_04: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![("my_vehicle_1", 0, vec![("job1", "delivery"), ("job1", "pickup")])],
+        vec![],
+        Err(vec!["Found pickup after delivery for 'job1'".to_string()])
+    ),
+    case_05: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![],
+        vec!["job1"],
+        Ok(())
+    ),
+    case_06: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![],
+        vec!["job1", "job1"],
+        Err(vec!["Duplicated job ids in the list of unassigned jobs".to_string()])
+    ),
+    case_07: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![],
+        vec!["job2"],
+        Err(vec!["Unknown job id in the list of unassigned jobs: 'job2'".to_string()])
+    ),
+    case_08: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![],
+        vec!["job1", "vehicle_break"],
+        Ok(())
+    ),
+    case_09: (
+        vec![("job1", vec!["pickup", "delivery"])],
+        vec![("my_vehicle_1", 0, vec![("job1", "pickup"), ("job1", "delivery")])],
+        vec!["job1"],
+        Err(vec![
+            "Job present as assigned and unassigned: 'job1'".to_string(),
+            "Amount of jobs present in problem and solution doesn't match: 1 vs 2".to_string()
+        ])
+    ),
+     case_10: (
+        vec![("job1", vec!["pickup"])],
+        vec![("my_vehicle_1", 0, vec![("job1", "pickup")])],
+        vec![],
+        Ok(())
+    ),
+}
+
+fn check_jobs_impl(
+    jobs: Vec<(&str, Vec<&str>)>,
+    tours: Vec<(&str, usize, Vec<(&str, &str)>)>,
+    unassigned: Vec<&str>,
+    expected_result: Result<(), Vec<String>>,
+) {
+    let create_tasks = |tgt: &str, tasks: &Vec<&str>| {
+        tasks.iter().filter(|&t| *t == tgt).map(|_| JobTask { places: vec![], demand: None, tag: None }).collect()
+    };
+
+    let create_stop = |stop: (&str, &str)| create_stop_with_activity(stop.0, stop.1, (0., 0.), 0, ("", ""), 0);
+
+    let problem = Problem {
+        plan: Plan {
+            jobs: jobs
+                .into_iter()
+                .map(|(id, tasks)| Job {
+                    id: id.to_string(),
+                    pickups: Some(create_tasks("pickup", &tasks)),
+                    deliveries: Some(create_tasks("delivery", &tasks)),
+                    replacements: Some(create_tasks("replacement", &tasks)),
+                    services: Some(create_tasks("service", &tasks)),
+                    priority: None,
+                    skills: None,
+                    max_ride_time: None,
+                    compatibility: None,
+                    group: None,
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: vec![], drivers: None },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: tours
+            .into_iter()
+            .map(|(id, shift_index, stops)| Tour {
+                vehicle_id: id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index,
+                stops: stops.into_iter().map(create_stop).collect(),
+                statistic: Statistic::default(),
+                driver_id: None,
+            })
+            .collect(),
+        unassigned: unassigned
+            .into_iter()
+            .map(|job| UnassignedJob { job_id: job.to_string(), reasons: vec![] })
+            .collect(),
+        extras: None,
+    };
+
+    let result = check_jobs(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_check_valid_solution() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+
+    let result = check_solution(&CheckerContext::new(problem, Some(vec![matrix]), solution));
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn can_return_violations_for_invalid_solution() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let mut solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    solution.tours.first_mut().unwrap().stops.retain(|stop| stop.activities.iter().all(|a| a.job_id != "job1"));
+
+    let result = check_solution(&CheckerContext::new(problem, Some(vec![matrix]), solution));
+
+    assert_eq!(
+        result,
+        Err(vec![
+            Violation {
+                rule: CheckRule::Assignment,
+                message: "Amount of jobs present in problem and solution doesn't match: 2 vs 1".to_string()
+            },
+            Violation {
+                rule: CheckRule::Capacity,
+                message: "Load mismatch at stop 0 in tour 'my_vehicle_1'".to_string()
+            },
+            Violation {
+                rule: CheckRule::Routing,
+                message: "Arrival time at stop 2 in tour 'my_vehicle_1' does not match routing: expected '5', got '6'"
+                    .to_string()
+            }
+        ])
+    );
+}
+
+#[test]
+fn can_return_format_violation_for_tour_without_stops() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let mut solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    solution.tours.first_mut().unwrap().stops.clear();
+
+    let result = check_solution(&CheckerContext::new(problem, Some(vec![matrix]), solution));
+
+    assert_eq!(
+        result,
+        Err(vec![Violation {
+            rule: CheckRule::Format,
+            message: "Tour of vehicle 'my_vehicle_1' has no stops".to_string()
+        }])
+    );
+}
+
+#[test]
+fn can_return_format_violation_for_invalid_time() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let mut solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    solution.tours.first_mut().unwrap().stops.last_mut().unwrap().time.arrival = "invalid".to_string();
+
+    let result = check_solution(&CheckerContext::new(problem, Some(vec![matrix]), solution));
+
+    assert_eq!(
+        result,
+        Err(vec![Violation {
+            rule: CheckRule::Format,
+            message: "Cannot parse time 'invalid' in tour of vehicle 'my_vehicle_1'".to_string()
+        }])
+    );
+}
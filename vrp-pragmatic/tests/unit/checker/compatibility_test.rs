@@ -0,0 +1,83 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_tour(job_ids: Vec<&str>) -> Tour {
+    Tour {
+        vehicle_id: "my_vehicle_1".to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops: job_ids
+            .into_iter()
+            .enumerate()
+            .map(|(idx, job_id)| {
+                create_stop_with_activity(
+                    job_id,
+                    if job_id == "reload" { "reload" } else { "delivery" },
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    idx as i32,
+                )
+            })
+            .collect(),
+        statistic: Statistic {
+            cost: 0.,
+            distance: 0,
+            duration: 0,
+            times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+        },
+        driver_id: None,
+    }
+}
+
+parameterized_test! {can_check_compatibility, (job_ids, expected_result), {
+    can_check_compatibility_impl(job_ids, expected_result);
+}}
+
+can_check_compatibility! {
+    case01: (vec!["job1", "job3", "job4"], Ok(())),
+    case02: (vec!["job1", "reload", "job2"], Ok(())),
+    case03: (vec!["job1", "job2"],
+             Err("Jobs with different compatibility are served together in tour 'my_vehicle_1': [\"A\", \"B\"]".to_owned())),
+    case04: (vec!["job1", "reload", "job2", "job3", "job4"],
+             Err("Jobs with different compatibility are served together in tour 'my_vehicle_1': [\"A\", \"B\"]".to_owned())),
+}
+
+fn can_check_compatibility_impl(job_ids: Vec<&str>, expected_result: Result<(), String>) {
+    let create_job = |id: &str, compatibility: Option<&str>| Job {
+        compatibility: compatibility.map(|compatibility| compatibility.to_string()),
+        ..create_delivery_job(id, vec![1., 0.])
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job("job1", Some("A")),
+                create_job("job2", Some("B")),
+                create_job("job3", None),
+                create_job("job4", Some("A")),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: Statistic {
+            cost: 0.,
+            distance: 0,
+            duration: 0,
+            times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+        },
+        tours: vec![create_test_tour(job_ids)],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_compatibility(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result, expected_result.map_err(|err| vec![err]));
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_empty_statistic() -> Statistic {
+    Statistic {
+        cost: 0.,
+        distance: 0,
+        duration: 0,
+        times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+    }
+}
+
+fn create_test_tour(vehicle_id: &str, job_ids: Vec<&str>) -> Tour {
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops: job_ids
+            .into_iter()
+            .enumerate()
+            .map(|(idx, job_id)| {
+                create_stop_with_activity(
+                    job_id,
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    idx as i32,
+                )
+            })
+            .collect(),
+        statistic: create_empty_statistic(),
+        driver_id: None,
+    }
+}
+
+parameterized_test! {can_check_groups, (tours, unassigned, expected_result), {
+    can_check_groups_impl(tours, unassigned, expected_result);
+}}
+
+can_check_groups! {
+    case01: (vec![("my_vehicle_1", vec!["job1", "job2"]), ("my_vehicle_2", vec!["job3"])], vec![], Ok(())),
+    case02: (vec![("my_vehicle_1", vec!["job3"])], vec!["job1", "job2"], Ok(())),
+    case03: (vec![("my_vehicle_1", vec!["job1"]), ("my_vehicle_2", vec!["job2", "job3"])], vec![],
+             Err("Jobs of group 'g1' are served in 2 tours".to_owned())),
+    case04: (vec![("my_vehicle_1", vec!["job1", "job3"])], vec!["job2"],
+             Err("Jobs of group 'g1' are partially assigned: 1 of 2".to_owned())),
+}
+
+fn can_check_groups_impl(tours: Vec<(&str, Vec<&str>)>, unassigned: Vec<&str>, expected_result: Result<(), String>) {
+    let create_job = |id: &str, group: Option<&str>| Job {
+        group: group.map(|group| group.to_string()),
+        ..create_delivery_job(id, vec![1., 0.])
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job("job1", Some("g1")), create_job("job2", Some("g1")), create_job("job3", None)],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: create_empty_statistic(),
+        tours: tours.into_iter().map(|(vehicle_id, job_ids)| create_test_tour(vehicle_id, job_ids)).collect(),
+        unassigned: unassigned
+            .into_iter()
+            .map(|job_id| UnassignedJob {
+                job_id: job_id.to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: 107,
+                    description: "cannot be assigned due to group constraint".to_string(),
+                }],
+            })
+            .collect(),
+        extras: None,
+    };
+
+    let result = check_groups(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result, expected_result.map_err(|err| vec![err]));
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_limits(
+    max_distance: Option<f64>,
+    shift_time: Option<f64>,
+    max_activities: Option<usize>,
+    max_jobs: Option<usize>,
+    min_jobs: Option<usize>,
+) -> VehicleLimits {
+    VehicleLimits { max_distance, shift_time, max_activities, max_jobs, min_jobs }
+}
+
+parameterized_test! {can_check_limits, (limits, expected_result), {
+    can_check_limits_impl(limits, expected_result);
+}}
+
+can_check_limits! {
+    case01: (None, Ok(())),
+    case02: (Some(create_limits(Some(4.), Some(6.), Some(2), Some(2), Some(2))), Ok(())),
+    case03: (Some(create_limits(Some(3.), None, None, None, None)),
+             Err(vec!["distance '4' exceeds max distance 'Some(3.0)'"])),
+    case04: (Some(create_limits(None, Some(5.), None, None, None)),
+             Err(vec!["duration '6' exceeds shift time 'Some(5.0)'"])),
+    case05: (Some(create_limits(None, None, Some(1), Some(1), None)),
+             Err(vec!["amount of activities '2' exceeds max activities 'Some(1)'",
+                      "amount of jobs '2' exceeds max jobs 'Some(1)'"])),
+    case06: (Some(create_limits(None, None, None, None, Some(3))),
+             Err(vec!["amount of jobs '2' is less than min jobs 'Some(3)'"])),
+}
+
+fn can_check_limits_impl(limits: Option<VehicleLimits>, expected_result: Result<(), Vec<&str>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { limits, ..create_default_vehicle("my_vehicle") }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let statistic = Statistic {
+        cost: 0.,
+        distance: 4,
+        duration: 6,
+        times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+    };
+    let solution = Solution {
+        statistic: statistic.clone(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    0,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:06Z", "1970-01-01T00:00:06Z"),
+                    4,
+                ),
+            ],
+            statistic,
+            driver_id: None,
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_limits(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(
+        result,
+        expected_result.map_err(|messages| messages
+            .into_iter()
+            .map(|message| format!("Tour of vehicle 'my_vehicle_1': {}", message))
+            .collect())
+    );
+}
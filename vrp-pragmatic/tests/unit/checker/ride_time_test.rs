@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_check_ride_time, (max_ride_time, expected_result), {
+    can_check_ride_time_impl(max_ride_time, expected_result);
+}}
+
+can_check_ride_time! {
+    case01: (None, Ok(())),
+    case02: (Some(3.), Ok(())),
+    case03: (Some(2.), Err("Ride time of job 'job1' is '3', but max ride time is '2'".to_owned())),
+}
+
+fn can_check_ride_time_impl(max_ride_time: Option<f64>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { max_ride_time, ..create_pickup_delivery_job("job1", vec![1., 0.], vec![4., 0.]) }],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: Statistic {
+            cost: 20.,
+            distance: 8,
+            duration: 10,
+            times: Timing { driving: 8, serving: 2, waiting: 0, break_time: 0 },
+        },
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "pickup",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (4., 0.),
+                    0,
+                    ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                    4,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
+                    8,
+                ),
+            ],
+            statistic: Statistic {
+                cost: 20.,
+                distance: 8,
+                duration: 10,
+                times: Timing { driving: 8, serving: 2, waiting: 0, break_time: 0 },
+            },
+            driver_id: None,
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_ride_time(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(result, expected_result.map_err(|err| vec![err]));
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_check_routing, (has_matrix, arrival, distance, expected_result), {
+    can_check_routing_impl(has_matrix, arrival, distance, expected_result);
+}}
+
+can_check_routing! {
+    case01: (true, "1970-01-01T00:00:03Z", 2, Ok(())),
+    case02: (false, "1970-01-01T00:00:05Z", 3, Ok(())),
+    case03: (true, "1970-01-01T00:00:05Z", 2,
+             Err("Arrival time at stop 2 in tour 'my_vehicle_1' does not match routing: expected '3', got '5'")),
+    case04: (true, "1970-01-01T00:00:03Z", 3,
+             Err("Distance at stop 2 in tour 'my_vehicle_1' does not match routing: expected '2', got '3'")),
+}
+
+fn can_check_routing_impl(has_matrix: bool, arrival: &str, distance: i32, expected_result: Result<(), &str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrices = if has_matrix { Some(vec![create_matrix_from_problem(&problem)]) } else { None };
+    let statistic = Statistic {
+        cost: 0.,
+        distance,
+        duration: 3,
+        times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+    };
+    let solution = Solution {
+        statistic: statistic.clone(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity("arrival", "arrival", (0., 0.), 0, (arrival, arrival), distance),
+            ],
+            statistic,
+            driver_id: None,
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_routing(&CheckerContext::new(problem, matrices, solution));
+
+    assert_eq!(result, expected_result.map_err(|message| vec![message.to_string()]));
+}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_skill_sets(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>, none_of: Option<Vec<&str>>) -> JobSkills {
+    JobSkills::Sets(JobSkillSets {
+        all_of: all_of.map(to_strings),
+        one_of: one_of.map(to_strings),
+        none_of: none_of.map(to_strings),
+    })
+}
+
+parameterized_test! {can_check_skills, (skills, expected_result), {
+    can_check_skills_impl(skills, expected_result);
+}}
+
+can_check_skills! {
+    case01: (None, Ok(())),
+    case02: (Some(JobSkills::List(to_strings(vec!["vehicle", "driver"]))), Ok(())),
+    case03: (Some(JobSkills::List(to_strings(vec!["other"]))), Err(())),
+    case04: (Some(create_skill_sets(None, Some(vec!["other", "driver"]), None)), Ok(())),
+    case05: (Some(create_skill_sets(None, Some(vec!["other"]), None)), Err(())),
+    case06: (Some(create_skill_sets(Some(vec!["vehicle"]), None, Some(vec!["other"]))), Ok(())),
+    case07: (Some(create_skill_sets(Some(vec!["vehicle"]), None, Some(vec!["driver"]))), Err(())),
+}
+
+fn can_check_skills_impl(skills: Option<JobSkills>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { skills, ..create_delivery_job("job1", vec![1., 0.]) }], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: Some(to_strings(vec!["vehicle"])),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            profiles: create_default_profiles(),
+            drivers: Some(vec![Driver { skills: Some(to_strings(vec!["driver"])), ..create_default_driver("driver") }]),
+        },
+        ..create_empty_problem()
+    };
+    let statistic = Statistic {
+        cost: 0.,
+        distance: 0,
+        duration: 0,
+        times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+    };
+    let solution = Solution {
+        statistic: statistic.clone(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+            ],
+            statistic,
+            driver_id: Some("driver".to_string()),
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_skills(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(
+        result,
+        expected_result
+            .map_err(|_| vec!["Job 'job1' is served in tour 'my_vehicle_1' which has no required skills".to_string()])
+    );
+}
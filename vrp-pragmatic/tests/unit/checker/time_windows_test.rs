@@ -0,0 +1,83 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_check_time_windows, (arrival, lateness, expected_result), {
+    can_check_time_windows_impl(arrival, lateness, expected_result);
+}}
+
+can_check_time_windows! {
+    case01: (3., None, Ok(())),
+    case02: (5., None, Ok(())),
+    case03: (7., None, Err(TimeWindow::new(7., 8.))),
+    case04: (7., Some(3.), Ok(())),
+    case05: (9., Some(3.), Err(TimeWindow::new(9., 10.))),
+}
+
+fn can_check_time_windows_impl(arrival: f64, lateness: Option<f64>, expected_result: Result<(), TimeWindow>) {
+    let mut job = create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 5)], 1.);
+    job.deliveries.as_mut().unwrap()[0].places[0].lateness = lateness.map(|max| JobPlaceLateness { max, cost: 1. });
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let statistic = Statistic {
+        cost: 0.,
+        distance: 0,
+        duration: 0,
+        times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+    };
+    let solution = Solution {
+        statistic: statistic.clone(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    (&format_time(0.), &format_time(0.)),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    (&format_time(arrival), &format_time(arrival + 1.)),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    (&format_time(arrival + 2.), &format_time(arrival + 2.)),
+                    2,
+                ),
+            ],
+            statistic,
+            driver_id: None,
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = check_time_windows(&CheckerContext::new(problem, None, solution));
+
+    assert_eq!(
+        result,
+        expected_result.map_err(|time| vec![format!(
+            "Activity of job 'job1' is served outside of its time windows in tour 'my_vehicle_1': '{:?}'",
+            time
+        )])
+    );
+}
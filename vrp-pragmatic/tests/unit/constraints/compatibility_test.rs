@@ -0,0 +1,78 @@
+use crate::constraints::CompatibilityModule;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::constraints::ConstraintPipeline;
+use vrp_core::construction::heuristics::{RouteContext, RouteState, SolutionContext};
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::{Fleet, Single};
+use vrp_core::models::solution::Registry;
+
+fn create_single(id: &str, compatibility: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+
+    if id == "reload" {
+        single.dimens.set_value("type", "reload".to_string());
+    }
+
+    if let Some(compatibility) = compatibility {
+        single.dimens.set_value("compatibility", compatibility.to_string());
+    }
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_unassign_incompatible_jobs, (jobs, expected_unassigned), {
+    can_unassign_incompatible_jobs_impl(jobs, expected_unassigned);
+}}
+
+can_unassign_incompatible_jobs! {
+    case01: (vec![("job1", Some("A")), ("job2", None), ("job3", Some("A"))], vec![]),
+    case02: (vec![("job1", Some("A")), ("reload", None), ("job2", Some("B"))], vec![]),
+    case03: (vec![("job1", Some("A")), ("job2", Some("B"))], vec!["job2"]),
+    case04: (vec![("job1", Some("A")), ("reload", None), ("job2", Some("B")), ("job3", Some("A"))], vec!["job3"]),
+}
+
+fn can_unassign_incompatible_jobs_impl(jobs: Vec<(&str, Option<&str>)>, expected_unassigned: Vec<&str>) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let mut solution_ctx = SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        routes: vec![RouteContext {
+            route: Arc::new(create_route_with_activities(
+                &fleet,
+                "v1",
+                jobs.iter()
+                    .map(|(id, compatibility)| {
+                        create_activity_with_job_at_location(create_single(id, *compatibility), DEFAULT_JOB_LOCATION)
+                    })
+                    .collect(),
+            )),
+            state: Arc::new(RouteState::default()),
+        }],
+        registry: Registry::new(&fleet),
+    };
+
+    ConstraintPipeline::default()
+        .add_module(Box::new(CompatibilityModule::new(12)))
+        .accept_solution_state(&mut solution_ctx);
+
+    let mut unassigned = solution_ctx
+        .unassigned
+        .iter()
+        .map(|(job, code)| {
+            assert_eq!(*code, 12);
+            job.to_single().dimens.get_id().unwrap().clone()
+        })
+        .collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, expected_unassigned);
+    assert_eq!(solution_ctx.routes.first().unwrap().route.tour.job_count(), jobs.len() - expected_unassigned.len());
+}
@@ -0,0 +1,74 @@
+use crate::constraints::{TourSizeLimits, TourSizeModule};
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::constraints::ConstraintPipeline;
+use vrp_core::construction::heuristics::{RouteContext, RouteState, SolutionContext};
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::problem::{Fleet, Job, Single};
+use vrp_core::models::solution::Registry;
+
+fn create_single(id: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+
+    Arc::new(single)
+}
+
+fn get_ids<'a>(jobs: impl Iterator<Item = &'a Job>) -> Vec<String> {
+    let mut ids = jobs.map(|job| job.to_single().dimens.get_id().unwrap().clone()).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+parameterized_test! {can_remove_tours_with_less_than_min_jobs, (jobs, required, expected_routes, expected_required, expected_unassigned), {
+    can_remove_tours_with_less_than_min_jobs_impl(jobs, required, expected_routes, expected_required, expected_unassigned);
+}}
+
+can_remove_tours_with_less_than_min_jobs! {
+    case01: (vec!["job1", "job2"], vec![], 1, vec![], vec![]),
+    case02: (vec!["job1"], vec![], 0, vec![], vec!["job1"]),
+    case03: (vec!["job1"], vec!["job2"], 0, vec!["job1", "job2"], vec![]),
+    case04: (vec!["job1", "job2"], vec!["job3"], 1, vec!["job3"], vec![]),
+}
+
+fn can_remove_tours_with_less_than_min_jobs_impl(
+    jobs: Vec<&str>,
+    required: Vec<&str>,
+    expected_routes: usize,
+    expected_required: Vec<&str>,
+    expected_unassigned: Vec<&str>,
+) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let mut solution_ctx = SolutionContext {
+        required: required.into_iter().map(|id| Job::Single(create_single(id))).collect(),
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        routes: vec![RouteContext {
+            route: Arc::new(create_route_with_activities(
+                &fleet,
+                "v1",
+                jobs.iter()
+                    .map(|id| create_activity_with_job_at_location(create_single(id), DEFAULT_JOB_LOCATION))
+                    .collect(),
+            )),
+            state: Arc::new(RouteState::default()),
+        }],
+        registry: Registry::new(&fleet),
+    };
+    let limit_func = Arc::new(|_: &_| TourSizeLimits { min_jobs: Some(2), ..TourSizeLimits::default() });
+
+    ConstraintPipeline::default()
+        .add_module(Box::new(TourSizeModule::new(limit_func, 11)))
+        .accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_ids(solution_ctx.required.iter()), expected_required);
+    assert_eq!(get_ids(solution_ctx.unassigned.keys()), expected_unassigned);
+    assert!(solution_ctx.unassigned.values().all(|code| *code == 11));
+    assert_eq!(solution_ctx.routes.len(), expected_routes);
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::models::common::TimeWindow;
+use vrp_core::models::problem::Single;
+use vrp_core::models::solution::TourActivity;
+
+fn create_activity(location: usize, time: (f64, f64), lateness: Vec<Option<PlaceLateness>>) -> TourActivity {
+    let mut single = Single {
+        places: vec![
+            Place { location: Some(1), duration: 0., times: vec![TimeSpan::Window(TimeWindow::new(0., 100.))] },
+            Place { location: Some(2), duration: 0., times: vec![TimeSpan::Window(TimeWindow::new(50., 150.))] },
+        ],
+        dimens: Default::default(),
+    };
+    set_places_lateness(&mut single.dimens, lateness);
+
+    let mut activity = create_activity_with_job_at_location(Arc::new(single), location);
+    activity.place.time = TimeWindow::new(time.0, time.1);
+
+    activity
+}
+
+parameterized_test! {can_get_activity_lateness, (location, time, arrival, expected), {
+    can_get_activity_lateness_impl(location, time, arrival, expected);
+}}
+
+can_get_activity_lateness! {
+    case01: (1, (0., 100.), 80., Some((0., 0.))),
+    case02: (1, (0., 100.), 95., Some((15., 30.))),
+    case03: (2, (50., 150.), 120., Some((20., 60.))),
+    case04: (2, (50., 150.), 10., Some((0., 0.))),
+    case05: (3, (0., 100.), 95., None),
+}
+
+fn can_get_activity_lateness_impl(
+    location: usize,
+    time: (f64, f64),
+    arrival: Timestamp,
+    expected: Option<(Duration, Cost)>,
+) {
+    let activity = create_activity(
+        location,
+        time,
+        vec![Some(PlaceLateness { max: 20., cost: 2. }), Some(PlaceLateness { max: 50., cost: 3. })],
+    );
+
+    assert_eq!(get_activity_lateness(&activity, arrival), expected);
+}
+
+#[test]
+fn can_skip_places_without_lateness() {
+    let activity = create_activity(1, (0., 100.), vec![None, Some(PlaceLateness { max: 50., cost: 3. })]);
+
+    assert_eq!(get_activity_lateness(&activity, 95.), None);
+}
+
+#[test]
+fn can_skip_jobs_without_lateness() {
+    let activity = create_activity(1, (0., 100.), vec![None, None]);
+
+    assert!(activity
+        .job
+        .as_ref()
+        .unwrap()
+        .dimens
+        .get_value::<Vec<Option<PlaceLateness>>>(PLACES_LATENESS_KEY)
+        .is_none());
+    assert_eq!(get_activity_lateness(&activity, 95.), None);
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::helpers::SIMPLE_MATRIX;
+use crate::json::problem::deserialize_matrix;
+
+fn to_binary(matrix: &Matrix) -> Vec<u8> {
+    let mut buffer = vec![];
+    serialize_binary_matrix(BufWriter::new(&mut buffer), matrix).unwrap();
+
+    buffer
+}
+
+fn assert_matrices(actual: &Matrix, expected: &Matrix) {
+    assert_eq!(actual.profile, expected.profile);
+    assert_eq!(actual.timestamp, expected.timestamp);
+    assert_eq!(actual.travel_times, expected.travel_times);
+    assert_eq!(actual.distances, expected.distances);
+    assert_eq!(actual.error_codes, expected.error_codes);
+}
+
+#[test]
+fn can_serialize_and_deserialize_binary_matrix() {
+    let matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).ok().unwrap();
+
+    let buffer = to_binary(&matrix);
+    let result = deserialize_matrix(BufReader::new(buffer.as_slice())).ok().unwrap();
+
+    assert_eq!(buffer.len(), 6 + 4 + matrix.profile.len() + 8 + 2 * 8 * 16);
+    assert_matrices(&result, &matrix);
+}
+
+#[test]
+fn can_serialize_and_deserialize_binary_matrix_with_optional_data() {
+    let matrix = Matrix {
+        profile: "car".to_string(),
+        timestamp: Some("1970-01-01T00:00:00Z".to_string()),
+        travel_times: vec![0, 1, -1, 0],
+        distances: vec![0, 2, -1, 0],
+        error_codes: Some(vec![0, 0, 1, 0]),
+    };
+
+    let result = deserialize_binary_matrix(BufReader::new(to_binary(&matrix).as_slice())).ok().unwrap();
+
+    assert_matrices(&result, &matrix);
+}
+
+#[test]
+fn can_detect_binary_matrix() {
+    let buffer = to_binary(&Matrix {
+        profile: "car".to_string(),
+        timestamp: None,
+        travel_times: vec![0],
+        distances: vec![0],
+        error_codes: None,
+    });
+
+    assert!(is_binary_matrix(&mut BufReader::new(buffer.as_slice())));
+    assert!(!is_binary_matrix(&mut BufReader::new(SIMPLE_MATRIX.as_bytes())));
+}
+
+#[test]
+fn can_return_error_for_truncated_binary_matrix() {
+    let matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).ok().unwrap();
+    let buffer = to_binary(&matrix);
+
+    let result = deserialize_matrix(BufReader::new(&buffer[0..buffer.len() - 1])).err().unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result.first().unwrap().code, "E0001");
+}
+
+#[test]
+fn can_return_error_for_too_large_length() {
+    let buffer = [b"VRPM".as_ref(), &[1, 0], &u32::MAX.to_le_bytes(), b"car"].concat();
+
+    let result = deserialize_matrix(BufReader::new(buffer.as_slice())).err().unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result.first().unwrap().code, "E0001");
+}
+
+#[test]
+fn can_return_error_for_inconsistent_matrix() {
+    let matrix = Matrix {
+        profile: "car".to_string(),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0],
+        error_codes: None,
+    };
+
+    assert!(serialize_binary_matrix(BufWriter::new(vec![]), &matrix).is_err());
+}
+
+#[test]
+fn can_return_error_for_non_square_matrix() {
+    let matrix = Matrix {
+        profile: "car".to_string(),
+        timestamp: None,
+        travel_times: vec![0, 1, 1],
+        distances: vec![0, 1, 1],
+        error_codes: None,
+    };
+
+    assert!(serialize_binary_matrix(BufWriter::new(vec![]), &matrix).is_err());
+}
+
+#[test]
+fn can_return_error_for_too_many_locations() {
+    let buffer = [b"VRPM".as_ref(), &[1, 0], &3_u32.to_le_bytes(), b"car", &u64::MAX.to_le_bytes()].concat();
+
+    let result = deserialize_matrix(BufReader::new(buffer.as_slice())).err().unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert!(result.first().unwrap().action.contains("too many locations"));
+}
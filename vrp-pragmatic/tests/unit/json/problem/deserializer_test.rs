@@ -11,8 +11,7 @@ fn assert_time_windows(actual: &Option<Vec<Vec<String>>>, expected: (&str, &str)
 }
 
 fn assert_location(actual: &Location, expected: (f64, f64)) {
-    assert_eq!(actual.lat, expected.0);
-    assert_eq!(actual.lng, expected.1);
+    assert_eq!(actual.to_lat_lng(), Some(expected));
 }
 
 fn assert_demand(actual: &Option<Vec<i32>>, expected: i32) {
@@ -55,6 +54,25 @@ fn can_deserialize_problem() {
     assert_eq!(job.deliveries.as_ref().unwrap().len(), 1);
 }
 
+#[test]
+fn can_deserialize_job_skills() {
+    let skills = serde_json::from_str::<JobSkills>(r#"["s1", "s2"]"#).ok().unwrap();
+    match skills {
+        JobSkills::List(all_of) => assert_eq!(all_of, vec!["s1".to_string(), "s2".to_string()]),
+        _ => unreachable!(),
+    }
+
+    let skills = serde_json::from_str::<JobSkills>(r#"{"oneOf": ["s1", "s2"], "noneOf": ["s3"]}"#).ok().unwrap();
+    match skills {
+        JobSkills::Sets(sets) => {
+            assert!(sets.all_of.is_none());
+            assert_eq!(sets.one_of, Some(vec!["s1".to_string(), "s2".to_string()]));
+            assert_eq!(sets.none_of, Some(vec!["s3".to_string()]));
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn can_deserialize_matrix() {
     let matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).ok().unwrap();
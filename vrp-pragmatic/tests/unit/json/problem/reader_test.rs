@@ -1,5 +1,8 @@
 use crate::extensions::MultiDimensionalCapacity;
-use crate::helpers::{create_default_profiles, single_demand_as_multi, ToLocation, SIMPLE_MATRIX, SIMPLE_PROBLEM};
+use crate::helpers::{
+    create_default_profiles, create_default_vehicle, create_delivery_job, create_empty_problem,
+    create_matrix_from_problem, single_demand_as_multi, ToLocation, SIMPLE_MATRIX, SIMPLE_PROBLEM,
+};
 use crate::json::problem::*;
 use std::collections::HashSet;
 use std::iter::FromIterator;
@@ -43,6 +46,16 @@ fn assert_demand(demand: &Demand<MultiDimensionalCapacity>, expected: &Demand<Mu
     assert_eq!(demand.delivery.1.as_vec(), expected.delivery.1.as_vec());
 }
 
+fn assert_job_skills(dimens: &Dimensions, expected: Option<Vec<String>>) {
+    let skills = dimens.get("skills").and_then(|any| any.downcast_ref::<crate::constraints::JobSkills>());
+    if let Some(expected) = expected {
+        let expected = HashSet::from_iter(expected.iter().cloned());
+        assert_eq!(skills.unwrap().all_of.clone(), Some(expected));
+    } else {
+        assert!(skills.is_none());
+    }
+}
+
 fn assert_skills(dimens: &Dimensions, expected: Option<Vec<String>>) {
     let skills = dimens.get("skills").and_then(|any| any.downcast_ref::<HashSet<String>>());
     if let Some(expected) = expected {
@@ -69,6 +82,7 @@ fn can_read_complex_problem() {
                             ]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 100.0,
+                            lateness: None,
                         }],
                         demand: Some(vec![0, 1]),
                         tag: Some("my_delivery".to_string()),
@@ -76,7 +90,10 @@ fn can_read_complex_problem() {
                     replacements: None,
                     services: None,
                     priority: None,
-                    skills: Some(vec!["unique".to_string()]),
+                    skills: Some(JobSkills::List(vec!["unique".to_string()])),
+                    max_ride_time: None,
+                    compatibility: None,
+                    group: None,
                 },
                 Job {
                     id: "pickup_delivery_job".to_string(),
@@ -88,6 +105,7 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48300, 13.4420].to_loc(),
                             duration: 110.0,
+                            lateness: None,
                         }],
                         demand: Some(vec![2]),
                         tag: None,
@@ -100,6 +118,7 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 120.0,
+                            lateness: None,
                         }],
                         demand: Some(vec![2]),
                         tag: None,
@@ -108,6 +127,9 @@ fn can_read_complex_problem() {
                     services: None,
                     priority: None,
                     skills: None,
+                    max_ride_time: None,
+                    compatibility: None,
+                    group: None,
                 },
                 Job {
                     id: "pickup_job".to_string(),
@@ -120,6 +142,7 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48321, 13.4438].to_loc(),
                             duration: 90.0,
+                            lateness: None,
                         }],
                         demand: Some(vec![3]),
                         tag: None,
@@ -128,7 +151,10 @@ fn can_read_complex_problem() {
                     replacements: None,
                     services: None,
                     priority: None,
-                    skills: Some(vec!["unique2".to_string()]),
+                    skills: Some(JobSkills::List(vec!["unique2".to_string()])),
+                    max_ride_time: None,
+                    compatibility: None,
+                    group: None,
                 },
             ],
             relations: Option::None,
@@ -138,15 +164,22 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: "car".to_string(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts {
+                    fixed: Some(100.),
+                    distance: 1.,
+                    time: 2.,
+                    waiting: None,
+                    service: None,
+                    overtime: None,
+                },
                 shifts: vec![VehicleShift {
                     start: VehiclePlace {
                         time: "1970-01-01T00:00:00Z".to_string(),
-                        location: vec![52.4862, 13.45148].to_loc(),
+                        location: Some(vec![52.4862, 13.45148].to_loc()),
                     },
                     end: Some(VehiclePlace {
                         time: "1970-01-01T00:01:40Z".to_string(),
-                        location: vec![52.4862, 13.45148].to_loc(),
+                        location: Some(vec![52.4862, 13.45148].to_loc()),
                     }),
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![
@@ -160,9 +193,16 @@ fn can_read_complex_problem() {
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), shift_time: Some(100.) }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    shift_time: Some(100.),
+                    max_activities: None,
+                    max_jobs: None,
+                    min_jobs: None,
+                }),
             }],
             profiles: create_default_profiles(),
+            drivers: None,
         },
         objectives: None,
         config: None,
@@ -193,12 +233,12 @@ fn can_read_complex_problem() {
         },
     );
     assert_time_spans(&place.times, vec![(0., 100.), (110., 120.)]);
-    assert_skills(&job.dimens, Some(vec!["unique".to_string()]));
+    assert_job_skills(&job.dimens, Some(vec!["unique".to_string()]));
 
     // shipment
     let job = get_multi_job(1, problem.jobs.as_ref());
     assert_eq!(job.dimens.get_id().unwrap(), "pickup_delivery_job");
-    assert_skills(&job.dimens, None);
+    assert_job_skills(&job.dimens, None);
 
     let pickup = job.jobs.first().unwrap().clone();
     let place = get_single_place(pickup.as_ref());
@@ -222,7 +262,7 @@ fn can_read_complex_problem() {
     assert_eq!(place.location.unwrap(), 2);
     assert_demand(job.dimens.get_demand().unwrap(), &single_demand_as_multi((3, 0), (0, 0)));
     assert_time_spans(&place.times, vec![(10., 70.)]);
-    assert_skills(&job.dimens, Some(vec!["unique2".to_string()]));
+    assert_job_skills(&job.dimens, Some(vec!["unique2".to_string()]));
 
     // fleet
     assert_eq!(problem.fleet.profiles.len(), 1);
@@ -261,3 +301,35 @@ fn can_deserialize_minimal_problem_and_matrix() {
         &(1562230800., 1562263200.),
     );
 }
+
+parameterized_test! {can_read_neighbourhood_config, (config, expected), {
+    can_read_neighbourhood_config_impl(config, expected);
+}}
+
+can_read_neighbourhood_config! {
+    case01: (None, 4),
+    case02: (Some(r#"{ "neighbourhood": { "maxNeighbours": 2 } }"#), 2),
+    case03: (Some(r#"{ "neighbourhood": { "maxNeighbours": 1, "isLazy": true } }"#), 1),
+}
+
+fn can_read_neighbourhood_config_impl(config: Option<&str>, expected: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=5).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        config: config.map(|config| serde_json::from_str(config).unwrap()),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let job = get_job(0, problem.jobs.as_ref());
+    assert_eq!(problem.jobs.neighbors(0, &job, 0., std::f64::MAX).count(), expected);
+}
@@ -0,0 +1,290 @@
+use crate::helpers::*;
+use crate::json::problem::*;
+use crate::json::solution::*;
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::utils::DefaultRandom;
+
+fn get_core_problem(problem: Problem, matrix: Matrix) -> Arc<CoreProblem> {
+    Arc::new((problem, vec![matrix]).read_pragmatic().ok().unwrap())
+}
+
+fn to_string(solution: &Solution) -> String {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, solution).ok().unwrap();
+
+    buffer
+}
+
+fn create_solution_with_stop(stop: Stop) -> Solution {
+    Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                stop,
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:11Z", "1970-01-01T00:00:11Z"),
+                    10,
+                ),
+            ],
+            statistic: Statistic::default(),
+            driver_id: None,
+        }],
+        unassigned: vec![],
+        extras: None,
+    }
+}
+
+fn create_test_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_read_solution_written_by_writer() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![5., 0.]),
+                create_pickup_delivery_job("job2", vec![10., 0.], vec![3., 0.]),
+                create_multi_job(
+                    "job3",
+                    vec![((2., 0.), 1., vec![1]), ((4., 0.), 1., vec![1])],
+                    vec![((6., 0.), 1., vec![2])],
+                ),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let expected = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = get_core_problem(problem, matrix);
+
+    let solution = read_init_solution(BufReader::new(to_string(&expected).as_bytes()), core_problem.clone()).unwrap();
+
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes.first().unwrap().tour.job_count(), 3);
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(create_solution(core_problem.as_ref(), &solution), expected);
+}
+
+#[test]
+fn can_read_solution_with_missing_jobs_as_unassigned() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = get_core_problem(problem, matrix);
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (5., 0.),
+                    0,
+                    ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                    5,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:11Z", "1970-01-01T00:00:11Z"),
+                    10,
+                ),
+            ],
+            statistic: Statistic::default(),
+            driver_id: None,
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let solution = read_init_solution(BufReader::new(to_string(&solution).as_bytes()), core_problem).unwrap();
+
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes.first().unwrap().tour.job_count(), 1);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.keys().next().unwrap().dimens().get_id().unwrap(), "job2");
+}
+
+#[test]
+fn can_return_error_for_unknown_vehicle() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![5., 0.])], relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = get_core_problem(problem, matrix);
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: "unknown".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![],
+            statistic: Statistic::default(),
+            driver_id: None,
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let result = read_init_solution(BufReader::new(to_string(&solution).as_bytes()), core_problem);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_lock_reloads_of_init_solution() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        times: None,
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.0,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let expected = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = get_core_problem(problem, matrix);
+    let solution = read_init_solution(BufReader::new(to_string(&expected).as_bytes()), core_problem.clone()).unwrap();
+
+    let insertion_ctx = InsertionContext::new_from_solution(
+        core_problem,
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+
+    let locked = insertion_ctx
+        .solution
+        .locked
+        .iter()
+        .filter_map(|job| job.dimens().get_value::<String>("type").cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(locked, vec!["reload".to_string()]);
+    assert_eq!(insertion_ctx.solution.routes.first().unwrap().route.tour.job_count(), 3);
+}
+
+#[test]
+fn can_read_partially_routed_multi_job_as_unassigned() {
+    let problem = create_test_problem(vec![
+        create_delivery_job("job1", vec![5., 0.]),
+        create_multi_job("job2", vec![((2., 0.), 1., vec![1]), ((4., 0.), 1., vec![1])], vec![((6., 0.), 1., vec![2])]),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = get_core_problem(problem, matrix);
+    let solution = create_solution_with_stop(create_stop_with_activity_with_tag(
+        "job2",
+        "pickup",
+        (2., 0.),
+        1,
+        ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
+        2,
+        "1",
+    ));
+
+    let solution = read_init_solution(BufReader::new(to_string(&solution).as_bytes()), core_problem).unwrap();
+
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes.first().unwrap().tour.job_count(), 0);
+    assert_eq!(solution.routes.first().unwrap().tour.activity_count(), 0);
+    let mut unassigned =
+        solution.unassigned.keys().map(|job| job.dimens().get_id().unwrap().clone()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, vec!["job1", "job2"]);
+}
+
+#[test]
+fn can_return_error_for_unexpected_job_location() {
+    let problem = create_test_problem(vec![create_delivery_job("job1", vec![5., 0.])]);
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = get_core_problem(problem, matrix);
+    let solution = create_solution_with_stop(create_stop_with_activity(
+        "job1",
+        "delivery",
+        (0., 0.),
+        0,
+        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+        5,
+    ));
+
+    let result = read_init_solution(BufReader::new(to_string(&solution).as_bytes()), core_problem);
+
+    assert_eq!(result.err(), Some("cannot match activity 'delivery' of job 'job1'".to_string()));
+}
@@ -9,7 +9,11 @@ fn can_create_solution() {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -69,6 +73,7 @@ fn can_create_solution() {
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -83,7 +88,11 @@ fn can_merge_activities_in_one_stop() {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![5., 0.])],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            drivers: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -129,7 +138,8 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:05Z".to_string(),
                                     end: "1970-01-01T00:00:06Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                lateness: None
                             },
                             Activity {
                                 job_id: "job1".to_string(),
@@ -139,7 +149,8 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:06Z".to_string(),
                                     end: "1970-01-01T00:00:07Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                lateness: None
                             }
                         ]
                     },
@@ -158,6 +169,7 @@ fn can_merge_activities_in_one_stop() {
                     duration: 12,
                     times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
                 },
+                driver_id: None,
             }],
             unassigned: vec![],
             extras: None,
@@ -0,0 +1,86 @@
+use super::*;
+use crate::helpers::create_default_profiles;
+use std::cell::{Cell, RefCell};
+use std::env::temp_dir;
+use std::rc::Rc;
+use uuid::Uuid;
+
+struct CountingProvider {
+    calls: Cell<usize>,
+}
+
+impl RoutingProvider for CountingProvider {
+    fn source(&self) -> String {
+        "counting".to_string()
+    }
+
+    fn get_matrix(&self, profile: &Profile, locations: &[Location]) -> Result<Matrix, String> {
+        self.calls.set(self.calls.get() + 1);
+
+        let size = locations.len() * locations.len();
+        Ok(Matrix {
+            profile: profile.name.clone(),
+            timestamp: None,
+            travel_times: (0..size as i64).collect(),
+            distances: (0..size as i64).map(|value| value * 2).collect(),
+            error_codes: None,
+        })
+    }
+}
+
+#[test]
+fn can_use_cached_matrix() {
+    let directory = temp_dir().join(Uuid::new_v4().to_string());
+    let provider = CachedRoutingProvider::new(CountingProvider { calls: Cell::new(0) }, directory.clone());
+    let profile = create_default_profiles().first().unwrap().clone();
+    let other_profile = Profile { name: "other".to_string(), ..profile.clone() };
+    let locations = vec![Location::new(52.1, 13.1), Location::new(52.2, 13.2)];
+
+    let original = provider.get_matrix(&profile, locations.as_slice()).unwrap();
+    let cached = provider.get_matrix(&other_profile, locations.as_slice()).unwrap();
+    provider.get_matrix(&profile, &locations[0..1]).unwrap();
+
+    assert_eq!(provider.inner.calls.get(), 2);
+    assert_eq!(cached.profile, "other");
+    assert_eq!(cached.travel_times, original.travel_times);
+    assert_eq!(cached.distances, original.distances);
+    assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 2);
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn can_use_different_cache_entries_for_different_profile_types() {
+    let provider = CachedRoutingProvider::new(CountingProvider { calls: Cell::new(0) }, PathBuf::from("routing_cache"));
+    let profile = create_default_profiles().first().unwrap().clone();
+    let truck_profile = Profile { profile_type: "truck".to_string(), ..profile.clone() };
+    let locations = vec![Location::new(52.1, 13.1)];
+
+    let car_path = provider.get_path(&profile, locations.as_slice());
+    let truck_path = provider.get_path(&truck_profile, locations.as_slice());
+
+    assert_ne!(car_path, truck_path);
+    assert_eq!(car_path, provider.get_path(&profile, locations.clone().as_slice()));
+}
+
+#[test]
+fn can_return_matrix_when_cache_cannot_be_written() {
+    let file = temp_dir().join(Uuid::new_v4().to_string());
+    std::fs::write(&file, b"not a directory").unwrap();
+    let messages = Rc::new(RefCell::new(Vec::<String>::new()));
+    let logger = {
+        let messages = messages.clone();
+        Box::new(move |msg: String| messages.borrow_mut().push(msg))
+    };
+    let provider =
+        CachedRoutingProvider::new_with_logger(CountingProvider { calls: Cell::new(0) }, file.clone(), logger);
+    let profile = create_default_profiles().first().unwrap().clone();
+
+    let matrix = provider.get_matrix(&profile, &[Location::new(52.1, 13.1)]).unwrap();
+
+    assert_eq!(matrix.travel_times, vec![0]);
+    assert_eq!(messages.borrow().len(), 1);
+    assert!(messages.borrow()[0].starts_with("cannot write routing cache"));
+
+    std::fs::remove_file(file).unwrap();
+}
@@ -0,0 +1,76 @@
+use super::*;
+use crate::helpers::start_mock_server;
+
+parameterized_test! {can_parse_url, (url, expected), {
+    can_parse_url_impl(url, expected);
+}}
+
+can_parse_url! {
+    case01: ("http://localhost:5000/table/v1", Ok(("localhost:5000", "/table/v1"))),
+    case02: ("http://localhost", Ok(("localhost:80", "/"))),
+    case03: ("http://127.0.0.1:8002/sources_to_targets?a=b", Ok(("127.0.0.1:8002", "/sources_to_targets?a=b"))),
+    case04: ("https://localhost:5000", Err(())),
+    case05: ("http:///table", Err(())),
+}
+
+fn can_parse_url_impl(url: &str, expected: Result<(&str, &str), ()>) {
+    let result = parse_url(url);
+
+    match expected {
+        Ok((authority, path)) => assert_eq!(result, Ok((authority.to_string(), path.to_string()))),
+        Err(_) => assert!(result.is_err()),
+    }
+}
+
+parameterized_test! {can_parse_response, (response, expected), {
+    can_parse_response_impl(response, expected);
+}}
+
+can_parse_response! {
+    case01: ("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n{}{}", Ok("{}{}")),
+    case02: ("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}garbage", Ok("{}")),
+    case03: ("HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\n{\"a\r\n5\r\n\":1}\n\r\n0\r\n\r\n", Ok("{\"a\":1}\n")),
+    case04: ("HTTP/1.0 200 OK\r\n\r\n{}", Ok("{}")),
+    case05: ("HTTP/1.1 400 Bad Request\r\nContent-Length: 2\r\n\r\n{}", Err(())),
+    case06: ("HTTP/1.1 200 OK\r\n", Err(())),
+}
+
+fn can_parse_response_impl(response: &str, expected: Result<&str, ()>) {
+    let result = parse_response(response.as_bytes());
+
+    match expected {
+        Ok(body) => assert_eq!(result, Ok(body.to_string())),
+        Err(_) => assert!(result.is_err()),
+    }
+}
+
+#[test]
+fn can_send_request_with_body() {
+    let (url, requests) = start_mock_server(|request| (200, format!("{{\"echo\":{}}}", request.body)));
+
+    let result = send_request("POST", format!("{}/echo", url).as_str(), Some("[1,2]"));
+
+    assert_eq!(result, Ok("{\"echo\":[1,2]}".to_string()));
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/echo");
+}
+
+#[test]
+fn can_return_error_when_service_is_not_available() {
+    let (url, _) = start_mock_server(|_| (503, "unavailable".to_string()));
+
+    let result = send_request("GET", url.as_str(), None);
+
+    assert_eq!(result, Err("routing service returned status 503: 'unavailable'".to_string()));
+}
+
+#[test]
+fn can_return_error_when_service_cannot_be_connected() {
+    let address = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+
+    let result = send_request("GET", format!("http://{}/table", address).as_str(), None);
+
+    assert!(result.unwrap_err().starts_with(format!("cannot connect to routing service '{}'", address).as_str()));
+}
@@ -0,0 +1,191 @@
+use super::*;
+use crate::helpers::*;
+use crate::json::problem::{Fleet, Plan, Problem};
+use serde_json::Value;
+
+/// Returns absolute longitude difference as duration and ten times more as distance.
+fn get_test_route(from: (f64, f64), to: (f64, f64)) -> Option<(f64, f64)> {
+    if from.0 < 0. || to.0 < 0. {
+        None
+    } else {
+        let duration = (from.1 - to.1).abs();
+        Some((duration, duration * 10.))
+    }
+}
+
+fn handle_osrm_request(request: &MockRequest) -> (u16, String) {
+    let (path, query) = request.path.split_at(request.path.find('?').unwrap());
+    let coordinates = path
+        .split('/')
+        .last()
+        .unwrap()
+        .split(';')
+        .map(|pair| {
+            let mut values = pair.split(',').map(|value| value.parse::<f64>().unwrap());
+            let lng = values.next().unwrap();
+            (values.next().unwrap(), lng)
+        })
+        .collect::<Vec<_>>();
+    let get_indices = |name: &str| {
+        query
+            .trim_start_matches('?')
+            .split('&')
+            .find(|param| param.starts_with(name))
+            .unwrap()
+            .trim_start_matches(name)
+            .split(';')
+            .map(|idx| coordinates[idx.parse::<usize>().unwrap()])
+            .collect::<Vec<_>>()
+    };
+    let (sources, destinations) = (get_indices("sources="), get_indices("destinations="));
+    let get_table = |selector: fn((f64, f64)) -> f64| {
+        sources
+            .iter()
+            .map(|&from| destinations.iter().map(|&to| get_test_route(from, to).map(selector)).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+
+    let response = json!({
+        "code": "Ok",
+        "durations": get_table(|(duration, _)| duration),
+        "distances": get_table(|(_, distance)| distance),
+    });
+
+    (200, response.to_string())
+}
+
+fn handle_valhalla_request(request: &MockRequest) -> (u16, String) {
+    let body: Value = serde_json::from_str(request.body.as_str()).unwrap();
+    let get_points = |name: &str| {
+        body[name]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|point| (point["lat"].as_f64().unwrap(), point["lon"].as_f64().unwrap()))
+            .collect::<Vec<_>>()
+    };
+    let (sources, targets) = (get_points("sources"), get_points("targets"));
+
+    let response = json!({
+        "sources_to_targets": sources.iter().map(|&from| {
+            targets.iter().map(|&to| {
+                let route = get_test_route(from, to);
+                json!({ "time": route.map(|(duration, _)| duration), "distance": route.map(|(_, distance)| distance / 1000.) })
+            }).collect::<Vec<_>>()
+        }).collect::<Vec<_>>()
+    });
+
+    (200, response.to_string())
+}
+
+fn create_test_locations(size: usize) -> Vec<Location> {
+    (0..size).map(|idx| Location::new(0., idx as f64)).collect()
+}
+
+fn get_expected_data(locations: &[Location]) -> (Vec<i64>, Vec<i64>) {
+    locations
+        .iter()
+        .flat_map(|from| {
+            locations.iter().map(move |to| {
+                get_test_route(from.to_lat_lng().unwrap(), to.to_lat_lng().unwrap())
+                    .map_or((-1, -1), |(duration, distance)| (duration as i64, distance as i64))
+            })
+        })
+        .unzip()
+}
+
+parameterized_test! {can_get_matrix_using_chunks, (api, size, chunk_size, expected_requests), {
+    can_get_matrix_using_chunks_impl(api, size, chunk_size, expected_requests);
+}}
+
+can_get_matrix_using_chunks! {
+    case01: (TableApi::Osrm, 5, 5, 1),
+    case02: (TableApi::Osrm, 5, 2, 9),
+    case03: (TableApi::Osrm, 3, 1, 9),
+    case04: (TableApi::Valhalla, 5, 10, 1),
+    case05: (TableApi::Valhalla, 5, 3, 4),
+}
+
+fn can_get_matrix_using_chunks_impl(api: TableApi, size: usize, chunk_size: usize, expected_requests: usize) {
+    let (url, requests) = start_mock_server(move |request| match api {
+        TableApi::Osrm => handle_osrm_request(request),
+        TableApi::Valhalla => handle_valhalla_request(request),
+    });
+    let locations = create_test_locations(size);
+    let provider = TableRoutingProvider::new_with_chunk_size(url.as_str(), api, chunk_size).unwrap();
+
+    let matrix = provider.get_matrix(create_default_profiles().first().unwrap(), locations.as_slice()).unwrap();
+
+    let (expected_durations, expected_distances) = get_expected_data(locations.as_slice());
+    assert_eq!(matrix.profile, "car");
+    assert_eq!(matrix.travel_times, expected_durations);
+    assert_eq!(matrix.distances, expected_distances);
+    assert!(matrix.error_codes.is_none());
+    assert_eq!(requests.lock().unwrap().len(), expected_requests);
+}
+
+#[test]
+fn can_mark_unreachable_locations_with_error_codes() {
+    let (url, _) = start_mock_server(handle_osrm_request);
+    let locations = vec![Location::new(0., 0.), Location::new(-1., 1.), Location::new(0., 2.)];
+    let provider = TableRoutingProvider::new_with_chunk_size(url.as_str(), TableApi::Osrm, 2).unwrap();
+
+    let matrix = provider.get_matrix(create_default_profiles().first().unwrap(), locations.as_slice()).unwrap();
+
+    assert_eq!(matrix.travel_times, vec![0, -1, 2, -1, -1, -1, 2, -1, 0]);
+    assert_eq!(matrix.distances, vec![0, -1, 20, -1, -1, -1, 20, -1, 0]);
+    assert_eq!(matrix.error_codes, Some(vec![0, 1, 0, 1, 1, 1, 0, 1, 0]));
+}
+
+#[test]
+fn can_return_error_from_osrm_response() {
+    let (url, _) = start_mock_server(|_| (200, json!({ "code": "NoTable", "message": "no table found" }).to_string()));
+    let provider = TableRoutingProvider::new(url.as_str(), TableApi::Osrm);
+
+    let result = provider.get_matrix(create_default_profiles().first().unwrap(), create_test_locations(2).as_slice());
+
+    assert_eq!(result.err(), Some("osrm returned error code 'NoTable': 'no table found'".to_string()));
+}
+
+#[test]
+fn can_return_error_for_zero_chunk_size() {
+    let result = TableRoutingProvider::new_with_chunk_size("http://localhost:5000", TableApi::Osrm, 0);
+
+    assert_eq!(result.err(), Some("table chunk size should be greater than zero".to_string()));
+}
+
+#[test]
+fn can_return_error_for_location_references() {
+    let provider = TableRoutingProvider::new("http://localhost:5000", TableApi::Osrm);
+
+    let result = provider.get_matrix(create_default_profiles().first().unwrap(), &[Location::new_reference(0)]);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_get_routing_matrices_for_all_profiles() {
+    let (url, _) = start_mock_server(handle_osrm_request);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![0., 1.]), create_delivery_job("job2", vec![0., 3.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: vec![
+                Profile { name: "car".to_string(), ..create_default_profiles().first().unwrap().clone() },
+                Profile { name: "truck".to_string(), ..create_default_profiles().first().unwrap().clone() },
+            ],
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let matrices =
+        crate::routing::get_routing_matrices(&problem, &TableRoutingProvider::new(url.as_str(), TableApi::Osrm))
+            .unwrap();
+
+    assert_eq!(matrices.iter().map(|matrix| matrix.profile.as_str()).collect::<Vec<_>>(), vec!["car", "truck"]);
+    assert_eq!(matrices[0].travel_times, vec![0, 2, 1, 2, 0, 3, 1, 3, 0]);
+}
@@ -4,10 +4,9 @@ use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
 
 #[test]
 fn can_calculate_distance_between_two_locations() {
-    let l1 = Location { lat: 52.52599, lng: 13.45413 };
-    let l2 = Location { lat: 52.5165, lng: 13.3808 };
+    let routing = ApproxRouting::new(1., 1., DistanceMetric::Haversine);
 
-    let distance = get_distance(&l1, &l2);
+    let distance = routing.distance((52.52599, 13.45413), (52.5165, 13.3808));
 
     assert_eq!(distance.round(), 5078.);
 }
@@ -15,12 +14,13 @@ fn can_calculate_distance_between_two_locations() {
 #[test]
 fn can_use_approximated_with_matrix_costs() {
     let locations = vec![
-        Location { lat: 52.52599, lng: 13.45413 },
-        Location { lat: 52.5225, lng: 13.4095 },
-        Location { lat: 52.5165, lng: 13.3808 },
+        Location::Coordinate { lat: 52.52599, lng: 13.45413 },
+        Location::Coordinate { lat: 52.5225, lng: 13.4095 },
+        Location::Coordinate { lat: 52.5165, lng: 13.3808 },
     ];
     let speed = 15.;
-    let (durations, distances) = get_approx_transportation(&locations, speed);
+    let routing = ApproxRouting::new(speed, 1., DistanceMetric::Haversine);
+    let (durations, distances) = get_approx_transportation_with_routing(&locations, &routing).unwrap();
 
     let costs = create_matrix_transport_cost(vec![MatrixData::new(0, durations, distances)])
         .expect("Cannot create matrix transport costs");
@@ -33,3 +33,36 @@ fn can_use_approximated_with_matrix_costs() {
         assert_eq!(duration.round(), (distance / speed).round());
     });
 }
+
+parameterized_test! {can_use_planar_metrics, (metric, expected), {
+    can_use_planar_metrics_impl(metric, expected);
+}}
+
+can_use_planar_metrics! {
+    case01: (DistanceMetric::Euclidean, 5072.),
+    case02: (DistanceMetric::Manhattan, 6017.),
+}
+
+fn can_use_planar_metrics_impl(metric: DistanceMetric, expected: f64) {
+    let locations = vec![
+        Location::Coordinate { lat: 52.52599, lng: 13.45413 },
+        Location::Coordinate { lat: 52.5165, lng: 13.3808 },
+    ];
+    let routing = ApproxRouting::new(10., 1., metric);
+
+    let (durations, distances) = get_approx_transportation_with_routing(&locations, &routing).unwrap();
+
+    assert_eq!(distances[1].round(), expected);
+    assert_eq!(distances[1], distances[2]);
+    assert_eq!(durations[1], distances[1] / 10.);
+}
+
+#[test]
+fn can_return_error_for_location_reference() {
+    let locations = vec![Location::new(52.52599, 13.45413), Location::new_reference(0)];
+    let routing = ApproxRouting::new(10., 1., DistanceMetric::Haversine);
+
+    let result = get_approx_transportation_with_routing(&locations, &routing);
+
+    assert!(result.is_err());
+}
@@ -1,4 +1,6 @@
 use super::*;
+use rand::SeedableRng;
+use vrp_core::utils::SeededRandom;
 
 #[test]
 fn can_generate_permutations() {
@@ -20,7 +22,7 @@ fn can_generate_permutations() {
 
 #[test]
 fn can_generate_split_permutations() {
-    let job_permutations = get_split_permutations(5, 3, 12);
+    let job_permutations = get_split_permutations(5, 3, 12, &mut StdRng::seed_from_u64(0));
 
     assert_eq!(job_permutations.len(), 12);
     job_permutations.iter().for_each(|permutation| {
@@ -31,10 +33,10 @@ fn can_generate_split_permutations() {
         assert_eq!(right, 3);
     });
 
-    let job_permutations = get_split_permutations(3, 0, 10);
+    let job_permutations = get_split_permutations(3, 0, 10, &mut StdRng::seed_from_u64(0));
     assert_eq!(job_permutations.len(), 6);
 
-    let job_permutations = get_split_permutations(3, 3, 10);
+    let job_permutations = get_split_permutations(3, 3, 10, &mut StdRng::seed_from_u64(0));
     assert_eq!(job_permutations.len(), 6);
 }
 
@@ -54,3 +56,14 @@ fn can_validate_permutations() {
     let permutator = VariableJobPermutation::new(3, 1, 3);
     assert!(permutator.validate(&vec![0, 1, 2]));
 }
+
+#[test]
+fn can_sample_same_permutations_with_same_seed() {
+    let permutator = VariableJobPermutation::new(6, 3, 8);
+
+    let first = permutator.get(&SeededRandom::new(42));
+    let second = permutator.get(&SeededRandom::new(42));
+
+    assert_eq!(first.len(), 8);
+    assert_eq!(first, second);
+}
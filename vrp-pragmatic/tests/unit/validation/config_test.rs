@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::create_empty_problem;
+
+parameterized_test! {can_detect_invalid_solver_config, (config, expected), {
+    can_detect_invalid_solver_config_impl(config, expected);
+}}
+
+can_detect_invalid_solver_config! {
+    case01: (r#"{ "termination": { "maxGenerations": 10 } }"#, None),
+    case02: (r#"{ "mutation": { "recreates": [] } }"#, Some("E1700")),
+    case03: (r#"{ "mutation": { "recreates": [{ "type": "cheapest", "weight": 0 }] } }"#, Some("E1700")),
+    case04: (r#"{ "mutation": { "recreates": [{ "type": "regret", "weight": 1, "start": 3, "end": 2 }] } }"#, Some("E1700")),
+    case05: (r#"{ "mutation": { "ruins": [] } }"#, Some("E1700")),
+    case06: (r#"{ "mutation": { "ruins": [{ "weight": 1, "methods": [
+        { "type": "random-job", "probability": 1.5, "min": 1, "max": 2, "threshold": 0.1 }
+    ] }] } }"#, Some("E1700")),
+    case07: (r#"{ "mutation": { "ruins": [{ "weight": 1, "methods": [
+        { "type": "random-route", "probability": 1, "min": 3, "max": 2, "threshold": 0.1 }
+    ] }] } }"#, Some("E1700")),
+    case08: (r#"{ "mutation": { "ruins": [{ "weight": 1, "methods": [
+        { "type": "random-route", "probability": 1, "min": 1, "max": 2, "threshold": 0.1 }
+    ] }] } }"#, None),
+    case09: (r#"{ "population": { "size": 0 } }"#, Some("E1700")),
+    case10: (r#"{ "islands": { "amount": 0 } }"#, Some("E1700")),
+    case11: (r#"{ "acceptance": { "type": "annealing", "initialTemperature": 0 } }"#, Some("E1700")),
+    case12: (r#"{ "acceptance": { "type": "annealing", "finalTemperature": -0.1 } }"#, Some("E1700")),
+}
+
+fn can_detect_invalid_solver_config_impl(config: &str, expected: Option<&str>) {
+    let problem = create_problem_with_config(config);
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1700_invalid_solver_config(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_neighbourhood_config, (config, expected), {
+    can_detect_invalid_neighbourhood_config_impl(config, expected);
+}}
+
+can_detect_invalid_neighbourhood_config! {
+    case01: (r#"{ "neighbourhood": { "maxNeighbours": 16 } }"#, None),
+    case02: (r#"{ "neighbourhood": { "isLazy": true } }"#, None),
+    case03: (r#"{ "neighbourhood": { "maxNeighbours": 0 } }"#, Some("E1701")),
+}
+
+fn can_detect_invalid_neighbourhood_config_impl(config: &str, expected: Option<&str>) {
+    let problem = create_problem_with_config(config);
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1701_invalid_neighbourhood_config(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+fn create_problem_with_config(config: &str) -> Problem {
+    Problem { config: Some(serde_json::from_str(config).unwrap()), ..create_empty_problem() }
+}
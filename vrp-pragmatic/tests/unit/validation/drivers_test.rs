@@ -0,0 +1,58 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_problem_with_drivers(drivers: Vec<Driver>) -> Problem {
+    Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            drivers: Some(drivers),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_detect_duplicated_driver_ids() {
+    let problem = create_problem_with_drivers(vec![
+        create_default_driver("driver1"),
+        create_default_driver("driver2"),
+        create_default_driver("driver1"),
+    ]);
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1400_no_drivers_with_duplicate_ids(&ctx);
+
+    assert_eq!(
+        result.err().map(|err| (err.code, err.action)),
+        Some(("E1400".to_string(), "remove duplicated driver ids: driver1".to_string()))
+    );
+}
+
+parameterized_test! {can_detect_invalid_driver_shift_time, (shifts, expected), {
+    can_detect_invalid_driver_shift_time_impl(shifts, expected);
+}}
+
+can_detect_invalid_driver_shift_time! {
+    case01: (vec![], None),
+    case02: (vec![("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z")], None),
+    case03: (vec![("1970-01-01T00:01:40Z", "1970-01-01T00:00:00Z")], Some("E1401")),
+    case04: (vec![("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z"), ("1970-01-01T00:01:00Z", "1970-01-01T00:02:00Z")], Some("E1401")),
+    case05: (vec![("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z"), ("1970-01-01T00:02:00Z", "1970-01-01T00:03:00Z")], None),
+    case06: (vec![("invalid", "1970-01-01T00:01:40Z")], Some("E1401")),
+}
+
+fn can_detect_invalid_driver_shift_time_impl(shifts: Vec<(&str, &str)>, expected: Option<&str>) {
+    let problem = create_problem_with_drivers(vec![Driver {
+        shifts: shifts
+            .into_iter()
+            .map(|(start, end)| DriverShift { time: vec![start.to_string(), end.to_string()] })
+            .collect(),
+        ..create_default_driver("driver1")
+    }]);
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1401_driver_shift_time(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
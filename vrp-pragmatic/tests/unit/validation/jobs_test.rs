@@ -16,7 +16,7 @@ can_detect_reserved_ids! {
 fn can_detect_reserved_ids_impl(job_id: String, expected: Option<&str>) {
     let problem = Problem {
         plan: Plan { jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])], relations: None },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], drivers: None },
         ..create_empty_problem()
     };
 
@@ -42,6 +42,9 @@ fn can_detect_empty_job() {
                 services: None,
                 priority: None,
                 skills: None,
+                max_ride_time: None,
+                compatibility: None,
+                group: None,
             }],
             relations: None,
         },
@@ -53,3 +56,114 @@ fn can_detect_empty_job() {
     assert_eq!(result.clone().map(|err| err.code), Some("E1105".to_string()));
     assert!(result.map_or("".to_string(), |err| err.action).contains("job1"));
 }
+
+parameterized_test! {can_detect_invalid_lateness, (max, cost, expected), {
+    can_detect_invalid_lateness_impl(max, cost, expected);
+}}
+
+can_detect_invalid_lateness! {
+    case01: (600., 1., None),
+    case02: (0., 0., None),
+    case03: (-1., 1., Some("E1106")),
+    case04: (600., -1., Some("E1106")),
+}
+
+fn can_detect_invalid_lateness_impl(max: f64, cost: f64, expected: Option<&str>) {
+    let mut job = create_delivery_job("job1", vec![1., 0.]);
+    job.deliveries.as_mut().unwrap()[0].places[0].lateness = Some(JobPlaceLateness { max, cost });
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], drivers: None },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1106_correct_lateness(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_max_ride_time, (pickups, deliveries, max_ride_time, expected), {
+    can_detect_invalid_max_ride_time_impl(pickups, deliveries, max_ride_time, expected);
+}}
+
+can_detect_invalid_max_ride_time! {
+    case01: (1, 1, Some(600.), None),
+    case02: (1, 1, None, None),
+    case03: (1, 1, Some(-1.), Some("E1107")),
+    case04: (0, 1, Some(600.), Some("E1107")),
+    case05: (2, 1, Some(600.), None),
+    case06: (1, 2, Some(600.), None),
+    case07: (2, 2, Some(600.), Some("E1107")),
+    case08: (2, 2, None, None),
+}
+
+fn can_detect_invalid_max_ride_time_impl(
+    pickups: usize,
+    deliveries: usize,
+    max_ride_time: Option<f64>,
+    expected: Option<&str>,
+) {
+    let create_tasks = |size: usize, location: f64| {
+        if size > 0 {
+            Some((0..size).map(|idx| create_task(vec![location + idx as f64, 0.])).collect())
+        } else {
+            None
+        }
+    };
+    let job = Job {
+        pickups: create_tasks(pickups, 1.),
+        deliveries: create_tasks(deliveries, 10.),
+        max_ride_time,
+        ..create_job("job1")
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], drivers: None },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1107_correct_max_ride_time(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_skills, (all_of, one_of, none_of, expected), {
+    can_detect_invalid_skills_impl(all_of, one_of, none_of, expected);
+}}
+
+can_detect_invalid_skills! {
+    case01: (Some(vec!["s1"]), Some(vec!["s2", "s3"]), Some(vec!["s4"]), None),
+    case02: (None, Some(vec!["s1"]), None, None),
+    case03: (None, None, None, Some("E1108")),
+    case04: (Some(vec![]), None, None, Some("E1108")),
+    case05: (Some(vec!["s1"]), None, Some(vec!["s1"]), Some("E1108")),
+    case06: (None, Some(vec!["s1", "s2"]), Some(vec!["s2"]), None),
+    case07: (None, Some(vec!["s1", "s2"]), Some(vec!["s1", "s2", "s3"]), Some("E1108")),
+    case08: (Some(vec!["s1"]), Some(vec!["s2"]), Some(vec!["s3"]), None),
+}
+
+fn can_detect_invalid_skills_impl(
+    all_of: Option<Vec<&str>>,
+    one_of: Option<Vec<&str>>,
+    none_of: Option<Vec<&str>>,
+    expected: Option<&str>,
+) {
+    let to_skills = |skills: Option<Vec<&str>>| skills.map(|skills| skills.iter().map(|s| s.to_string()).collect());
+    let skills = JobSkills::Sets(JobSkillSets {
+        all_of: to_skills(all_of),
+        one_of: to_skills(one_of),
+        none_of: to_skills(none_of),
+    });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { skills: Some(skills), ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], drivers: None },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1108_correct_skills(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -36,7 +36,7 @@ fn can_detect_relation_errors_impl(job_ids: Vec<String>, vehicle_id: String, exp
                 shift_index: None,
             }]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], drivers: None },
         ..create_empty_problem()
     };
 
@@ -81,7 +81,7 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
                 shift_index: None,
             }]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], drivers: None },
         ..create_empty_problem()
     };
 
@@ -127,6 +127,7 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("car"), create_default_vehicle("truck")],
             profiles: vec![],
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -142,3 +143,61 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
         _ => panic!(format!("{:?} vs {}", result, expected.is_some())),
     }
 }
+
+parameterized_test! {can_detect_incompatible_jobs, (compatibilities, has_reloads, expected), {
+    can_detect_incompatible_jobs_impl(compatibilities, has_reloads, expected);
+}}
+
+can_detect_incompatible_jobs! {
+    case01: ((Some("food"), Some("food")), false, None),
+    case02: ((Some("food"), None), false, None),
+    case03: ((Some("food"), Some("chemicals")), false, Some("job1, job2")),
+    case04: ((Some("food"), Some("chemicals")), true, None),
+}
+
+fn can_detect_incompatible_jobs_impl(
+    compatibilities: (Option<&str>, Option<&str>),
+    has_reloads: bool,
+    expected: Option<&str>,
+) {
+    let create_job = |id: &str, location: f64, compatibility: Option<&str>| Job {
+        compatibility: compatibility.map(|compatibility| compatibility.to_string()),
+        ..create_delivery_job(id, vec![location, 0.])
+    };
+    let reloads = if has_reloads {
+        Some(vec![VehicleReload { times: None, location: vec![0., 0.].to_loc(), duration: 2.0, tag: None }])
+    } else {
+        None
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job("job1", 1., compatibilities.0), create_job("job2", 2., compatibilities.1)],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Any,
+                jobs: vec!["job1".to_string(), "job2".to_string()],
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { reloads, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None));
+
+    match (&result, &expected) {
+        (Some(error), Some(jobs)) => {
+            assert_eq!(error.code, "E1205");
+            assert!(error.action.contains(jobs))
+        }
+        (None, None) => {}
+        _ => panic!(format!("{:?} vs {}", result, expected.is_some())),
+    }
+}
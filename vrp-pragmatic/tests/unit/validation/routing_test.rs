@@ -1,5 +1,6 @@
 use super::*;
-use crate::helpers::create_empty_problem;
+use crate::helpers::*;
+use crate::json::Location;
 
 #[test]
 fn can_detect_duplicates() {
@@ -7,9 +8,22 @@ fn can_detect_duplicates() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                Profile { name: "my_vehicle".to_string(), profile_type: "car".to_string() },
-                Profile { name: "my_vehicle".to_string(), profile_type: "truck".to_string() },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: None,
+                    circuity: None,
+                    metric: None,
+                },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "truck".to_string(),
+                    speed: None,
+                    circuity: None,
+                    metric: None,
+                },
             ],
+            drivers: None,
         },
         ..create_empty_problem()
     };
@@ -22,10 +36,129 @@ fn can_detect_duplicates() {
 
 #[test]
 fn can_detect_empty_profiles() {
-    let problem = Problem { fleet: Fleet { vehicles: vec![], profiles: vec![] }, ..create_empty_problem() };
+    let problem =
+        Problem { fleet: Fleet { vehicles: vec![], profiles: vec![], drivers: None }, ..create_empty_problem() };
     let ctx = ValidationContext::new(&problem, None);
 
     let result = check_e1501_empty_profiles(&ctx);
 
     assert_eq!(result.err().map(|err| err.code), Some("E1501".to_string()));
 }
+
+fn create_problem_with_locations(locations: Vec<Location>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: locations
+                .into_iter()
+                .enumerate()
+                .map(|(idx, location)| Job {
+                    services: Some(vec![JobTask {
+                        places: vec![JobPlace { location, ..create_job_place(vec![0., 0.]) }],
+                        demand: None,
+                        tag: None,
+                    }]),
+                    ..create_job(format!("job{}", idx).as_str())
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![], profiles: create_default_profiles(), drivers: None },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_detect_mixed_location_types, (locations, expected), {
+    can_detect_mixed_location_types_impl(locations, expected);
+}}
+
+can_detect_mixed_location_types! {
+    case01: (vec![Location::new(1., 0.), Location::new_reference(0)], Some("E1502".to_string())),
+    case02: (vec![Location::new(1., 0.), Location::new(2., 0.)], None),
+    case03: (vec![Location::new_reference(1), Location::new_reference(0)], None),
+}
+
+fn can_detect_mixed_location_types_impl(locations: Vec<Location>, expected: Option<String>) {
+    let problem = create_problem_with_locations(locations);
+    let coord_index = CoordIndex::new(&problem);
+
+    let result = check_e1502_mixed_location_types(&coord_index);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_missing_matrix_for_index_locations, (locations, has_matrix, expected), {
+    can_detect_missing_matrix_for_index_locations_impl(locations, has_matrix, expected);
+}}
+
+can_detect_missing_matrix_for_index_locations! {
+    case01: (vec![Location::new_reference(0)], false, Some("E1503".to_string())),
+    case02: (vec![Location::new_reference(0)], true, None),
+    case03: (vec![Location::new(1., 0.)], false, None),
+}
+
+fn can_detect_missing_matrix_for_index_locations_impl(
+    locations: Vec<Location>,
+    has_matrix: bool,
+    expected: Option<String>,
+) {
+    let problem = create_problem_with_locations(locations);
+    let matrices = vec![create_matrix(vec![0])];
+    let ctx = ValidationContext::new(&problem, if has_matrix { Some(&matrices) } else { None });
+
+    let result = check_e1503_no_matrix_with_index_locations(&ctx, &CoordIndex::new(&problem));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_too_small_matrix, (locations, matrix_size, expected), {
+    can_detect_too_small_matrix_impl(locations, matrix_size, expected);
+}}
+
+can_detect_too_small_matrix! {
+    case01: (vec![Location::new_reference(0), Location::new_reference(2)], 2, Some("E1504".to_string())),
+    case02: (vec![Location::new_reference(0), Location::new_reference(2)], 3, None),
+    case03: (vec![Location::new(1., 0.), Location::new(2., 0.)], 1, Some("E1504".to_string())),
+    case04: (vec![Location::new(1., 0.), Location::new(2., 0.)], 2, None),
+}
+
+fn can_detect_too_small_matrix_impl(locations: Vec<Location>, matrix_size: usize, expected: Option<String>) {
+    let problem = create_problem_with_locations(locations);
+    let matrices = vec![create_matrix(vec![0; matrix_size * matrix_size])];
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1504_matrix_is_too_small(&ctx, &CoordIndex::new(&problem));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_approximation_parameters, (speed, circuity, expected), {
+    can_detect_invalid_approximation_parameters_impl(speed, circuity, expected);
+}}
+
+can_detect_invalid_approximation_parameters! {
+    case01: (Some(10.), Some(1.2), None),
+    case02: (None, None, None),
+    case03: (Some(0.), None, Some("E1505".to_string())),
+    case04: (Some(-1.), None, Some("E1505".to_string())),
+    case05: (None, Some(0.5), Some("E1505".to_string())),
+}
+
+fn can_detect_invalid_approximation_parameters_impl(
+    speed: Option<f64>,
+    circuity: Option<f64>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![],
+            profiles: vec![Profile { speed, circuity, ..create_default_profiles().first().unwrap().clone() }],
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1505_invalid_approximation_parameters(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
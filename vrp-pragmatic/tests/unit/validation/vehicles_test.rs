@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_detect_invalid_time_costs, (waiting, service, overtime, expected), {
+    can_detect_invalid_time_costs_impl(waiting, service, overtime, expected);
+}}
+
+can_detect_invalid_time_costs! {
+    case01: (None, None, None, None),
+    case02: (Some(0.5), Some(2.), Some((3600., 1.)), None),
+    case03: (Some(-1.), None, None, Some("E1305")),
+    case04: (None, Some(-1.), None, Some("E1305")),
+    case05: (None, None, Some((-1., 1.)), Some("E1305")),
+    case06: (None, None, Some((3600., -1.)), Some("E1305")),
+}
+
+fn can_detect_invalid_time_costs_impl(
+    waiting: Option<f64>,
+    service: Option<f64>,
+    overtime: Option<(f64, f64)>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    waiting,
+                    service,
+                    overtime: overtime.map(|(threshold, time)| VehicleOvertimeCosts { threshold, time }),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle("vehicle")
+            }],
+            profiles: vec![],
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1305_vehicle_time_costs_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_tour_size_limits, (max_activities, max_jobs, min_jobs, expected), {
+    can_detect_invalid_tour_size_limits_impl(max_activities, max_jobs, min_jobs, expected);
+}}
+
+can_detect_invalid_tour_size_limits! {
+    case01: (None, Some(3), Some(2), None),
+    case02: (None, Some(2), Some(2), None),
+    case03: (Some(4), None, Some(2), None),
+    case04: (None, None, Some(2), None),
+    case05: (None, Some(1), Some(2), Some("E1306")),
+    case06: (Some(1), None, Some(2), Some("E1306")),
+}
+
+fn can_detect_invalid_tour_size_limits_impl(
+    max_activities: Option<usize>,
+    max_jobs: Option<usize>,
+    min_jobs: Option<usize>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    max_activities,
+                    max_jobs,
+                    min_jobs,
+                }),
+                ..create_default_vehicle("vehicle")
+            }],
+            profiles: vec![],
+            drivers: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1306_vehicle_tour_size_limits_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
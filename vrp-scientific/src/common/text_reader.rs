@@ -50,6 +50,7 @@ pub fn create_fleet_with_distance_costs(number: usize, capacity: usize, location
                 per_driving_time: 0.0,
                 per_waiting_time: 0.0,
                 per_service_time: 0.0,
+                overtime: None,
             },
             dimens: create_dimens_with_id("driver", 0),
             details: Default::default(),
@@ -66,6 +67,7 @@ pub fn create_fleet_with_distance_costs(number: usize, capacity: usize, location
                         per_driving_time: 0.0,
                         per_waiting_time: 0.0,
                         per_service_time: 0.0,
+                        overtime: None,
                     },
                     dimens,
                     details: vec![VehicleDetail {
@@ -1,7 +1,7 @@
 //! Contains functionality to read lilim problem and write its solution.
 
 mod reader;
-pub use self::reader::LilimProblem;
+pub use self::reader::{read_lilim_format_with_routing, LilimProblem};
 
 mod writer;
 pub use self::writer::LilimSolution;
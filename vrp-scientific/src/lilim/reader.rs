@@ -15,7 +15,15 @@ use vrp_core::models::Problem;
 use vrp_core::utils::TryCollect;
 
 pub fn read_lilim_format<R: Read>(reader: BufReader<R>) -> Result<Problem, String> {
-    LilimReader { buffer: String::new(), reader, matrix: MatrixFactory::default() }.read_problem()
+    read_lilim_format_with_routing(reader, ApproxRouting::default())
+}
+
+/// Reads lilim problem using given routing approximation to create routing matrix.
+pub fn read_lilim_format_with_routing<R: Read>(
+    reader: BufReader<R>,
+    routing: ApproxRouting,
+) -> Result<Problem, String> {
+    LilimReader { buffer: String::new(), reader, matrix: MatrixFactory::new(routing) }.read_problem()
 }
 
 /// A trait to read lilim problem.
@@ -1,7 +1,7 @@
 //! Contains functionality to read solomon problem and write its solution.
 
 mod reader;
-pub use self::reader::SolomonProblem;
+pub use self::reader::{read_solomon_format_with_routing, SolomonProblem};
 
 mod writer;
 pub use self::writer::SolomonSolution;
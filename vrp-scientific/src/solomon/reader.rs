@@ -14,7 +14,15 @@ use vrp_core::models::Problem;
 use vrp_core::utils::TryCollect;
 
 pub fn read_solomon_format<R: Read>(reader: BufReader<R>) -> Result<Problem, String> {
-    SolomonReader { buffer: String::new(), reader, matrix: MatrixFactory::default() }.read_problem()
+    read_solomon_format_with_routing(reader, ApproxRouting::default())
+}
+
+/// Reads solomon problem using given routing approximation to create routing matrix.
+pub fn read_solomon_format_with_routing<R: Read>(
+    reader: BufReader<R>,
+    routing: ApproxRouting,
+) -> Result<Problem, String> {
+    SolomonReader { buffer: String::new(), reader, matrix: MatrixFactory::new(routing) }.read_problem()
 }
 
 /// A trait read write solomon problem.
@@ -1,18 +1,24 @@
 use std::sync::Arc;
 use vrp_core::models::common::Location;
-use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, TransportCost};
+use vrp_core::models::problem::{create_matrix_transport_cost, ApproxRouting, MatrixData, TransportCost};
 
 pub struct MatrixFactory {
     locations: Vec<(i32, i32)>,
+    routing: ApproxRouting,
 }
 
 impl Default for MatrixFactory {
     fn default() -> Self {
-        Self { locations: vec![] }
+        Self::new(ApproxRouting::default())
     }
 }
 
 impl MatrixFactory {
+    /// Creates a new instance of `MatrixFactory` which uses given routing approximation.
+    pub fn new(routing: ApproxRouting) -> Self {
+        Self { locations: vec![], routing }
+    }
+
     pub fn collect(&mut self, location: (i32, i32)) -> Location {
         match self.locations.iter().position(|l| l.0 == location.0 && l.1 == location.1) {
             Some(position) => position,
@@ -24,19 +30,10 @@ impl MatrixFactory {
     }
 
     pub fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
-        let matrix_values = self
-            .locations
-            .iter()
-            .flat_map(|&(x1, y1)| {
-                self.locations.iter().map(move |&(x2, y2)| {
-                    let x = x1 as f64 - x2 as f64;
-                    let y = y1 as f64 - y2 as f64;
-                    (x * x + y * y).sqrt()
-                })
-            })
-            .collect::<Vec<f64>>();
+        let points = self.locations.iter().map(|&(x, y)| (x as f64, y as f64)).collect::<Vec<_>>();
+        let (durations, distances) = self.routing.get_matrices(points.as_slice());
 
-        let matrix_data = MatrixData::new(0, matrix_values.clone(), matrix_values);
+        let matrix_data = MatrixData::new(0, durations, distances);
 
         create_matrix_transport_cost(vec![matrix_data])
     }
@@ -1,5 +1,7 @@
 use crate::helpers::*;
-use crate::solomon::SolomonProblem;
+use crate::solomon::{read_solomon_format_with_routing, SolomonProblem};
+use std::io::BufReader;
+use vrp_core::models::problem::{ApproxRouting, DistanceMetric};
 
 #[test]
 fn can_read_solomon_built_from_builder() {
@@ -37,3 +39,22 @@ fn can_read_solomon_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+#[test]
+fn can_read_solomon_with_custom_routing() {
+    let problem = SolomonBuilder::new()
+        .set_title("Two customers")
+        .set_vehicle((1, 10))
+        .add_customer((0, 0, 0, 0, 0, 1000, 1))
+        .add_customer((1, 3, 4, 1, 5, 1000, 5))
+        .build();
+
+    let problem = read_solomon_format_with_routing(
+        BufReader::new(problem.as_bytes()),
+        ApproxRouting::new(2., 1., DistanceMetric::Manhattan),
+    )
+    .unwrap();
+
+    assert_eq!(problem.transport.distance(0, 0, 1, 0.), 7.);
+    assert_eq!(problem.transport.duration(0, 0, 1, 0.), 3.5);
+}
@@ -1,26 +1,52 @@
-use crate::extensions::SimplePopulation;
+use crate::extensions::{SharedQuota, SimplePopulation};
+use std::cmp::Ordering::Less;
 use std::ops::Deref;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::construction::Quota;
-use vrp_core::models::{Problem, Solution};
+use vrp_core::models::{Extras, Problem, Solution};
 use vrp_core::refinement::acceptance::{Acceptance, Greedy};
-use vrp_core::refinement::mutation::{Mutation, RuinAndRecreateMutation};
+use vrp_core::refinement::mutation::{Mutation, MutationOutcome, RuinAndRecreateMutation};
 use vrp_core::refinement::objectives::ObjectiveCost;
 use vrp_core::refinement::selection::{SelectRandom, Selection};
 use vrp_core::refinement::termination::*;
-use vrp_core::refinement::{Individuum, RefinementContext};
-use vrp_core::utils::{DefaultRandom, Timer};
+use vrp_core::refinement::{Individuum, Progress, RefinementContext};
+use vrp_core::utils::{parallel_into_collect, DefaultRandom, Random, SeededRandom, Timer};
+
+/// Specifies island model settings.
+pub struct IslandModel {
+    /// Amount of islands: independent refinement loops, each with its own population.
+    pub islands: usize,
+    /// Amount of generations after which islands exchange their best individuums.
+    pub migration_interval: usize,
+}
+
+impl Default for IslandModel {
+    fn default() -> Self {
+        Self { islands: 1, migration_interval: 100 }
+    }
+}
+
+/// A key of refinement context state which keeps collected telemetry.
+const TELEMETRY_KEY: &str = "telemetry";
+
+/// Specifies a callback which receives solving progress.
+pub type ProgressCallback = Box<dyn Fn(&Progress) + Send + Sync>;
 
 /// A skeleton of metaheuristic with default ruin and recreate implementation.
 pub struct Solver {
-    pub selection: Box<dyn Selection>,
-    pub mutation: Box<dyn Mutation>,
-    pub acceptance: Box<dyn Acceptance>,
-    pub termination: Box<dyn Termination>,
+    pub selection: Box<dyn Selection + Send + Sync>,
+    pub mutation: Box<dyn Mutation + Send + Sync>,
+    pub acceptance: Box<dyn Acceptance + Send + Sync>,
+    pub termination: Box<dyn Termination + Send + Sync>,
     pub quota: Option<Box<dyn Quota + Sync + Send>>,
     pub initial: Option<InsertionContext>,
-    pub logger: Box<dyn Fn(String) -> ()>,
+    pub random: Arc<dyn Random + Send + Sync>,
+    pub island_model: IslandModel,
+    pub progress: Option<ProgressCallback>,
+    pub telemetry_interval: Option<usize>,
+    pub population_size: usize,
+    pub logger: Box<dyn Fn(String) -> () + Send + Sync>,
 }
 
 impl Default for Solver {
@@ -40,76 +66,249 @@ impl Default for Solver {
 impl Solver {
     /// Creates a new instance of [`Solver`].
     pub fn new(
-        selection: Box<dyn Selection>,
-        mutation: Box<dyn Mutation>,
-        acceptance: Box<dyn Acceptance>,
-        termination: Box<dyn Termination>,
+        selection: Box<dyn Selection + Send + Sync>,
+        mutation: Box<dyn Mutation + Send + Sync>,
+        acceptance: Box<dyn Acceptance + Send + Sync>,
+        termination: Box<dyn Termination + Send + Sync>,
         quota: Option<Box<dyn Quota + Sync + Send>>,
         initial: Option<InsertionContext>,
-        logger: Box<dyn Fn(String) -> ()>,
+        logger: Box<dyn Fn(String) -> () + Send + Sync>,
     ) -> Self {
-        Self { selection, mutation, acceptance, termination, quota, initial, logger }
+        Self {
+            selection,
+            mutation,
+            acceptance,
+            termination,
+            quota,
+            initial,
+            random: Arc::new(DefaultRandom::default()),
+            island_model: IslandModel::default(),
+            progress: None,
+            telemetry_interval: None,
+            population_size: 5,
+            logger,
+        }
     }
 
     /// Solves given problem and returns solution, its cost and generation when it is found.
     /// Return None if no solution found.
     pub fn solve(&mut self, problem: Arc<Problem>) -> Option<(Solution, Box<dyn ObjectiveCost + Send + Sync>, usize)> {
-        let mut refinement_ctx =
-            RefinementContext::new_with_population(problem.clone(), Box::new(SimplePopulation::new(5)));
+        let quota = std::mem::replace(&mut self.quota, None);
+        let initial = std::mem::replace(&mut self.initial, None);
+        let islands = get_islands_amount(self.island_model.islands);
 
-        if let Some(quota) = std::mem::replace(&mut self.quota, None) {
-            refinement_ctx.set_quota(quota);
-        }
+        let refinement_time = Timer::start();
+        let refinement_ctx = if islands > 1 {
+            self.solve_with_islands(problem, initial, quota, islands, &refinement_time)
+        } else {
+            let mut refinement_ctx = self.create_refinement_ctx(problem, quota);
+            let insertion_ctx = self.create_insertion_ctx(&mut refinement_ctx, initial, self.random.clone());
 
-        let mut insertion_ctx = match std::mem::replace(&mut self.initial, None) {
-            Some(ctx) => {
-                let cost = problem.objective.estimate_cost(&mut RefinementContext::new(problem.clone()), &ctx);
-                refinement_ctx.population.add((ctx.deep_copy(), cost, 1));
-                ctx
-            }
-            None => InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default())),
+            self.refine(&mut refinement_ctx, insertion_ctx, None, &refinement_time, true);
+            self.log_speed(refinement_ctx.generation, &refinement_time);
+
+            refinement_ctx
         };
 
-        let refinement_time = Timer::start();
+        self.get_result(refinement_ctx)
+    }
+
+    /// Runs multiple independent refinement loops which exchange their best individuums after each
+    /// migration interval. Termination criteria are checked per island, so generation limit is
+    /// applied to each island. Returns refinement context of the island with the best individuum.
+    fn solve_with_islands(
+        &self,
+        problem: Arc<Problem>,
+        initial: Option<InsertionContext>,
+        quota: Option<Box<dyn Quota + Sync + Send>>,
+        islands: usize,
+        refinement_time: &Timer,
+    ) -> RefinementContext {
+        let quota = quota.map(Arc::new);
+        let mut states = (0..islands)
+            .map(|_| {
+                // NOTE each island has its own random to keep results reproducible
+                let random: Arc<dyn Random + Send + Sync> =
+                    Arc::new(SeededRandom::new(self.random.uniform_int(0, std::i32::MAX - 1) as u64));
+                let quota =
+                    quota.clone().map::<Box<dyn Quota + Sync + Send>, _>(|quota| Box::new(SharedQuota::new(quota)));
+                let initial =
+                    initial.as_ref().map(|ctx| InsertionContext { random: random.clone(), ..ctx.deep_copy() });
+
+                let mut refinement_ctx = self.create_refinement_ctx(problem.clone(), quota);
+                let insertion_ctx = self.create_insertion_ctx(&mut refinement_ctx, initial, random);
+
+                (refinement_ctx, insertion_ctx, false)
+            })
+            .collect::<Vec<_>>();
+
+        let mut epoch = 0;
+        while states.iter().any(|(_, _, is_terminated)| !is_terminated) {
+            states = parallel_into_collect(states, |(mut refinement_ctx, insertion_ctx, is_terminated)| {
+                if is_terminated {
+                    (refinement_ctx, insertion_ctx, is_terminated)
+                } else {
+                    let limit = Some(self.island_model.migration_interval);
+                    let (insertion_ctx, is_terminated) =
+                        self.refine(&mut refinement_ctx, insertion_ctx, limit, refinement_time, false);
+
+                    (refinement_ctx, insertion_ctx, is_terminated)
+                }
+            });
+
+            epoch += 1;
+            migrate(&mut states);
+            self.log_islands(epoch, &states, refinement_time);
+
+            if let Some(idx) = get_best_island(&states) {
+                self.report_progress(&states[idx].0, refinement_time);
+            }
+        }
+
+        let generations = states.iter().map(|(refinement_ctx, _, _)| refinement_ctx.generation).sum();
+        let best_idx = get_best_island(&states).expect("no solution found by islands");
+        let (refinement_ctx, _, _) = states.swap_remove(best_idx);
+
+        self.log_speed(generations, refinement_time);
+
+        refinement_ctx
+    }
+
+    /// Runs refinement loop till termination or till generations limit is reached. When verbose,
+    /// logs and reports progress on each generation. Telemetry is collected regardless of verbosity.
+    /// Returns last selected insertion context and true if termination criteria is met.
+    fn refine(
+        &self,
+        refinement_ctx: &mut RefinementContext,
+        insertion_ctx: InsertionContext,
+        limit: Option<usize>,
+        refinement_time: &Timer,
+        is_verbose: bool,
+    ) -> (InsertionContext, bool) {
+        let problem = refinement_ctx.problem.clone();
+        let last_generation = limit.map(|limit| refinement_ctx.generation + limit);
+        let mut insertion_ctx = insertion_ctx;
+
         loop {
             let generation_time = Timer::start();
 
-            insertion_ctx = self.mutation.mutate(&mut refinement_ctx, insertion_ctx);
+            insertion_ctx = self.mutation.mutate(refinement_ctx, insertion_ctx);
 
-            let cost = problem.objective.estimate_cost(&mut refinement_ctx, &insertion_ctx);
+            let cost = problem.objective.estimate_cost(refinement_ctx, &insertion_ctx);
             let individuum = (insertion_ctx, cost, refinement_ctx.generation);
-            let is_accepted = self.acceptance.is_accepted(&mut refinement_ctx, &individuum);
-            let is_terminated = self.termination.is_termination(&mut refinement_ctx, (&individuum, is_accepted));
-            let is_goal_satisfied =
-                problem.objective.is_goal_satisfied(&mut refinement_ctx, &individuum.0).unwrap_or(false);
+            let is_accepted = self.acceptance.is_accepted(refinement_ctx, &individuum);
+            let is_terminated = self.termination.is_termination(refinement_ctx, (&individuum, is_accepted));
+            let is_goal_satisfied = problem.objective.is_goal_satisfied(refinement_ctx, &individuum.0).unwrap_or(false);
 
-            if refinement_ctx.generation % 100 == 0 || is_terminated || is_goal_satisfied || is_accepted {
-                self.log_generation(&refinement_ctx, &generation_time, &refinement_time, &individuum, is_accepted);
-            }
+            if is_verbose {
+                if refinement_ctx.generation % 100 == 0 || is_terminated || is_goal_satisfied || is_accepted {
+                    self.log_generation(refinement_ctx, &generation_time, refinement_time, &individuum, is_accepted);
+                }
 
-            if refinement_ctx.generation > 0 && refinement_ctx.generation % 1000 == 0 {
-                self.log_population(&refinement_ctx, &refinement_time);
+                if refinement_ctx.generation > 0 && refinement_ctx.generation % 1000 == 0 {
+                    self.log_population(refinement_ctx, refinement_time);
+                }
             }
 
+            let outcome = match (is_accepted, refinement_ctx.population.best()) {
+                (false, _) => MutationOutcome::Rejected,
+                (true, Some((_, best, _))) if individuum.1.cmp_relaxed(best).0 != Less => MutationOutcome::Accepted,
+                (true, _) => MutationOutcome::NewBest,
+            };
+            self.mutation.accept_outcome(refinement_ctx, &outcome);
+
             if is_accepted {
                 refinement_ctx.population.add(individuum)
             }
 
-            insertion_ctx = self.selection.select(&mut refinement_ctx);
+            if is_verbose {
+                self.report_progress(refinement_ctx, refinement_time);
+            }
+
+            self.record_telemetry(refinement_ctx, refinement_time, is_terminated || is_goal_satisfied);
+
+            insertion_ctx = self.selection.select(refinement_ctx);
 
             if is_terminated || is_goal_satisfied {
-                self.logger.deref()(format!(
-                    "stopped due to termination ({}) or goal satisfaction ({})",
-                    is_terminated, is_goal_satisfied
-                ));
-                break;
+                if is_verbose {
+                    self.logger.deref()(format!(
+                        "stopped due to termination ({}) or goal satisfaction ({})",
+                        is_terminated, is_goal_satisfied
+                    ));
+                }
+                return (insertion_ctx, true);
             }
 
             refinement_ctx.generation += 1;
+
+            if last_generation.map_or(false, |last_generation| refinement_ctx.generation >= last_generation) {
+                return (insertion_ctx, false);
+            }
         }
+    }
 
-        self.log_speed(&refinement_ctx, &refinement_time);
-        self.get_result(refinement_ctx)
+    fn create_refinement_ctx(
+        &self,
+        problem: Arc<Problem>,
+        quota: Option<Box<dyn Quota + Sync + Send>>,
+    ) -> RefinementContext {
+        let mut refinement_ctx =
+            RefinementContext::new_with_population(problem, Box::new(SimplePopulation::new(self.population_size)));
+
+        if let Some(quota) = quota {
+            refinement_ctx.set_quota(quota);
+        }
+
+        refinement_ctx
+    }
+
+    fn create_insertion_ctx(
+        &self,
+        refinement_ctx: &mut RefinementContext,
+        initial: Option<InsertionContext>,
+        random: Arc<dyn Random + Send + Sync>,
+    ) -> InsertionContext {
+        let problem = refinement_ctx.problem.clone();
+        match initial {
+            Some(ctx) => {
+                let cost = problem.objective.estimate_cost(&mut RefinementContext::new(problem.clone()), &ctx);
+                refinement_ctx.population.add((ctx.deep_copy(), cost, 1));
+                ctx
+            }
+            None => InsertionContext::new(problem, random),
+        }
+    }
+
+    fn report_progress(&self, refinement_ctx: &RefinementContext, refinement_time: &Timer) {
+        if let (Some(callback), Some(progress)) = (&self.progress, get_progress(refinement_ctx, refinement_time)) {
+            callback(&progress);
+        }
+    }
+
+    /// Stores progress into refinement context state each telemetry interval and on last generation.
+    fn record_telemetry(&self, refinement_ctx: &mut RefinementContext, refinement_time: &Timer, is_last: bool) {
+        let is_sampled = self
+            .telemetry_interval
+            .map(|interval| is_last || refinement_ctx.generation % interval.max(1) == 0)
+            .unwrap_or(false);
+
+        if !is_sampled {
+            return;
+        }
+
+        if let Some(progress) = get_progress(refinement_ctx, refinement_time) {
+            let telemetry = refinement_ctx
+                .state
+                .entry(TELEMETRY_KEY.to_string())
+                .or_insert_with(|| Box::new(Vec::<Progress>::new()))
+                .downcast_mut::<Vec<Progress>>()
+                .expect("unexpected telemetry type");
+
+            // NOTE last generation can be already sampled by interval
+            if telemetry.last().map(|last| last.generation) != Some(progress.generation) {
+                telemetry.push(progress);
+            }
+        }
     }
 
     fn log_generation(
@@ -155,32 +354,76 @@ impl Solver {
         });
     }
 
-    fn log_speed(&self, refinement_ctx: &RefinementContext, refinement_time: &Timer) {
+    fn log_islands(&self, epoch: usize, states: &[IslandState], refinement_time: &Timer) {
+        self.logger.deref()(format!(
+            "epoch {} finished after {}s, best costs per island: [{}]",
+            epoch,
+            refinement_time.elapsed_secs(),
+            states
+                .iter()
+                .map(|(refinement_ctx, _, _)| refinement_ctx
+                    .population
+                    .best()
+                    .map_or("-".to_string(), |(_, cost, _)| format!("{:.2}", cost.value())))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    fn log_speed(&self, generations: usize, refinement_time: &Timer) {
         self.logger.deref()(format!(
             "solving took {}s, total generations: {}, speed: {:.2} gen/sec",
             refinement_time.elapsed_secs(),
-            refinement_ctx.generation,
-            refinement_ctx.generation as f64 / refinement_time.elapsed_secs_as_f64()
+            generations,
+            generations as f64 / refinement_time.elapsed_secs_as_f64()
         ));
     }
 
     fn get_result(
         &self,
-        refinement_ctx: RefinementContext,
+        mut refinement_ctx: RefinementContext,
     ) -> Option<(Solution, Box<dyn ObjectiveCost + Send + Sync>, usize)> {
+        let extras = get_solution_extras(&mut refinement_ctx);
+
         if let Some((ctx, cost, generation)) = refinement_ctx.population.best() {
             self.logger.deref()(format!(
                 "best solution within cost {} discovered at {} generation",
                 cost.value(),
                 generation
             ));
-            Some((ctx.solution.to_solution(refinement_ctx.problem.extras.clone()), cost.clone_box(), *generation))
+            if let Some(state) = self.mutation.describe_state(&refinement_ctx) {
+                self.logger.deref()(state);
+            }
+            Some((ctx.solution.to_solution(extras), cost.clone_box(), *generation))
         } else {
             None
         }
     }
 }
 
+/// Returns progress of the best known solution.
+fn get_progress(refinement_ctx: &RefinementContext, refinement_time: &Timer) -> Option<Progress> {
+    refinement_ctx.population.best().map(|(insertion_ctx, cost, _)| Progress {
+        generation: refinement_ctx.generation,
+        cost: cost.value(),
+        elapsed: refinement_time.elapsed_secs_as_f64(),
+        tours: insertion_ctx.solution.routes.len(),
+        unassigned: insertion_ctx.solution.unassigned.len(),
+    })
+}
+
+/// Returns extras of the solution: problem extras with collected telemetry stored as `iterations`.
+fn get_solution_extras(refinement_ctx: &mut RefinementContext) -> Arc<Extras> {
+    match refinement_ctx.state.remove(TELEMETRY_KEY) {
+        Some(telemetry) => {
+            let mut extras = refinement_ctx.problem.extras.as_ref().clone();
+            extras.insert("iterations".to_string(), Arc::from(telemetry));
+            Arc::new(extras)
+        }
+        None => refinement_ctx.problem.extras.clone(),
+    }
+}
+
 fn get_cost_change(refinement_ctx: &RefinementContext, new_cost: &Box<dyn ObjectiveCost + Send + Sync>) -> f64 {
     refinement_ctx
         .population
@@ -188,3 +431,56 @@ fn get_cost_change(refinement_ctx: &RefinementContext, new_cost: &Box<dyn Object
         .map(|(_, best_cost, _)| (new_cost.value() - best_cost.value()) / best_cost.value() * 100.)
         .unwrap_or(100.)
 }
+
+/// Keeps refinement context, last selected insertion context and termination flag of the island.
+type IslandState = (RefinementContext, InsertionContext, bool);
+
+/// Returns index of the island with the best individuum.
+fn get_best_island(states: &[IslandState]) -> Option<usize> {
+    states
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, (refinement_ctx, _, _))| refinement_ctx.population.best().map(|best| (idx, best)))
+        .min_by(|(_, a), (_, b)| a.1.cmp_relaxed(&b.1).0)
+        .map(|(idx, _)| idx)
+}
+
+/// Moves the best individuum of each island to the next one using ring topology.
+/// Migrant is accepted only if it is better than the best individuum of the target island.
+fn migrate(states: &mut [IslandState]) {
+    let migrants = states
+        .iter()
+        .map(|(refinement_ctx, _, _)| {
+            refinement_ctx
+                .population
+                .best()
+                .map(|(insertion_ctx, cost, _)| (insertion_ctx.deep_copy(), cost.clone_box()))
+        })
+        .collect::<Vec<_>>();
+
+    let size = states.len();
+    migrants.into_iter().enumerate().for_each(|(idx, migrant)| {
+        let (refinement_ctx, current_ctx, is_terminated) = &mut states[(idx + 1) % size];
+
+        if let (Some((insertion_ctx, cost)), false) = (migrant, *is_terminated) {
+            let is_better =
+                refinement_ctx.population.best().map_or(true, |(_, best, _)| cost.cmp_relaxed(best).0 == Less);
+
+            if is_better {
+                let insertion_ctx = InsertionContext { random: current_ctx.random.clone(), ..insertion_ctx };
+                let generation = refinement_ctx.generation;
+                refinement_ctx.population.add((insertion_ctx, cost, generation));
+            }
+        }
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn get_islands_amount(islands: usize) -> usize {
+    islands.max(1)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn get_islands_amount(_: usize) -> usize {
+    1
+}
@@ -1,13 +1,18 @@
-use crate::extensions::TimeQuota;
-use crate::Solver;
+use crate::config::*;
+use crate::extensions::{CancellationQuota, CompositeQuota, TimeQuota};
+use crate::{IslandModel, ProgressCallback, Solver};
 use std::ops::Deref;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::construction::Quota;
 use vrp_core::models::{Problem, Solution};
+use vrp_core::refinement::acceptance::*;
+use vrp_core::refinement::mutation::*;
+use vrp_core::refinement::selection::{SelectBest, SelectRandom};
 use vrp_core::refinement::termination::*;
 use vrp_core::refinement::RefinementContext;
-use vrp_core::utils::DefaultRandom;
+use vrp_core::utils::SeededRandom;
 
 /// Provides configurable way to build solver.
 pub struct SolverBuilder {
@@ -15,16 +20,61 @@ pub struct SolverBuilder {
     max_generations: Option<usize>,
     max_time: Option<f64>,
     init_solution: Option<(Arc<Problem>, Arc<Solution>)>,
+    seed: Option<u64>,
+    islands: Option<(usize, usize)>,
+    acceptance: Option<AcceptanceType>,
+    adaptive: Option<AdaptiveSettings>,
+    local_search: Option<LocalSearchType>,
+    cancellation: Option<Arc<AtomicBool>>,
+    progress: Option<ProgressCallback>,
+    telemetry_interval: Option<usize>,
+    config: Option<Config>,
+}
+
+/// Specifies acceptance criteria which can be configured by builder.
+#[derive(Clone, Copy, Debug)]
+pub enum AcceptanceType {
+    /// Accepts only improvements.
+    Greedy,
+    /// Accepts worse solutions with probability which decreases with temperature.
+    SimulatedAnnealing,
+    /// Accepts worse solutions within threshold which decreases over time.
+    ThresholdAccepting,
+    /// Accepts worse solutions within fixed deviation from the best known one.
+    RecordToRecord,
+}
+
+/// Specifies how local search is used by solver.
+#[derive(Clone, Copy, Debug)]
+pub enum LocalSearchType {
+    /// Local search is used instead of ruin and recreate.
+    Standalone,
+    /// Local search is applied to each solution produced by ruin and recreate.
+    AfterRecreate,
 }
 
 impl Default for SolverBuilder {
     fn default() -> Self {
-        Self { solver: Solver::default(), max_generations: None, max_time: None, init_solution: None }
+        Self {
+            solver: Solver::default(),
+            max_generations: None,
+            max_time: None,
+            init_solution: None,
+            seed: None,
+            islands: None,
+            acceptance: None,
+            adaptive: None,
+            local_search: None,
+            cancellation: None,
+            progress: None,
+            telemetry_interval: None,
+            config: None,
+        }
     }
 }
 
 impl SolverBuilder {
-    /// Sets max generations to be run.
+    /// Sets max generations to be run. With islands, the limit is applied to each island.
     /// Default is 2000.
     pub fn with_max_generations(&mut self, limit: Option<usize>) -> &mut Self {
         self.max_generations = limit;
@@ -45,43 +95,252 @@ impl SolverBuilder {
         self
     }
 
+    /// Sets seed for random generator which makes solver results reproducible.
+    /// Default is none.
+    pub fn with_seed(&mut self, seed: Option<u64>) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets amount of islands and their migration interval in generations. Islands are independent
+    /// refinement loops run in parallel which periodically exchange their best solutions. Each island
+    /// runs up to max generations, so total amount of generations is multiplied by amount of islands.
+    /// Default is none: single refinement loop is used. Not supported on wasm target.
+    pub fn with_islands(&mut self, islands: Option<(usize, usize)>) -> &mut Self {
+        self.islands = islands;
+        self
+    }
+
+    /// Sets acceptance criteria. Cooling schedule of simulated annealing and threshold accepting is
+    /// tied to max generations if specified, otherwise to max time.
+    /// Default is greedy acceptance.
+    pub fn with_acceptance(&mut self, acceptance: Option<AcceptanceType>) -> &mut Self {
+        self.acceptance = acceptance;
+        self
+    }
+
+    /// Sets adaptive weights settings. When specified, ruin and recreate methods are selected using
+    /// weights adjusted by their performance (adaptive large neighbourhood search).
+    /// Default is none: fixed weights are used.
+    pub fn with_adaptive_weights(&mut self, adaptive: Option<AdaptiveSettings>) -> &mut Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    /// Sets local search usage: local search applies 2-opt, or-opt, relocate, exchange and
+    /// cross-exchange moves to the solution.
+    /// Default is none: only ruin and recreate is used.
+    pub fn with_local_search(&mut self, local_search: Option<LocalSearchType>) -> &mut Self {
+        self.local_search = local_search;
+        self
+    }
+
+    /// Sets cancellation flag: solver stops as soon as possible when the flag is set to true and
+    /// returns the best known solution.
+    /// Default is none.
+    pub fn with_cancellation(&mut self, is_cancelled: Option<Arc<AtomicBool>>) -> &mut Self {
+        self.cancellation = is_cancelled;
+        self
+    }
+
+    /// Sets progress callback which receives generation, best known cost, amount of tours and
+    /// unassigned jobs. With islands, it is called after each migration.
+    /// Default is none.
+    pub fn with_progress(&mut self, progress: Option<ProgressCallback>) -> &mut Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Sets generation interval for collecting search telemetry: best known cost, elapsed time,
+    /// amount of tours and unassigned jobs. Collected telemetry is stored in solution extras
+    /// under `iterations` key as a vector of `Progress`.
+    /// Default is none.
+    pub fn with_telemetry(&mut self, interval: Option<usize>) -> &mut Self {
+        self.telemetry_interval = interval;
+        self
+    }
+
+    /// Sets solver configuration which declares population, mutation, acceptance, termination and
+    /// island model settings. Parameters set explicitly by other builder methods take precedence.
+    /// Default is none.
+    pub fn with_config(&mut self, config: Option<Config>) -> &mut Self {
+        self.config = config;
+        self
+    }
+
     /// Builds solver with parameters specified.
     pub fn build(&mut self) -> Solver {
-        let (criterias, quota): (Vec<Box<dyn Termination>>, _) = match (self.max_generations, self.max_time) {
-            (None, None) => {
-                self.solver.logger.deref()(
-                    "configured to use default max-generations (2000) and max-time (300secs)".to_string(),
-                );
-                (vec![Box::new(MaxGeneration::default()), Box::new(QuotaReached::default())], create_time_quota(300.))
-            }
-            _ => {
-                let mut criterias: Vec<Box<dyn Termination>> = vec![];
+        let config = self.config.take().unwrap_or_default();
+        self.apply_config(&config);
 
-                if let Some(limit) = self.max_generations {
-                    self.solver.logger.deref()(format!("configured to use max-generations {}", limit));
-                    criterias.push(Box::new(MaxGeneration::new(limit)))
+        let (criterias, quota): (Vec<Box<dyn Termination + Send + Sync>>, _) =
+            match (self.max_generations, self.max_time) {
+                (None, None) => {
+                    self.solver.logger.deref()(
+                        "configured to use default max-generations (2000) and max-time (300secs)".to_string(),
+                    );
+                    (
+                        vec![Box::new(MaxGeneration::default()), Box::new(QuotaReached::default())],
+                        create_time_quota(300.),
+                    )
                 }
+                _ => {
+                    let mut criterias: Vec<Box<dyn Termination + Send + Sync>> = vec![];
 
-                let quota = if let Some(limit) = self.max_time {
-                    self.solver.logger.deref()(format!("configured to use max-time {}s", limit));
-                    criterias.push(Box::new(QuotaReached::default()));
-                    create_time_quota(limit)
-                } else {
-                    None
-                };
+                    if let Some(limit) = self.max_generations {
+                        self.solver.logger.deref()(format!("configured to use max-generations {}", limit));
+                        criterias.push(Box::new(MaxGeneration::new(limit)))
+                    }
+
+                    let quota = if let Some(limit) = self.max_time {
+                        self.solver.logger.deref()(format!("configured to use max-time {}s", limit));
+                        criterias.push(Box::new(QuotaReached::default()));
+                        create_time_quota(limit)
+                    } else {
+                        None
+                    };
 
-                (criterias, quota)
+                    (criterias, quota)
+                }
+            };
+
+        let (criterias, quota) = match self.cancellation.take() {
+            Some(is_cancelled) => {
+                self.solver.logger.deref()("configured to use cancellation".to_string());
+                let cancellation: Box<dyn Quota + Send + Sync> = Box::new(CancellationQuota::new(is_cancelled));
+
+                match quota {
+                    Some(quota) => (criterias, Some(Box::new(CompositeQuota::new(vec![quota, cancellation])) as _)),
+                    None => {
+                        let mut criterias = criterias;
+                        criterias.push(Box::new(QuotaReached::default()));
+                        (criterias, Some(cancellation))
+                    }
+                }
             }
+            None => (criterias, quota),
         };
 
         self.solver.termination = Box::new(CompositeTermination::new(criterias));
         self.solver.quota = quota;
+        self.solver.progress = self.progress.take();
+
+        if let Some(interval) = self.telemetry_interval {
+            self.solver.logger.deref()(format!("configured to collect telemetry each {} generations", interval));
+            self.solver.telemetry_interval = Some(interval);
+        }
+
+        if let Some(seed) = self.seed {
+            self.solver.logger.deref()(format!("configured to use seed {}", seed));
+            self.solver.random = Arc::new(SeededRandom::new(seed));
+        }
+
+        let acceptance = self.acceptance.map(|acceptance| match acceptance {
+            AcceptanceType::Greedy => AcceptanceConfig::Greedy,
+            AcceptanceType::SimulatedAnnealing => {
+                AcceptanceConfig::SimulatedAnnealing { initial_temperature: None, final_temperature: None }
+            }
+            AcceptanceType::ThresholdAccepting => AcceptanceConfig::ThresholdAccepting { initial_threshold: None },
+            AcceptanceType::RecordToRecord => AcceptanceConfig::RecordToRecord { deviation: None },
+        });
+
+        if let Some(acceptance) = acceptance.or_else(|| config.acceptance.clone()) {
+            self.solver.logger.deref()(format!("configured to use {:?} acceptance", acceptance));
+            let (max_generations, max_time) = (self.max_generations, self.max_time);
+            let create_schedule = || match (max_generations, max_time) {
+                (None, Some(limit)) => CoolingSchedule::new_with_time(limit),
+                (Some(limit), _) => CoolingSchedule::new_with_generations(limit),
+                (None, None) => CoolingSchedule::new_with_generations(2000),
+            };
+
+            self.solver.acceptance = match acceptance {
+                AcceptanceConfig::Greedy => Box::new(Greedy::default()),
+                AcceptanceConfig::SimulatedAnnealing { initial_temperature: None, final_temperature: None } => {
+                    Box::new(SimulatedAnnealing::new_with_schedule(create_schedule()))
+                }
+                AcceptanceConfig::SimulatedAnnealing { initial_temperature, final_temperature } => {
+                    let default = (0.01, 0.0001);
+                    match SimulatedAnnealing::new(
+                        initial_temperature.unwrap_or(default.0),
+                        final_temperature.unwrap_or(default.1),
+                        create_schedule(),
+                    ) {
+                        Ok(acceptance) => Box::new(acceptance),
+                        Err(err) => {
+                            self.solver.logger.deref()(format!("{}, default temperatures are used", err));
+                            Box::new(SimulatedAnnealing::new_with_schedule(create_schedule()))
+                        }
+                    }
+                }
+                AcceptanceConfig::ThresholdAccepting { initial_threshold: Some(threshold) } => {
+                    Box::new(ThresholdAccepting::new(threshold, create_schedule()))
+                }
+                AcceptanceConfig::ThresholdAccepting { initial_threshold: None } => {
+                    Box::new(ThresholdAccepting::new_with_schedule(create_schedule()))
+                }
+                AcceptanceConfig::RecordToRecord { deviation: Some(deviation) } => {
+                    Box::new(RecordToRecord::new(deviation))
+                }
+                AcceptanceConfig::RecordToRecord { deviation: None } => Box::new(RecordToRecord::default()),
+            };
+        }
+
+        if let Some(settings) = &self.adaptive {
+            self.solver.logger.deref()(format!(
+                "configured to use adaptive weights with segment size {} and reaction factor {}",
+                settings.segment_size, settings.reaction_factor
+            ));
+        }
+
+        let mutation_config = config.mutation.as_ref();
+        let ruins = mutation_config.and_then(|mutation| mutation.ruins.as_ref());
+        let recreates = mutation_config.and_then(|mutation| mutation.recreates.as_ref());
+
+        if ruins.is_some() || recreates.is_some() || self.adaptive.is_some() {
+            let adaptive = self.adaptive.clone();
+
+            let ruin = match ruins {
+                Some(ruins) => {
+                    self.solver.logger.deref()(format!("configured to use {} ruin groups", ruins.len()));
+                    CompositeRuin::new_with_names(create_ruins(ruins), adaptive.clone())
+                }
+                None => adaptive.clone().map_or_else(CompositeRuin::default, CompositeRuin::new_adaptive),
+            };
+
+            let recreate = match recreates {
+                Some(recreates) => {
+                    self.solver.logger.deref()(format!("configured to use {} recreate methods", recreates.len()));
+                    CompositeRecreate::new_with_names(create_recreates(recreates), adaptive)
+                }
+                None => adaptive.map_or_else(CompositeRecreate::default, CompositeRecreate::new_adaptive),
+            };
+
+            self.solver.mutation = Box::new(RuinAndRecreateMutation::new(Box::new(recreate), Box::new(ruin)));
+        }
+
+        if let Some(local_search) = self.local_search {
+            self.solver.logger.deref()(format!("configured to use {:?} local search", local_search));
+            let mutation = std::mem::replace(&mut self.solver.mutation, Box::new(LocalSearchMutation::default()));
+
+            if let LocalSearchType::AfterRecreate = local_search {
+                self.solver.mutation =
+                    Box::new(CompositeMutation::new(vec![mutation, Box::new(LocalSearchMutation::default())]));
+            }
+        }
+
+        if let Some((islands, migration_interval)) = self.islands {
+            self.solver.logger.deref()(format!(
+                "configured to use {} islands with migration interval {}",
+                islands, migration_interval
+            ));
+            self.solver.island_model = IslandModel { islands, migration_interval: migration_interval.max(1) };
+        }
 
         if let Some((problem, solution)) = &self.init_solution {
             let insertion_ctx = InsertionContext::new_from_solution(
                 problem.clone(),
                 (solution.clone(), None),
-                Arc::new(DefaultRandom::default()),
+                self.solver.random.clone(),
             );
 
             let cost = problem.objective.estimate_cost(&mut RefinementContext::new(problem.clone()), &insertion_ctx);
@@ -97,6 +356,116 @@ impl SolverBuilder {
     }
 }
 
+impl SolverBuilder {
+    /// Applies configuration to parameters which are not set explicitly.
+    fn apply_config(&mut self, config: &Config) {
+        if let Some(termination) = &config.termination {
+            if self.max_generations.is_none() && self.max_time.is_none() {
+                self.max_generations = termination.max_generations;
+                self.max_time = termination.max_time;
+            }
+        }
+
+        if let Some(islands) = config.islands.as_ref().filter(|_| self.islands.is_none()) {
+            self.islands = Some((islands.amount, islands.migration_interval.unwrap_or(100)));
+        }
+
+        if let Some(mutation) = &config.mutation {
+            if self.adaptive.is_none() {
+                self.adaptive = mutation.adaptive.as_ref().map(|adaptive| {
+                    let default = AdaptiveSettings::default();
+                    AdaptiveSettings {
+                        best_score: adaptive.best_score.unwrap_or(default.best_score),
+                        accepted_score: adaptive.accepted_score.unwrap_or(default.accepted_score),
+                        rejected_score: adaptive.rejected_score.unwrap_or(default.rejected_score),
+                        reaction_factor: adaptive.reaction_factor.unwrap_or(default.reaction_factor),
+                        segment_size: adaptive.segment_size.unwrap_or(default.segment_size),
+                    }
+                });
+            }
+
+            if self.local_search.is_none() {
+                self.local_search = mutation.local_search.as_ref().map(|local_search| match local_search {
+                    LocalSearchConfig::Standalone => LocalSearchType::Standalone,
+                    LocalSearchConfig::AfterRecreate => LocalSearchType::AfterRecreate,
+                });
+            }
+        }
+
+        if let Some(population) = &config.population {
+            if let Some(size) = population.size {
+                self.solver.logger.deref()(format!("configured to use population size {}", size));
+                self.solver.population_size = size.max(2);
+            }
+
+            if let Some(selection) = &population.selection {
+                self.solver.logger.deref()(format!("configured to use {:?} selection", selection));
+                self.solver.selection = match selection {
+                    SelectionConfig::Random => Box::new(SelectRandom::default()),
+                    SelectionConfig::Best => Box::new(SelectBest::default()),
+                };
+            }
+        }
+    }
+}
+
+fn create_ruins(ruins: &[RuinGroupConfig]) -> Vec<(RuinGroup, usize, String)> {
+    ruins
+        .iter()
+        .enumerate()
+        .map(|(idx, group)| {
+            let methods = group
+                .methods
+                .iter()
+                .map::<(Arc<dyn Ruin + Send + Sync>, f64), _>(|method| match *method {
+                    RuinConfig::AdjustedString { probability, lmax, cavg, alpha } => {
+                        (Arc::new(AdjustedStringRemoval::new(lmax, cavg, alpha)), probability)
+                    }
+                    RuinConfig::Neighbour { probability, min, max, threshold } => {
+                        (Arc::new(NeighbourRemoval::new(min, max, threshold)), probability)
+                    }
+                    RuinConfig::RandomJob { probability, min, max, threshold } => {
+                        (Arc::new(RandomJobRemoval::new(min, max, threshold)), probability)
+                    }
+                    RuinConfig::RandomRoute { probability, min, max, threshold } => {
+                        (Arc::new(RandomRouteRemoval::new(min, max, threshold)), probability)
+                    }
+                    RuinConfig::WorstJob { probability, threshold, skip, min, max } => {
+                        (Arc::new(WorstJobRemoval::new(threshold, skip, (min, max))), probability)
+                    }
+                })
+                .collect();
+
+            (methods, group.weight, group.name.clone().unwrap_or_else(|| format!("ruin_{}", idx)))
+        })
+        .collect()
+}
+
+fn create_recreates(recreates: &[RecreateConfig]) -> Vec<(Box<dyn Recreate + Send + Sync>, usize, String)> {
+    recreates
+        .iter()
+        .map::<(Box<dyn Recreate + Send + Sync>, usize, String), _>(|recreate| match *recreate {
+            RecreateConfig::Cheapest { weight } => {
+                (Box::new(RecreateWithCheapest::default()), weight, "cheapest".to_string())
+            }
+            RecreateConfig::Regret { weight, start, end } => (
+                Box::new(RecreateWithRegret::new((start as i32, end as i32))),
+                weight,
+                format!("regret_{}_{}", start, end),
+            ),
+            RecreateConfig::Blinks { weight } => {
+                (Box::new(RecreateWithBlinks::<i32>::default()), weight, "blinks".to_string())
+            }
+            RecreateConfig::Gaps { weight, min } => {
+                (Box::new(RecreateWithGaps::new(min)), weight, format!("gaps_{}", min))
+            }
+            RecreateConfig::NearestNeighbor { weight } => {
+                (Box::new(RecreateWithNearestNeighbor::default()), weight, "nearest_neighbor".to_string())
+            }
+        })
+        .collect()
+}
+
 fn create_time_quota(limit: f64) -> Option<Box<dyn Quota + Sync + Send>> {
     Some(Box::new(TimeQuota::new(limit)))
 }
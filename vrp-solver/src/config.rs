@@ -0,0 +1,386 @@
+//! Contains a declarative solver configuration which can be used to tune metaheuristic
+//! without recompiling.
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read};
+
+/// Specifies solver configuration. All parameters are optional: unspecified ones are taken
+/// from solver builder or default settings.
+#[derive(Clone, Deserialize, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Population settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub population: Option<PopulationConfig>,
+    /// Mutation settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutation: Option<MutationConfig>,
+    /// Acceptance criteria.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acceptance: Option<AcceptanceConfig>,
+    /// Termination criteria.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub termination: Option<TerminationConfig>,
+    /// Island model settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub islands: Option<IslandsConfig>,
+}
+
+/// Specifies population settings.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PopulationConfig {
+    /// Max amount of individuums kept in population.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    /// Selection policy of individuum used for next generation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection: Option<SelectionConfig>,
+}
+
+/// Specifies selection policies.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SelectionConfig {
+    /// Selects random individuum from population.
+    Random,
+    /// Selects the best individuum from population.
+    Best,
+}
+
+/// Specifies mutation settings.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutationConfig {
+    /// Groups of ruin methods, one group is selected on each generation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ruins: Option<Vec<RuinGroupConfig>>,
+    /// Recreate methods, one method is selected on each generation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recreates: Option<Vec<RecreateConfig>>,
+    /// Adaptive weights settings: when specified, weights of ruin and recreate methods are
+    /// adjusted using their performance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<AdaptiveConfig>,
+    /// Local search usage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_search: Option<LocalSearchConfig>,
+}
+
+/// Specifies a group of ruin methods which are applied one by one with their probabilities.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuinGroupConfig {
+    /// Group name used in logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Initial weight of the group.
+    pub weight: usize,
+    /// Ruin methods of the group.
+    pub methods: Vec<RuinConfig>,
+}
+
+/// Specifies ruin methods with their parameters.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum RuinConfig {
+    /// Adjusted string removal method.
+    #[serde(rename = "adjusted-string")]
+    AdjustedString {
+        /// Probability of the method to be applied.
+        probability: f64,
+        /// Max removed string cardinality for specific tour.
+        lmax: usize,
+        /// Average number of removed customers.
+        cavg: usize,
+        /// Preserved customers ratio.
+        alpha: f64,
+    },
+    /// Neighbour jobs removal method.
+    #[serde(rename = "neighbour")]
+    Neighbour {
+        /// Probability of the method to be applied.
+        probability: f64,
+        /// Min amount of removed jobs.
+        min: usize,
+        /// Max amount of removed jobs.
+        max: usize,
+        /// Threshold ratio of max removed jobs.
+        threshold: f64,
+    },
+    /// Random jobs removal method.
+    #[serde(rename = "random-job")]
+    RandomJob {
+        /// Probability of the method to be applied.
+        probability: f64,
+        /// Min amount of removed jobs.
+        min: usize,
+        /// Max amount of removed jobs.
+        max: usize,
+        /// Threshold ratio of max removed jobs.
+        threshold: f64,
+    },
+    /// Random routes removal method.
+    #[serde(rename = "random-route")]
+    RandomRoute {
+        /// Probability of the method to be applied.
+        probability: f64,
+        /// Min amount of removed routes.
+        min: usize,
+        /// Max amount of removed routes.
+        max: usize,
+        /// Threshold ratio of max removed routes.
+        threshold: f64,
+    },
+    /// Worst jobs removal method.
+    #[serde(rename = "worst-job")]
+    WorstJob {
+        /// Probability of the method to be applied.
+        probability: f64,
+        /// Max amount of removed jobs.
+        threshold: usize,
+        /// Max amount of skipped worst jobs.
+        skip: usize,
+        /// Min amount of jobs removed around each worst one.
+        min: usize,
+        /// Max amount of jobs removed around each worst one.
+        max: usize,
+    },
+}
+
+/// Specifies recreate methods with their weights and parameters.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum RecreateConfig {
+    /// Cheapest insertion method.
+    #[serde(rename = "cheapest")]
+    Cheapest {
+        /// Initial weight of the method.
+        weight: usize,
+    },
+    /// Regret insertion method.
+    #[serde(rename = "regret")]
+    Regret {
+        /// Initial weight of the method.
+        weight: usize,
+        /// Min regret index.
+        start: usize,
+        /// Max regret index.
+        end: usize,
+    },
+    /// Insertion with blinks method.
+    #[serde(rename = "blinks")]
+    Blinks {
+        /// Initial weight of the method.
+        weight: usize,
+    },
+    /// Insertion with gaps method.
+    #[serde(rename = "gaps")]
+    Gaps {
+        /// Initial weight of the method.
+        weight: usize,
+        /// Min amount of jobs inserted.
+        min: usize,
+    },
+    /// Nearest neighbor insertion method.
+    #[serde(rename = "nearest-neighbor")]
+    NearestNeighbor {
+        /// Initial weight of the method.
+        weight: usize,
+    },
+}
+
+/// Specifies adaptive weights settings: unspecified parameters have default values.
+#[derive(Clone, Deserialize, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaptiveConfig {
+    /// Score given to method which produced a new best solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_score: Option<f64>,
+    /// Score given to method which produced accepted, but not the best solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_score: Option<f64>,
+    /// Score given to method which produced rejected solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected_score: Option<f64>,
+    /// Reaction factor in range [0, 1].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reaction_factor: Option<f64>,
+    /// Amount of method usages after which weights are updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_size: Option<usize>,
+}
+
+/// Specifies local search usage.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LocalSearchConfig {
+    /// Local search is used instead of ruin and recreate.
+    Standalone,
+    /// Local search is applied to each solution produced by ruin and recreate.
+    AfterRecreate,
+}
+
+/// Specifies acceptance criteria with their parameters: unspecified parameters have default values.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum AcceptanceConfig {
+    /// Accepts only improvements.
+    #[serde(rename = "greedy")]
+    Greedy,
+    /// Simulated annealing.
+    #[serde(rename = "annealing", rename_all = "camelCase")]
+    SimulatedAnnealing {
+        /// Initial temperature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        initial_temperature: Option<f64>,
+        /// Final temperature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        final_temperature: Option<f64>,
+    },
+    /// Threshold accepting.
+    #[serde(rename = "threshold", rename_all = "camelCase")]
+    ThresholdAccepting {
+        /// Initial threshold.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        initial_threshold: Option<f64>,
+    },
+    /// Record-to-record travel.
+    #[serde(rename = "record")]
+    RecordToRecord {
+        /// Allowed deviation from the best known cost.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        deviation: Option<f64>,
+    },
+}
+
+/// Specifies termination criteria.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminationConfig {
+    /// Max amount of generations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_generations: Option<usize>,
+    /// Max running time in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_time: Option<f64>,
+}
+
+/// Specifies island model settings.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IslandsConfig {
+    /// Amount of islands.
+    pub amount: usize,
+    /// Amount of generations between migrations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migration_interval: Option<usize>,
+}
+
+/// Reads solver configuration from json and validates it.
+pub fn read_config<R: Read>(reader: BufReader<R>) -> Result<Config, String> {
+    let config = serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize config: {}", err))?;
+
+    validate_config(&config)?;
+
+    Ok(config)
+}
+
+/// Checks that configuration parameters have values which can be used by the solver.
+pub fn validate_config(config: &Config) -> Result<(), String> {
+    if let Some(mutation) = &config.mutation {
+        if let Some(ruins) = &mutation.ruins {
+            validate_weights("ruin", ruins.iter().map(|group| group.weight))?;
+            ruins.iter().flat_map(|group| group.methods.iter()).try_for_each(validate_ruin)?;
+        }
+
+        if let Some(recreates) = &mutation.recreates {
+            validate_weights("recreate", recreates.iter().map(get_recreate_weight))?;
+            recreates.iter().try_for_each(|recreate| match *recreate {
+                RecreateConfig::Regret { start, end, .. } => validate_range("regret", start, end),
+                _ => Ok(()),
+            })?;
+        }
+
+        if let Some(adaptive) = &mutation.adaptive {
+            validate_ratio("adaptive reaction factor", adaptive.reaction_factor)?;
+
+            if adaptive.segment_size == Some(0) {
+                return Err("adaptive segment size should be greater than zero".to_string());
+            }
+        }
+    }
+
+    if let Some(AcceptanceConfig::SimulatedAnnealing { initial_temperature, final_temperature }) = &config.acceptance {
+        if initial_temperature.iter().chain(final_temperature.iter()).any(|&temperature| temperature <= 0.) {
+            return Err("annealing temperatures should be greater than zero".to_string());
+        }
+    }
+
+    if let Some(population) = &config.population {
+        if population.size == Some(0) {
+            return Err("population size should be greater than zero".to_string());
+        }
+    }
+
+    if let Some(islands) = &config.islands {
+        if islands.amount == 0 {
+            return Err("amount of islands should be greater than zero".to_string());
+        }
+
+        if islands.migration_interval == Some(0) {
+            return Err("islands migration interval should be greater than zero".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_ruin(ruin: &RuinConfig) -> Result<(), String> {
+    let (name, probability, range) = match *ruin {
+        RuinConfig::AdjustedString { probability, .. } => ("adjusted-string", probability, None),
+        RuinConfig::Neighbour { probability, min, max, .. } => ("neighbour", probability, Some((min, max))),
+        RuinConfig::RandomJob { probability, min, max, .. } => ("random-job", probability, Some((min, max))),
+        RuinConfig::RandomRoute { probability, min, max, .. } => ("random-route", probability, Some((min, max))),
+        RuinConfig::WorstJob { probability, min, max, .. } => ("worst-job", probability, Some((min, max))),
+    };
+
+    validate_ratio(&format!("{} ruin probability", name), Some(probability))?;
+
+    range.map_or(Ok(()), |(min, max)| validate_range(name, min, max))
+}
+
+fn validate_weights(name: &str, mut weights: impl Iterator<Item = usize>) -> Result<(), String> {
+    if weights.any(|weight| weight > 0) {
+        Ok(())
+    } else {
+        Err(format!("{} methods should have at least one positive weight", name))
+    }
+}
+
+fn validate_ratio(name: &str, value: Option<f64>) -> Result<(), String> {
+    match value {
+        Some(value) if !(0. ..=1.).contains(&value) => {
+            Err(format!("{} should be in range [0, 1], got {}", name, value))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_range(name: &str, min: usize, max: usize) -> Result<(), String> {
+    if min > max {
+        Err(format!("{} min {} should not be greater than max {}", name, min, max))
+    } else {
+        Ok(())
+    }
+}
+
+fn get_recreate_weight(recreate: &RecreateConfig) -> usize {
+    match *recreate {
+        RecreateConfig::Cheapest { weight }
+        | RecreateConfig::Regret { weight, .. }
+        | RecreateConfig::Blinks { weight }
+        | RecreateConfig::Gaps { weight, .. }
+        | RecreateConfig::NearestNeighbor { weight } => weight,
+    }
+}
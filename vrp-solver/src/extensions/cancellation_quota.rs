@@ -0,0 +1,21 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use vrp_core::construction::Quota;
+
+/// A quota which is reached when cancellation is requested via shared atomic flag.
+pub struct CancellationQuota {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationQuota {
+    /// Creates a new instance of [`CancellationQuota`].
+    pub fn new(is_cancelled: Arc<AtomicBool>) -> Self {
+        Self { is_cancelled }
+    }
+}
+
+impl Quota for CancellationQuota {
+    fn is_reached(&self) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+}
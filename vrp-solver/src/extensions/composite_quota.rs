@@ -0,0 +1,19 @@
+use vrp_core::construction::Quota;
+
+/// A quota which is reached when any of inner quotas is reached.
+pub struct CompositeQuota {
+    quotas: Vec<Box<dyn Quota + Sync + Send>>,
+}
+
+impl CompositeQuota {
+    /// Creates a new instance of [`CompositeQuota`].
+    pub fn new(quotas: Vec<Box<dyn Quota + Sync + Send>>) -> Self {
+        Self { quotas }
+    }
+}
+
+impl Quota for CompositeQuota {
+    fn is_reached(&self) -> bool {
+        self.quotas.iter().any(|quota| quota.is_reached())
+    }
+}
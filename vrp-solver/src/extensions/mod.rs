@@ -3,3 +3,12 @@ pub use self::population::SimplePopulation;
 
 mod time_quota;
 pub use self::time_quota::TimeQuota;
+
+mod shared_quota;
+pub use self::shared_quota::SharedQuota;
+
+mod cancellation_quota;
+pub use self::cancellation_quota::CancellationQuota;
+
+mod composite_quota;
+pub use self::composite_quota::CompositeQuota;
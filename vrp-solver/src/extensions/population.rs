@@ -1,13 +1,22 @@
+use std::cmp::Ordering::Less;
 use std::collections::VecDeque;
 use vrp_core::refinement::{Individuum, Population};
 
+/// A population which keeps the most recently added individuums and tracks the best one separately,
+/// so acceptance criteria are allowed to add worse solutions.
 pub struct SimplePopulation {
     individuums: VecDeque<Individuum>,
+    best: Option<Individuum>,
     size: usize,
 }
 
 impl Population for SimplePopulation {
     fn add(&mut self, individuum: Individuum) {
+        let is_best = self.best.as_ref().map_or(true, |(_, best, _)| individuum.1.cmp_relaxed(best).0 == Less);
+        if is_best {
+            self.best = Some((individuum.0.deep_copy(), individuum.1.clone_box(), individuum.2));
+        }
+
         self.individuums.push_front(individuum);
         self.individuums.truncate(self.size);
     }
@@ -17,7 +26,7 @@ impl Population for SimplePopulation {
     }
 
     fn best(&self) -> Option<&Individuum> {
-        self.individuums.front()
+        self.best.as_ref()
     }
 
     fn size(&self) -> usize {
@@ -29,6 +38,6 @@ impl SimplePopulation {
     pub fn new(size: usize) -> Self {
         assert!(size > 1);
 
-        Self { individuums: VecDeque::default(), size }
+        Self { individuums: VecDeque::default(), best: None, size }
     }
 }
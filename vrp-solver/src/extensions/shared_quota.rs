@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use vrp_core::construction::Quota;
+
+/// A quota which can be shared between multiple refinement contexts.
+pub struct SharedQuota {
+    inner: Arc<Box<dyn Quota + Sync + Send>>,
+}
+
+impl SharedQuota {
+    /// Creates a new instance of [`SharedQuota`].
+    pub fn new(inner: Arc<Box<dyn Quota + Sync + Send>>) -> Self {
+        Self { inner }
+    }
+}
+
+impl Quota for SharedQuota {
+    fn is_reached(&self) -> bool {
+        self.inner.is_reached()
+    }
+}
@@ -1,9 +1,12 @@
 //! A solver crate contains metaheuristic implementation to solve arbitrary VRP problem.
 
 mod algorithm;
-pub use self::algorithm::Solver;
+pub use self::algorithm::{IslandModel, ProgressCallback, Solver};
+pub use vrp_core::refinement::Progress;
+
+pub mod config;
 
 mod builder;
-pub use self::builder::SolverBuilder;
+pub use self::builder::{AcceptanceType, LocalSearchType, SolverBuilder};
 
 mod extensions;